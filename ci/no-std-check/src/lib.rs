@@ -7,7 +7,12 @@ extern crate alloc;
 // Import the crates that we want to check if they are fully no-std compliance
 
 use ibc_proto;
-use ibc_types;
+use ibc_types_core_channel;
+use ibc_types_core_client;
+use ibc_types_core_connection;
+use ibc_types_identifier;
+use ibc_types_lightclients_tendermint;
+use ibc_types_timestamp;
 use tendermint;
 use tendermint_light_client_verifier;
 use tendermint_proto;
@@ -26,6 +31,57 @@ use sp_std;
 
 use core::panic::PanicInfo;
 
+use alloc::vec;
+
+use ibc_types_core_channel::{packet::Sequence, ChannelId, Packet, PortId, TimeoutHeight};
+use ibc_types_core_client::{ClientId, ClientType, Height};
+use ibc_types_core_connection::{
+    ConnectionEnd, ConnectionId, Counterparty, State as ConnectionState, Version,
+};
+use ibc_types_timestamp::Timestamp;
+
+/// Builds and validates the core IBC types, so that their code paths (and not
+/// just their crates) are compiled and linked against `core` and `alloc` only.
+pub fn exercise_core_types() -> bool {
+    let height = Height::new(0, 10).unwrap();
+
+    let client_type = ClientType::try_new("07-tendermint".into()).unwrap();
+    let client_id = ClientId::new(client_type, 0).unwrap();
+    let connection_id: ConnectionId = "connection-0".parse().unwrap();
+    let port_id = PortId::transfer();
+    let channel_id = ChannelId::new(0);
+    let identifiers_valid = ibc_types_identifier::validate_client_identifier(client_id.as_str())
+        .is_ok()
+        && ibc_types_identifier::validate_connection_identifier(connection_id.as_str()).is_ok()
+        && ibc_types_identifier::validate_port_identifier(port_id.as_str()).is_ok()
+        && ibc_types_identifier::validate_channel_identifier(channel_id.as_str()).is_ok();
+
+    let connection_end = ConnectionEnd {
+        state: ConnectionState::Open,
+        client_id: client_id.clone(),
+        counterparty: Counterparty {
+            client_id,
+            connection_id: Some(connection_id),
+            prefix: b"ibc".to_vec().into(),
+        },
+        versions: Version::compatible_versions(),
+        delay_period: core::time::Duration::ZERO,
+    };
+
+    let packet = Packet {
+        sequence: Sequence::from(1),
+        port_on_a: port_id.clone(),
+        chan_on_a: channel_id.clone(),
+        port_on_b: port_id,
+        chan_on_b: channel_id,
+        data: vec![1, 2, 3].into(),
+        timeout_height_on_b: TimeoutHeight::At(height.increment()),
+        timeout_timestamp_on_b: Timestamp::none(),
+    };
+
+    identifiers_valid && connection_end.is_open() && !packet.timed_out(&Timestamp::none(), height)
+}
+
 /*
 
 This function definition checks for the compliance of no-std in
@@ -46,7 +102,6 @@ error[E0152]: found duplicate lang item `panic_impl`
  */
 #[cfg(feature = "panic-handler")]
 #[panic_handler]
-#[no_mangle]
 fn panic(_info: &PanicInfo) -> ! {
     loop {}
 }
@@ -9,9 +9,12 @@ use ibc_proto::ibc::core::channel::v1::{
 };
 use ibc_proto::Protobuf;
 
+use bytes::BufMut;
+use ibc_types_core_client::FieldChange;
 use ibc_types_core_connection::ConnectionId;
+use ibc_types_domain_type::encoding;
 
-use crate::{ChannelError, ChannelId, PortId, Version};
+use crate::{ChannelError, ChannelId, PortId, ValidationErrors, Version};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(
@@ -40,6 +43,23 @@ impl IdentifiedChannelEnd {
             upgrade_sequence,
         }
     }
+
+    /// Like [`IdentifiedChannelEnd::new`], but rejects a `channel_end` that
+    /// fails [`ChannelEnd::validate_basic`].
+    pub fn try_new(
+        port_id: PortId,
+        channel_id: ChannelId,
+        channel_end: ChannelEnd,
+        upgrade_sequence: u64,
+    ) -> Result<Self, ChannelError> {
+        channel_end.validate_basic()?;
+        Ok(Self::new(
+            port_id,
+            channel_id,
+            channel_end,
+            upgrade_sequence,
+        ))
+    }
 }
 
 impl Protobuf<RawIdentifiedChannel> for IdentifiedChannelEnd {}
@@ -193,6 +213,53 @@ impl ChannelEnd {
         }
     }
 
+    /// Creates a new ChannelEnd in state Open, e.g. to describe an
+    /// established channel in tests.
+    pub fn new_open(
+        ordering: Order,
+        remote: Counterparty,
+        connection_hops: Vec<ConnectionId>,
+        version: Version,
+    ) -> Self {
+        Self::new(State::Open, ordering, remote, connection_hops, version, 0)
+    }
+
+    /// Returns this end with its state replaced by `state`.
+    pub fn with_state(mut self, state: State) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Returns this end with its ordering replaced by `ordering`.
+    pub fn with_ordering(mut self, ordering: Order) -> Self {
+        self.ordering = ordering;
+        self
+    }
+
+    /// Returns this end with its counterparty replaced by `remote`.
+    pub fn with_counterparty(mut self, remote: Counterparty) -> Self {
+        self.remote = remote;
+        self
+    }
+
+    /// Returns this end with its connection hops replaced by `connection_hops`.
+    pub fn with_connection_hops(mut self, connection_hops: Vec<ConnectionId>) -> Self {
+        self.connection_hops = connection_hops;
+        self
+    }
+
+    /// Returns this end with its version replaced by `version`.
+    pub fn with_version(mut self, version: Version) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Returns this end with its upgrade sequence replaced by `upgrade_sequence`.
+    pub fn with_upgrade_sequence(mut self, upgrade_sequence: u64) -> Self {
+        self.upgrade_sequence = upgrade_sequence;
+        self
+    }
+
     /// Updates the ChannelEnd to assume a new State 's'.
     pub fn set_state(&mut self, s: State) {
         self.state = s;
@@ -206,11 +273,31 @@ impl ChannelEnd {
         self.remote.channel_id = Some(c);
     }
 
+    /// Returns `true` if this `ChannelEnd` is in state [`State::Uninitialized`].
+    pub fn is_uninitialized(&self) -> bool {
+        self.state_matches(&State::Uninitialized)
+    }
+
+    /// Returns `true` if this `ChannelEnd` is in state [`State::Init`].
+    pub fn is_init(&self) -> bool {
+        self.state_matches(&State::Init)
+    }
+
+    /// Returns `true` if this `ChannelEnd` is in state [`State::TryOpen`].
+    pub fn is_tryopen(&self) -> bool {
+        self.state_matches(&State::TryOpen)
+    }
+
     /// Returns `true` if this `ChannelEnd` is in state [`State::Open`].
     pub fn is_open(&self) -> bool {
         self.state_matches(&State::Open)
     }
 
+    /// Returns `true` if this `ChannelEnd` is in state [`State::Closed`].
+    pub fn is_closed(&self) -> bool {
+        self.state_matches(&State::Closed)
+    }
+
     pub fn state(&self) -> &State {
         &self.state
     }
@@ -231,14 +318,56 @@ impl ChannelEnd {
         &self.version
     }
 
+    /// Checks that this end is well-formed.
+    ///
+    /// The connection hops must be non-empty and must not repeat a
+    /// connection; in addition, only single-hop channels are supported.
+    ///
+    /// Only the first violation is returned; see
+    /// [`validate_all`](Self::validate_all) to collect all of them.
     pub fn validate_basic(&self) -> Result<(), ChannelError> {
-        if self.connection_hops.len() != 1 {
-            return Err(ChannelError::InvalidConnectionHopsLength {
+        match self.violations().into_iter().next() {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks that this end is well-formed, like
+    /// [`validate_basic`](Self::validate_basic), but returns every violation
+    /// rather than only the first.
+    pub fn validate_all(&self) -> Result<(), ValidationErrors> {
+        let violations = self.violations();
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationErrors(violations))
+        }
+    }
+
+    fn violations(&self) -> Vec<ChannelError> {
+        let mut violations = Vec::new();
+
+        let has_duplicates = self
+            .connection_hops
+            .iter()
+            .enumerate()
+            .any(|(i, hop)| self.connection_hops[..i].contains(hop));
+        if self.connection_hops.is_empty() || has_duplicates {
+            violations.push(ChannelError::InvalidConnectionHops {
+                connection_hops: self.connection_hops.clone(),
+            });
+        }
+        if !self.connection_hops.is_empty() && self.connection_hops.len() != 1 {
+            violations.push(ChannelError::InvalidConnectionHopsLength {
                 expected: 1,
                 actual: self.connection_hops.len(),
             });
         }
-        self.counterparty().validate_basic()
+        if let Err(e) = self.counterparty().validate_basic() {
+            violations.push(e);
+        }
+
+        violations
     }
 
     /// Helper function to compare the state of this end with another state.
@@ -246,6 +375,18 @@ impl ChannelEnd {
         self.state.eq(other)
     }
 
+    /// Checks that this end is in the `expected` state, as handlers must
+    /// before acting on a channel.
+    pub fn verify_state_matches(&self, expected: &State) -> Result<(), ChannelError> {
+        if !self.state_matches(expected) {
+            return Err(ChannelError::InvalidState {
+                expected: *expected,
+                actual: self.state,
+            });
+        }
+        Ok(())
+    }
+
     /// Helper function to compare the order of this end with another order.
     pub fn order_matches(&self, other: &Order) -> bool {
         self.ordering.eq(other)
@@ -262,6 +403,76 @@ impl ChannelEnd {
     pub fn version_matches(&self, other: &Version) -> bool {
         self.version().eq(other)
     }
+
+    /// Returns the length of this end's [`RawChannel`] encoding, without
+    /// cloning it into a [`RawChannel`] first.
+    pub fn encoded_len_borrowed(&self) -> usize {
+        encoding::enumeration_len(1, self.state as i32)
+            + encoding::enumeration_len(2, self.ordering as i32)
+            + encoding::length_delimited_len(3, self.remote.raw_encoded_len())
+            + encoding::repeated_bytes_len(4, self.connection_hops.iter().map(|c| c.as_bytes()))
+            + encoding::bytes_len(5, self.version.as_str().as_bytes())
+            + encoding::uint64_len(6, self.upgrade_sequence)
+    }
+
+    /// Encodes this end as a [`RawChannel`] into `buf`, without cloning it
+    /// first. The output is identical to encoding the owned proto type.
+    pub fn encode_borrowed(&self, buf: &mut impl BufMut) {
+        encoding::encode_enumeration(1, self.state as i32, buf);
+        encoding::encode_enumeration(2, self.ordering as i32, buf);
+        encoding::encode_length_delimited_header(3, self.remote.raw_encoded_len(), buf);
+        self.remote.encode_raw(buf);
+        encoding::encode_repeated_bytes(4, self.connection_hops.iter().map(|c| c.as_bytes()), buf);
+        encoding::encode_bytes(5, self.version.as_str().as_bytes(), buf);
+        encoding::encode_uint64(6, self.upgrade_sequence, buf);
+    }
+
+    /// Encodes this end as a [`RawChannel`] into a new byte vector, without
+    /// cloning it first.
+    pub fn encode_to_vec_borrowed(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(self.encoded_len_borrowed());
+        self.encode_borrowed(&mut buf);
+        buf
+    }
+
+    /// Appends this end's [`RawChannel`] encoding to `buf`, reserving the
+    /// encoded length up front so that `buf` is grown at most once.
+    pub fn encode_to(&self, buf: &mut Vec<u8>) {
+        buf.reserve(self.encoded_len_borrowed());
+        self.encode_borrowed(buf);
+    }
+
+    /// Returns the bytes a counterparty chain commits to for this end, i.e.
+    /// the expected value when verifying a proof of this channel.
+    ///
+    /// This is the [`RawChannel`] encoding, as in ibc-go.
+    pub fn encode_for_proof(&self) -> Vec<u8> {
+        self.encode_to_vec_borrowed()
+    }
+
+    /// Lists the fields that differ between this end and `other`, e.g. to
+    /// review a proposed channel upgrade.
+    pub fn diff(&self, other: &ChannelEnd) -> Vec<FieldChange> {
+        [
+            FieldChange::compare("state", &self.state, &other.state),
+            FieldChange::compare("ordering", &self.ordering, &other.ordering),
+            FieldChange::compare("remote", &self.remote, &other.remote),
+            FieldChange::compare(
+                "connection_hops",
+                &self.connection_hops,
+                &other.connection_hops,
+            ),
+            FieldChange::compare("version", &self.version, &other.version),
+            FieldChange::compare(
+                "upgrade_sequence",
+                &self.upgrade_sequence,
+                &other.upgrade_sequence,
+            ),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
@@ -276,9 +487,9 @@ pub struct Counterparty {
 }
 
 impl Counterparty {
-    pub fn new(port_id: PortId, channel_id: Option<ChannelId>) -> Self {
+    pub fn new(port_id: impl Into<PortId>, channel_id: Option<ChannelId>) -> Self {
         Self {
-            port_id,
+            port_id: port_id.into(),
             channel_id,
         }
     }
@@ -287,6 +498,10 @@ impl Counterparty {
         &self.port_id
     }
 
+    /// The counterparty's channel identifier, which is `None` until the
+    /// counterparty has opened its end of the channel (i.e. while the
+    /// handshake is in `Init`, possibly with packets already sent
+    /// optimistically).
     pub fn channel_id(&self) -> Option<&ChannelId> {
         self.channel_id.as_ref()
     }
@@ -294,6 +509,22 @@ impl Counterparty {
     pub fn validate_basic(&self) -> Result<(), ChannelError> {
         Ok(())
     }
+
+    fn channel_id_bytes(&self) -> &[u8] {
+        self.channel_id.as_ref().map_or(&[][..], |id| id.as_bytes())
+    }
+
+    /// Length of this counterparty's [`RawCounterparty`] encoding.
+    fn raw_encoded_len(&self) -> usize {
+        encoding::bytes_len(1, self.port_id.as_bytes())
+            + encoding::bytes_len(2, self.channel_id_bytes())
+    }
+
+    /// Encodes this counterparty as a [`RawCounterparty`], without cloning it first.
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        encoding::encode_bytes(1, self.port_id.as_bytes(), buf);
+        encoding::encode_bytes(2, self.channel_id_bytes(), buf);
+    }
 }
 
 impl Display for Counterparty {
@@ -333,7 +564,7 @@ impl TryFrom<RawCounterparty> for Counterparty {
         Ok(Counterparty::new(
             raw_counterparty
                 .port_id
-                .parse()
+                .parse::<PortId>()
                 .map_err(ChannelError::Identifier)?,
             channel_id,
         ))
@@ -351,20 +582,17 @@ impl From<Counterparty> for RawCounterparty {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-#[cfg_attr(feature = "with_serde", derive(serde::Serialize, serde::Deserialize))]
+/// With the `with_serde` feature, an `Order` serializes as its proto enum
+/// string (e.g. `"ORDER_ORDERED"`), matching ibc-go's JSON encoding. Both the
+/// string and the numeric proto forms are accepted when deserializing.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum Order {
     None = 0isize,
+    #[default]
     Unordered = 1isize,
     Ordered = 2isize,
 }
 
-impl Default for Order {
-    fn default() -> Self {
-        Order::Unordered
-    }
-}
-
 impl Display for Order {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
         write!(f, "{}", self.as_str())
@@ -395,12 +623,16 @@ impl Order {
     }
 }
 
+/// Parses both the proto enum names emitted by [`Order::as_str`] (e.g.
+/// `ORDER_ORDERED`) and the unprefixed forms (e.g. `ORDERED`) that
+/// cosmos-sdk emits in some events, case-insensitively.
 impl FromStr for Order {
     type Err = ChannelError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.to_lowercase().trim_start_matches("order_") {
-            "uninitialized" => Ok(Self::None),
+        let lowercase = s.to_lowercase();
+        match lowercase.strip_prefix("order_").unwrap_or(&lowercase) {
+            "uninitialized" | "none_unspecified" => Ok(Self::None),
             "unordered" => Ok(Self::Unordered),
             "ordered" => Ok(Self::Ordered),
             _ => Err(ChannelError::UnknownOrderType {
@@ -410,6 +642,56 @@ impl FromStr for Order {
     }
 }
 
+#[cfg(feature = "with_serde")]
+impl serde::Serialize for Order {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "with_serde")]
+impl<'de> serde::Deserialize<'de> for Order {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::{Error, Unexpected, Visitor};
+
+        struct OrderVisitor;
+
+        impl<'de> Visitor<'de> for OrderVisitor {
+            type Value = Order;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+                f.write_str("a channel order as a proto enum string or number")
+            }
+
+            fn visit_str<E: Error>(self, v: &str) -> Result<Order, E> {
+                [Order::None, Order::Unordered, Order::Ordered]
+                    .into_iter()
+                    .find(|order| order.as_str() == v)
+                    .map_or_else(|| v.parse().map_err(E::custom), Ok)
+            }
+
+            fn visit_i64<E: Error>(self, v: i64) -> Result<Order, E> {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|v| Order::from_i32(v).ok())
+                    .ok_or_else(|| E::invalid_value(Unexpected::Signed(v), &self))
+            }
+
+            fn visit_u64<E: Error>(self, v: u64) -> Result<Order, E> {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|v| Order::from_i32(v).ok())
+                    .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(v), &self))
+            }
+        }
+
+        deserializer.deserialize_any(OrderVisitor)
+    }
+}
+
+/// With the `with_serde` feature, a `State` serializes as its proto enum
+/// string (e.g. `"STATE_OPEN"`), matching ibc-go's JSON encoding. Both the
+/// string and the numeric proto forms are accepted when deserializing.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum State {
     Uninitialized = 0isize,
@@ -420,8 +702,9 @@ pub enum State {
 }
 
 impl State {
-    /// Yields the state as a string
-    pub fn as_string(&self) -> &'static str {
+    /// Yields the state as a string, using the short state names ibc-go
+    /// gives the proto enum variants (e.g. `OPEN` for `STATE_OPEN`).
+    pub fn as_str(&self) -> &'static str {
         match self {
             Self::Uninitialized => "UNINITIALIZED",
             Self::Init => "INIT",
@@ -431,6 +714,24 @@ impl State {
         }
     }
 
+    /// Yields the state as a string, see [`State::as_str`].
+    pub fn as_string(&self) -> &'static str {
+        self.as_str()
+    }
+
+    /// Yields the full name of the proto enum variant for this state
+    /// (e.g. `STATE_OPEN`).
+    pub fn as_proto_str(&self) -> &'static str {
+        match self {
+            // Note: taken from [ibc-go](https://github.com/cosmos/ibc-go/blob/e3a32a61098d463cd00b8937e18cb671bd20c6b7/modules/core/04-channel/types/channel.pb.go#L46-L52)
+            Self::Uninitialized => "STATE_UNINITIALIZED_UNSPECIFIED",
+            Self::Init => "STATE_INIT",
+            Self::TryOpen => "STATE_TRYOPEN",
+            Self::Open => "STATE_OPEN",
+            Self::Closed => "STATE_CLOSED",
+        }
+    }
+
     // Parses the State out from a i32.
     pub fn from_i32(s: i32) -> Result<Self, ChannelError> {
         match s {
@@ -465,7 +766,60 @@ impl State {
 /// Provides a `to_string` method.
 impl Display for State {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
-        write!(f, "{}", self.as_string())
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(feature = "with_serde")]
+impl serde::Serialize for State {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_proto_str())
+    }
+}
+
+#[cfg(feature = "with_serde")]
+impl<'de> serde::Deserialize<'de> for State {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::{Error, Unexpected, Visitor};
+
+        struct StateVisitor;
+
+        impl<'de> Visitor<'de> for StateVisitor {
+            type Value = State;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+                f.write_str("a channel state as a proto enum string or number")
+            }
+
+            fn visit_str<E: Error>(self, v: &str) -> Result<State, E> {
+                [
+                    State::Uninitialized,
+                    State::Init,
+                    State::TryOpen,
+                    State::Open,
+                    State::Closed,
+                ]
+                .into_iter()
+                .find(|state| state.as_proto_str() == v)
+                .ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+            }
+
+            fn visit_i64<E: Error>(self, v: i64) -> Result<State, E> {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|v| State::from_i32(v).ok())
+                    .ok_or_else(|| E::invalid_value(Unexpected::Signed(v), &self))
+            }
+
+            fn visit_u64<E: Error>(self, v: u64) -> Result<State, E> {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|v| State::from_i32(v).ok())
+                    .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(v), &self))
+            }
+        }
+
+        deserializer.deserialize_any(StateVisitor)
     }
 }
 
@@ -602,6 +956,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn channel_end_decode_rejects_unknown_state() {
+        use prost::Message;
+
+        // prost keeps unknown enum discriminants as raw `i32`s, so they must
+        // be rejected on conversion rather than decoding as `Uninitialized`.
+        let bytes = RawChannel {
+            state: 7,
+            ..get_dummy_raw_channel_end(Some(0))
+        }
+        .encode_to_vec();
+        let raw = RawChannel::decode(bytes.as_slice()).unwrap();
+        assert_eq!(raw.state, 7);
+        assert!(matches!(
+            ChannelEnd::try_from(raw),
+            Err(ChannelError::UnknownState { state: 7 })
+        ));
+        assert!(<ChannelEnd as Protobuf<RawChannel>>::decode_vec(&bytes).is_err());
+    }
+
     #[test]
     fn parse_channel_ordering_type() {
         use super::Order;
@@ -627,11 +1001,36 @@ mod tests {
                 want_res: Order::Ordered,
                 want_err: false,
             },
+            Test {
+                ordering: "ORDER_NONE_UNSPECIFIED",
+                want_res: Order::None,
+                want_err: false,
+            },
+            Test {
+                ordering: "ORDER_UNORDERED",
+                want_res: Order::Unordered,
+                want_err: false,
+            },
+            Test {
+                ordering: "ORDER_ORDERED",
+                want_res: Order::Ordered,
+                want_err: false,
+            },
+            Test {
+                ordering: "order_ordered",
+                want_res: Order::Ordered,
+                want_err: false,
+            },
             Test {
                 ordering: "UNKNOWN_ORDER",
                 want_res: Order::None,
                 want_err: true,
             },
+            Test {
+                ordering: "ORDER_ORDER_ORDERED",
+                want_res: Order::None,
+                want_err: true,
+            },
         ]
         .into_iter()
         .collect();
@@ -645,5 +1044,408 @@ mod tests {
                 Err(_) => assert!(test.want_err, "parse failed"),
             }
         }
+
+        for order in [Order::None, Order::Unordered, Order::Ordered] {
+            assert_eq!(Order::from_str(&order.to_string()).unwrap(), order);
+        }
+    }
+
+    #[test]
+    fn channel_end_validate_connection_hops() {
+        let end_with_hops = |connection_hops: Vec<ConnectionId>| {
+            ChannelEnd::new(
+                State::Init,
+                Order::Unordered,
+                Counterparty::new(PortId::transfer(), None),
+                connection_hops,
+                Version::empty(),
+                0,
+            )
+        };
+
+        assert!(end_with_hops(vec![ConnectionId::new(0)])
+            .validate_basic()
+            .is_ok());
+        assert!(matches!(
+            end_with_hops(vec![]).validate_basic(),
+            Err(ChannelError::InvalidConnectionHops { connection_hops }) if connection_hops.is_empty()
+        ));
+        assert!(matches!(
+            end_with_hops(vec![ConnectionId::new(0), ConnectionId::new(0)]).validate_basic(),
+            Err(ChannelError::InvalidConnectionHops { .. })
+        ));
+        assert!(matches!(
+            end_with_hops(vec![
+                ConnectionId::new(0),
+                ConnectionId::new(1),
+                ConnectionId::new(0)
+            ])
+            .validate_basic(),
+            Err(ChannelError::InvalidConnectionHops { .. })
+        ));
+        // distinct hops are well-formed, but multi-hop channels are unsupported
+        assert!(matches!(
+            end_with_hops(vec![ConnectionId::new(0), ConnectionId::new(1)]).validate_basic(),
+            Err(ChannelError::InvalidConnectionHopsLength {
+                expected: 1,
+                actual: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn channel_end_state_predicates() {
+        let states = [
+            State::Uninitialized,
+            State::Init,
+            State::TryOpen,
+            State::Open,
+            State::Closed,
+        ];
+
+        for state in states {
+            let mut end = ChannelEnd::default();
+            end.set_state(state);
+            assert_eq!(end.is_uninitialized(), state == State::Uninitialized);
+            assert_eq!(end.is_init(), state == State::Init);
+            assert_eq!(end.is_tryopen(), state == State::TryOpen);
+            assert_eq!(end.is_open(), state == State::Open);
+            assert_eq!(end.is_closed(), state == State::Closed);
+        }
+    }
+
+    #[test]
+    fn channel_end_matchers() {
+        let counterparty = Counterparty::new(PortId::transfer(), Some(ChannelId::new(3)));
+        let end = ChannelEnd::new_open(
+            Order::Ordered,
+            counterparty.clone(),
+            vec![ConnectionId::new(0)],
+            Version::new("ics20-1".to_string()),
+        );
+
+        assert!(end.order_matches(&Order::Ordered));
+        assert!(!end.order_matches(&Order::Unordered));
+
+        assert!(end.counterparty_matches(&counterparty));
+        assert!(!end.counterparty_matches(&Counterparty::new(
+            PortId::transfer(),
+            Some(ChannelId::new(4))
+        )));
+        assert!(!end.counterparty_matches(&Counterparty::new(PortId::transfer(), None)));
+
+        end.verify_state_matches(&State::Open).unwrap();
+        assert!(matches!(
+            end.verify_state_matches(&State::Closed),
+            Err(ChannelError::InvalidState {
+                expected: State::Closed,
+                actual: State::Open,
+            })
+        ));
+    }
+
+    #[test]
+    fn counterparty_without_channel_id() {
+        let counterparty = Counterparty::new(PortId::transfer(), None);
+        assert_eq!(counterparty.port_id(), &PortId::transfer());
+        assert_eq!(counterparty.channel_id(), None);
+        counterparty.validate_basic().unwrap();
+
+        // a missing channel id is encoded as an empty string
+        let raw = RawCounterparty::from(counterparty.clone());
+        assert!(raw.channel_id.is_empty());
+        assert_eq!(Counterparty::try_from(raw).unwrap(), counterparty);
+    }
+
+    #[test]
+    fn channel_end_validate_all() {
+        let end_with_hops = |connection_hops: Vec<ConnectionId>| {
+            ChannelEnd::new(
+                State::Init,
+                Order::Unordered,
+                Counterparty::new(PortId::transfer(), None),
+                connection_hops,
+                Version::empty(),
+                0,
+            )
+        };
+
+        assert!(end_with_hops(vec![ConnectionId::new(0)])
+            .validate_all()
+            .is_ok());
+
+        // repeated hops are also too many hops
+        let end = end_with_hops(vec![ConnectionId::new(0), ConnectionId::new(0)]);
+        let errors = end.validate_all().unwrap_err();
+        assert_eq!(errors.0.len(), 2);
+        assert!(errors.to_string().starts_with("2 validation error(s): "));
+        assert!(matches!(
+            errors.0[0],
+            ChannelError::InvalidConnectionHops { .. }
+        ));
+        assert!(matches!(
+            errors.0[1],
+            ChannelError::InvalidConnectionHopsLength {
+                expected: 1,
+                actual: 2
+            }
+        ));
+        // validate_basic reports only the first of them
+        assert!(matches!(
+            end.validate_basic(),
+            Err(ChannelError::InvalidConnectionHops { .. })
+        ));
+
+        let errors = end_with_hops(vec![]).validate_all().unwrap_err();
+        assert_eq!(errors.0.len(), 1);
+    }
+
+    #[test]
+    fn identified_channel_end_try_new() {
+        let end = ChannelEnd::new(
+            State::Init,
+            Order::Unordered,
+            Counterparty::new(PortId::transfer(), None),
+            vec![ConnectionId::new(0)],
+            Version::empty(),
+            0,
+        );
+        let identified =
+            IdentifiedChannelEnd::try_new(PortId::transfer(), ChannelId::new(0), end.clone(), 0)
+                .expect("valid end is accepted");
+        assert_eq!(identified.channel_end, end);
+
+        let no_hops = ChannelEnd {
+            connection_hops: vec![],
+            ..end
+        };
+        assert!(matches!(
+            IdentifiedChannelEnd::try_new(PortId::transfer(), ChannelId::new(0), no_hops, 0),
+            Err(ChannelError::InvalidConnectionHops { .. })
+        ));
+    }
+
+    #[test]
+    fn state_as_str() {
+        let tests = [
+            (State::Uninitialized, "UNINITIALIZED"),
+            (State::Init, "INIT"),
+            (State::TryOpen, "TRYOPEN"),
+            (State::Open, "OPEN"),
+            (State::Closed, "CLOSED"),
+        ];
+
+        for (state, expected) in tests {
+            assert_eq!(state.as_str(), expected);
+            assert_eq!(state.as_string(), expected);
+            assert_eq!(state.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn channel_end_new_open() {
+        let counterparty = Counterparty::new(PortId::transfer(), Some(ChannelId::new(3)));
+        let end = ChannelEnd::new_open(
+            Order::Ordered,
+            counterparty.clone(),
+            vec![ConnectionId::new(0)],
+            Version::new("ics20-1".to_string()),
+        );
+
+        assert_eq!(end.state, State::Open);
+        assert!(end.is_open());
+        assert_eq!(end.ordering, Order::Ordered);
+        assert_eq!(end.remote, counterparty);
+        assert_eq!(end.connection_hops, vec![ConnectionId::new(0)]);
+        assert_eq!(end.version, Version::new("ics20-1".to_string()));
+        assert_eq!(end.upgrade_sequence, 0);
+        assert!(end.validate_basic().is_ok());
+    }
+
+    #[test]
+    fn channel_end_with_fields() {
+        let open = ChannelEnd::new_open(
+            Order::Ordered,
+            Counterparty::new(PortId::transfer(), Some(ChannelId::new(3))),
+            vec![ConnectionId::new(0)],
+            Version::new("ics20-1".to_string()),
+        );
+        let counterparty = Counterparty::new(PortId::transfer(), None);
+
+        let end = open
+            .clone()
+            .with_state(State::Init)
+            .with_ordering(Order::Unordered)
+            .with_counterparty(counterparty.clone())
+            .with_connection_hops(vec![ConnectionId::new(5)])
+            .with_version(Version::new("ics20-2".to_string()))
+            .with_upgrade_sequence(2);
+
+        assert_eq!(
+            end,
+            ChannelEnd::new(
+                State::Init,
+                Order::Unordered,
+                counterparty,
+                vec![ConnectionId::new(5)],
+                Version::new("ics20-2".to_string()),
+                2,
+            )
+        );
+        assert!(end.validate_basic().is_ok());
+
+        // fields that are not replaced are left as they were
+        let closed = open.clone().with_state(State::Closed);
+        assert_eq!(closed.state, State::Closed);
+        assert_eq!(closed.with_state(State::Open), open);
+    }
+
+    #[test]
+    fn channel_end_encode_for_proof() {
+        let end = ChannelEnd::new_open(
+            Order::Ordered,
+            Counterparty::new(PortId::transfer(), Some(ChannelId::new(3))),
+            vec![ConnectionId::new(0)],
+            Version::new("ics20-1".to_string()),
+        );
+
+        let expected = subtle_encoding::hex::decode(
+            "080310021a150a087472616e7366657212096368616e6e656c2d33220c636f6e6e65\
+             6374696f6e2d302a0769637332302d31",
+        )
+        .unwrap();
+        assert_eq!(end.encode_for_proof(), expected);
+        assert_eq!(end.encode_for_proof(), end.encode_vec());
+    }
+
+    #[test]
+    fn encode_borrowed_matches_owned() {
+        use prost::Message;
+
+        let open = ChannelEnd::new(
+            State::Open,
+            Order::Unordered,
+            Counterparty::new(PortId::transfer(), Some(ChannelId::new(42))),
+            vec![ConnectionId::new(0), ConnectionId::new(1)],
+            Version::new("ics20-1".to_string()),
+            3,
+        );
+        let init = ChannelEnd::new(
+            State::Init,
+            Order::Ordered,
+            Counterparty::new(PortId::default(), None),
+            vec![],
+            Version::empty(),
+            0,
+        );
+
+        for end in [open, init, ChannelEnd::default()] {
+            let owned = RawChannel::from(end.clone()).encode_to_vec();
+            assert_eq!(end.encode_to_vec_borrowed(), owned);
+            assert_eq!(end.encoded_len_borrowed(), owned.len());
+        }
+    }
+
+    #[test]
+    fn encode_to_matches_encode_to_vec() {
+        let end = ChannelEnd::new(
+            State::Open,
+            Order::Unordered,
+            Counterparty::new(PortId::transfer(), Some(ChannelId::new(42))),
+            vec![ConnectionId::new(0)],
+            Version::new("ics20-1".to_string()),
+            3,
+        );
+
+        let mut buf = Vec::new();
+        end.encode_to(&mut buf);
+        assert_eq!(buf, end.clone().encode_vec());
+
+        // encodings are appended to what is already in the buffer
+        end.encode_to(&mut buf);
+        assert_eq!(
+            buf,
+            [end.clone().encode_vec(), end.clone().encode_vec()].concat()
+        );
+    }
+
+    #[cfg(feature = "with_serde")]
+    #[test]
+    fn order_serde_uses_proto_strings() {
+        use super::Order;
+
+        for (order, name, number) in [
+            (Order::None, "ORDER_NONE_UNSPECIFIED", 0),
+            (Order::Unordered, "ORDER_UNORDERED", 1),
+            (Order::Ordered, "ORDER_ORDERED", 2),
+        ] {
+            let json = serde_json::to_value(order).unwrap();
+            assert_eq!(json, serde_json::Value::String(name.to_string()));
+            assert_eq!(serde_json::from_value::<Order>(json).unwrap(), order);
+            assert_eq!(
+                serde_json::from_value::<Order>(serde_json::json!(number)).unwrap(),
+                order
+            );
+        }
+
+        assert!(serde_json::from_str::<Order>("\"ORDER_SOMETIMES\"").is_err());
+        assert!(serde_json::from_str::<Order>("3").is_err());
+        assert!(serde_json::from_str::<Order>("-1").is_err());
+    }
+
+    #[cfg(feature = "with_serde")]
+    #[test]
+    fn state_serde_uses_proto_strings() {
+        for (state, name, number) in [
+            (State::Uninitialized, "STATE_UNINITIALIZED_UNSPECIFIED", 0),
+            (State::Init, "STATE_INIT", 1),
+            (State::TryOpen, "STATE_TRYOPEN", 2),
+            (State::Open, "STATE_OPEN", 3),
+            (State::Closed, "STATE_CLOSED", 4),
+        ] {
+            let json = serde_json::to_value(state).unwrap();
+            assert_eq!(json, serde_json::Value::String(name.to_string()));
+            assert_eq!(serde_json::from_value::<State>(json).unwrap(), state);
+            assert_eq!(
+                serde_json::from_value::<State>(serde_json::json!(number)).unwrap(),
+                state
+            );
+        }
+
+        assert!(serde_json::from_str::<State>("\"STATE_FLUSHING\"").is_err());
+        assert!(serde_json::from_str::<State>("5").is_err());
+    }
+
+    #[cfg(feature = "with_serde")]
+    #[test]
+    fn identified_channel_end_serde_round_trip() {
+        let end = IdentifiedChannelEnd::new(
+            PortId::transfer(),
+            ChannelId::new(7),
+            ChannelEnd::new(
+                State::Open,
+                Order::Ordered,
+                Counterparty::new(PortId::transfer(), Some(ChannelId::new(3))),
+                vec![ConnectionId::new(0)],
+                Version::new("ics20-1".to_string()),
+                0,
+            ),
+            0,
+        );
+
+        let json = serde_json::to_value(&end).unwrap();
+        assert_eq!(json["state"], "STATE_OPEN");
+        assert_eq!(json["ordering"], "ORDER_ORDERED");
+        assert_eq!(
+            serde_json::from_value::<IdentifiedChannelEnd>(json).unwrap(),
+            end
+        );
+
+        let json = serde_json::to_value(&end.channel_end).unwrap();
+        assert_eq!(json["state"], "STATE_OPEN");
+        assert_eq!(
+            serde_json::from_value::<ChannelEnd>(json).unwrap(),
+            end.channel_end
+        );
     }
 }
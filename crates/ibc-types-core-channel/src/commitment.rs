@@ -1,13 +1,28 @@
 use crate::prelude::*;
 
+use ibc_types_timestamp::Timestamp;
+use sha2::{Digest, Sha256};
+
+use crate::TimeoutHeight;
+
 /// Packet commitment
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct PacketCommitment(pub Vec<u8>);
 
 impl PacketCommitment {
+    /// Wraps the raw commitment hash bytes, e.g. as read from chain state.
+    pub fn from_vec(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+
     pub fn into_vec(self) -> Vec<u8> {
         self.0
     }
+
+    /// Returns the raw commitment hash bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
 }
 
 impl AsRef<[u8]> for PacketCommitment {
@@ -27,9 +42,19 @@ impl From<Vec<u8>> for PacketCommitment {
 pub struct AcknowledgementCommitment(pub Vec<u8>);
 
 impl AcknowledgementCommitment {
+    /// Wraps the raw commitment hash bytes, e.g. as read from chain state.
+    pub fn from_vec(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+
     pub fn into_vec(self) -> Vec<u8> {
         self.0
     }
+
+    /// Returns the raw commitment hash bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
 }
 
 impl AsRef<[u8]> for AcknowledgementCommitment {
@@ -43,3 +68,101 @@ impl From<Vec<u8>> for AcknowledgementCommitment {
         Self(bytes)
     }
 }
+
+/// Computes the commitment to a packet's data and timeouts, as stored by the
+/// sending chain.
+///
+/// This matches the cosmos-sdk (`ibc-go`) hashing scheme:
+/// `sha256(timeout_timestamp || revision_number || revision_height || sha256(data))`,
+/// with each integer encoded as 8 big-endian bytes, and with
+/// [`TimeoutHeight::Never`] and [`Timestamp::none`] committed as zero.
+pub fn compute_packet_commitment(
+    data: &[u8],
+    timeout_height: &TimeoutHeight,
+    timeout_timestamp: &Timestamp,
+) -> PacketCommitment {
+    let mut hasher = Sha256::new();
+    hasher.update(timeout_timestamp.nanoseconds().to_be_bytes());
+    hasher.update(timeout_height.commitment_revision_number().to_be_bytes());
+    hasher.update(timeout_height.commitment_revision_height().to_be_bytes());
+    hasher.update(Sha256::digest(data));
+
+    PacketCommitment(hasher.finalize().to_vec())
+}
+
+/// Computes the commitment to a packet acknowledgement, as stored by the
+/// receiving chain: the SHA-256 hash of the acknowledgement bytes.
+pub fn compute_ack_commitment(ack: &[u8]) -> AcknowledgementCommitment {
+    AcknowledgementCommitment(Sha256::digest(ack).to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    use ibc_types_core_client::Height;
+    use subtle_encoding::hex;
+
+    #[test]
+    fn packet_commitment_vectors() {
+        let at = |revision_number, revision_height| {
+            TimeoutHeight::At(Height::new(revision_number, revision_height).unwrap())
+        };
+        let timestamp = Timestamp::from_nanoseconds(1_700_000_000_000_000_000).unwrap();
+
+        let tests = [
+            (
+                at(0, 10),
+                Timestamp::none(),
+                "56be6a924be0269db802bcf9814d26306b81674d3ce730493300ce270748420a",
+            ),
+            (
+                at(1, 10),
+                timestamp,
+                "e3a5732ea83cddafa4036bad32125d460223cbcc719511179aa9f92ca6bf4113",
+            ),
+            (
+                TimeoutHeight::Never,
+                timestamp,
+                "da813c01f2c4ba8409b3a7bb9cb5756dbf875bbb31da91770d5de73180bb0828",
+            ),
+        ];
+
+        for (timeout_height, timeout_timestamp, expected) in tests {
+            let commitment =
+                compute_packet_commitment(b"hello", &timeout_height, &timeout_timestamp);
+            assert_eq!(
+                commitment,
+                PacketCommitment::from_vec(hex::decode(expected).unwrap())
+            );
+            assert_eq!(commitment.as_bytes().len(), 32);
+        }
+    }
+
+    #[test]
+    fn ack_commitment_vector() {
+        // the ICS-20 success acknowledgement
+        let commitment = compute_ack_commitment(br#"{"result":"AQ=="}"#);
+        assert_eq!(
+            commitment,
+            AcknowledgementCommitment::from_vec(
+                hex::decode("08f7557ed51826fe18d84512bf24ec75001edbaf2123a477df72a0a9f3640a7c")
+                    .unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn commitment_bytes() {
+        let bytes = vec![1, 2, 3];
+
+        let packet_commitment = PacketCommitment::from_vec(bytes.clone());
+        assert_eq!(packet_commitment.as_bytes(), &bytes[..]);
+        assert_eq!(packet_commitment.into_vec(), bytes);
+
+        let ack_commitment = AcknowledgementCommitment::from_vec(bytes.clone());
+        assert_eq!(ack_commitment.as_bytes(), &bytes[..]);
+        assert_eq!(ack_commitment.into_vec(), bytes);
+    }
+}
@@ -17,10 +17,14 @@ pub enum ChannelError {
     // Port(port_error::PortError),
     /// channel state unknown: `{state}`
     UnknownState { state: i32 },
+    /// invalid channel state: expected `{expected}`, actual `{actual}`
+    InvalidState { expected: State, actual: State },
     /// channel order type unknown: `{type_id}`
     UnknownOrderType { type_id: String },
     /// invalid connection hops length: expected `{expected}`; actual `{actual}`
     InvalidConnectionHopsLength { expected: usize, actual: usize },
+    /// invalid connection hops `{connection_hops:?}`: must be non-empty and free of duplicates
+    InvalidConnectionHops { connection_hops: Vec<ConnectionId> },
     /// invalid proof: missing height
     MissingHeight,
     /// packet data bytes must be valid UTF-8 (this restriction will be lifted in the future)
@@ -93,6 +97,11 @@ pub enum PacketError {
         port_id: PortId,
         channel_id: ChannelId,
     },
+    /// packet source port `{port_id}` and channel `{channel_id}` doesn't match the channel end's port/channel
+    InvalidPacketSource {
+        port_id: PortId,
+        channel_id: ChannelId,
+    },
     /// Client with id `{client_id}` is frozen
     FrozenClient { client_id: ClientId },
     /// Receiving chain block height `{chain_height}` >= packet timeout height `{timeout_height}`
@@ -179,29 +188,81 @@ pub enum PacketError {
     },
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for PacketError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for PacketError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match &self {
             Self::Connection(e) => Some(e),
             Self::Channel(e) => Some(e),
             Self::Identifier(e) => Some(e),
+            Self::InvalidPacketTimestamp(e) => Some(e),
             _ => None,
         }
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for ChannelError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+/// A collection of every [`ChannelError`] found while validating a value,
+/// as returned by [`ChannelEnd::validate_all`](crate::ChannelEnd::validate_all).
+#[derive(Debug)]
+pub struct ValidationErrors(pub Vec<ChannelError>);
+
+impl core::fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} validation error(s)", self.0.len())?;
+        for (i, e) in self.0.iter().enumerate() {
+            let sep = if i == 0 { ": " } else { "; " };
+            write!(f, "{sep}{e}")?;
+        }
+        Ok(())
+    }
+}
+
+impl core::error::Error for ValidationErrors {}
+
+impl core::error::Error for ChannelError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match &self {
             Self::Connection(e) => Some(e),
             Self::Identifier(e) => Some(e),
             Self::PacketVerificationFailed {
                 client_error: e, ..
             } => Some(e),
+            Self::VerifyChannelFailed(e) => Some(e),
             Self::InvalidStringAsSequence { error: e, .. } => Some(e),
             _ => None,
         }
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    use std::error::Error as _;
+
+    #[test]
+    fn packet_error_source_chain() {
+        let err = PacketError::Channel(ChannelError::PacketVerificationFailed {
+            sequence: 1.into(),
+            client_error: ClientError::HeaderVerificationFailure {
+                reason: "bad commitment".to_string(),
+            },
+        });
+
+        let channel_error = err.source().expect("packet error has a source");
+        assert!(channel_error.is::<ChannelError>());
+
+        let client_error = channel_error.source().expect("channel error has a source");
+        assert!(matches!(
+            client_error.downcast_ref::<ClientError>(),
+            Some(ClientError::HeaderVerificationFailure { reason }) if reason == "bad commitment"
+        ));
+        assert!(client_error.source().is_none());
+
+        let err = ChannelError::VerifyChannelFailed(ClientError::ImplementationSpecific);
+        assert!(err
+            .source()
+            .and_then(|e| e.downcast_ref::<ClientError>())
+            .is_some());
+    }
+}
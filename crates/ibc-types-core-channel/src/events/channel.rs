@@ -33,7 +33,7 @@ impl From<OpenInit> for Event {
                 ("port_id", event.port_id.0),
                 ("channel_id", event.channel_id.0),
                 ("counterparty_port_id", event.counterparty_port_id.0),
-                ("connection_id", event.connection_id.0),
+                ("connection_id", event.connection_id.into()),
                 ("version", event.version.0),
             ],
         )
@@ -58,21 +58,41 @@ impl TryFrom<Event> for OpenInit {
         for attr in event.attributes {
             match attr.key_bytes() {
                 b"port_id" => {
-                    port_id = Some(PortId(String::from_utf8_lossy(attr.value_bytes()).into()));
+                    port_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParsePortId { key: "port_id", e })?,
+                    );
                 }
                 b"channel_id" => {
-                    channel_id = Some(ChannelId(
-                        String::from_utf8_lossy(attr.value_bytes()).into(),
-                    ));
+                    channel_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParseChannelId {
+                                key: "channel_id",
+                                e,
+                            })?,
+                    );
                 }
                 b"counterparty_port_id" => {
-                    counterparty_port_id =
-                        Some(PortId(String::from_utf8_lossy(attr.value_bytes()).into()));
+                    counterparty_port_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParsePortId {
+                                key: "counterparty_port_id",
+                                e,
+                            })?,
+                    );
                 }
                 b"connection_id" => {
-                    connection_id = Some(ConnectionId(
-                        String::from_utf8_lossy(attr.value_bytes()).into(),
-                    ));
+                    connection_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParseConnectionId {
+                                key: "connection_id",
+                                e,
+                            })?,
+                    );
                 }
                 b"version" => {
                     version = Some(Version(String::from_utf8_lossy(attr.value_bytes()).into()));
@@ -121,7 +141,7 @@ impl From<OpenTry> for Event {
                 ("channel_id", event.channel_id.0),
                 ("counterparty_port_id", event.counterparty_port_id.0),
                 ("counterparty_channel_id", event.counterparty_channel_id.0),
-                ("connection_id", event.connection_id.0),
+                ("connection_id", event.connection_id.into()),
                 ("version", event.version.0),
             ],
         )
@@ -147,26 +167,51 @@ impl TryFrom<Event> for OpenTry {
         for attr in event.attributes {
             match attr.key_bytes() {
                 b"port_id" => {
-                    port_id = Some(PortId(String::from_utf8_lossy(attr.value_bytes()).into()));
+                    port_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParsePortId { key: "port_id", e })?,
+                    );
                 }
                 b"channel_id" => {
-                    channel_id = Some(ChannelId(
-                        String::from_utf8_lossy(attr.value_bytes()).into(),
-                    ));
+                    channel_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParseChannelId {
+                                key: "channel_id",
+                                e,
+                            })?,
+                    );
                 }
                 b"counterparty_port_id" => {
-                    counterparty_port_id =
-                        Some(PortId(String::from_utf8_lossy(attr.value_bytes()).into()));
+                    counterparty_port_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParsePortId {
+                                key: "counterparty_port_id",
+                                e,
+                            })?,
+                    );
                 }
                 b"counterparty_channel_id" => {
-                    counterparty_channel_id = Some(ChannelId(
-                        String::from_utf8_lossy(attr.value_bytes()).into(),
-                    ));
+                    counterparty_channel_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParseChannelId {
+                                key: "counterparty_channel_id",
+                                e,
+                            })?,
+                    );
                 }
                 b"connection_id" => {
-                    connection_id = Some(ConnectionId(
-                        String::from_utf8_lossy(attr.value_bytes()).into(),
-                    ));
+                    connection_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParseConnectionId {
+                                key: "connection_id",
+                                e,
+                            })?,
+                    );
                 }
                 b"version" => {
                     version = Some(Version(String::from_utf8_lossy(attr.value_bytes()).into()));
@@ -216,7 +261,7 @@ impl From<OpenAck> for Event {
                 ("channel_id", event.channel_id.0),
                 ("counterparty_port_id", event.counterparty_port_id.0),
                 ("counterparty_channel_id", event.counterparty_channel_id.0),
-                ("connection_id", event.connection_id.0),
+                ("connection_id", event.connection_id.into()),
             ],
         )
     }
@@ -240,26 +285,51 @@ impl TryFrom<Event> for OpenAck {
         for attr in event.attributes {
             match attr.key_bytes() {
                 b"port_id" => {
-                    port_id = Some(PortId(String::from_utf8_lossy(attr.value_bytes()).into()));
+                    port_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParsePortId { key: "port_id", e })?,
+                    );
                 }
                 b"channel_id" => {
-                    channel_id = Some(ChannelId(
-                        String::from_utf8_lossy(attr.value_bytes()).into(),
-                    ));
+                    channel_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParseChannelId {
+                                key: "channel_id",
+                                e,
+                            })?,
+                    );
                 }
                 b"counterparty_port_id" => {
-                    counterparty_port_id =
-                        Some(PortId(String::from_utf8_lossy(attr.value_bytes()).into()));
+                    counterparty_port_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParsePortId {
+                                key: "counterparty_port_id",
+                                e,
+                            })?,
+                    );
                 }
                 b"counterparty_channel_id" => {
-                    counterparty_channel_id = Some(ChannelId(
-                        String::from_utf8_lossy(attr.value_bytes()).into(),
-                    ));
+                    counterparty_channel_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParseChannelId {
+                                key: "counterparty_channel_id",
+                                e,
+                            })?,
+                    );
                 }
                 b"connection_id" => {
-                    connection_id = Some(ConnectionId(
-                        String::from_utf8_lossy(attr.value_bytes()).into(),
-                    ));
+                    connection_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParseConnectionId {
+                                key: "connection_id",
+                                e,
+                            })?,
+                    );
                 }
                 unknown => {
                     return Err(Error::UnexpectedAttribute(
@@ -305,7 +375,7 @@ impl From<OpenConfirm> for Event {
                 ("channel_id", event.channel_id.0),
                 ("counterparty_port_id", event.counterparty_port_id.0),
                 ("counterparty_channel_id", event.counterparty_channel_id.0),
-                ("connection_id", event.connection_id.0),
+                ("connection_id", event.connection_id.into()),
             ],
         )
     }
@@ -329,26 +399,51 @@ impl TryFrom<Event> for OpenConfirm {
         for attr in event.attributes {
             match attr.key_bytes() {
                 b"port_id" => {
-                    port_id = Some(PortId(String::from_utf8_lossy(attr.value_bytes()).into()));
+                    port_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParsePortId { key: "port_id", e })?,
+                    );
                 }
                 b"channel_id" => {
-                    channel_id = Some(ChannelId(
-                        String::from_utf8_lossy(attr.value_bytes()).into(),
-                    ));
+                    channel_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParseChannelId {
+                                key: "channel_id",
+                                e,
+                            })?,
+                    );
                 }
                 b"counterparty_port_id" => {
-                    counterparty_port_id =
-                        Some(PortId(String::from_utf8_lossy(attr.value_bytes()).into()));
+                    counterparty_port_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParsePortId {
+                                key: "counterparty_port_id",
+                                e,
+                            })?,
+                    );
                 }
                 b"counterparty_channel_id" => {
-                    counterparty_channel_id = Some(ChannelId(
-                        String::from_utf8_lossy(attr.value_bytes()).into(),
-                    ));
+                    counterparty_channel_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParseChannelId {
+                                key: "counterparty_channel_id",
+                                e,
+                            })?,
+                    );
                 }
                 b"connection_id" => {
-                    connection_id = Some(ConnectionId(
-                        String::from_utf8_lossy(attr.value_bytes()).into(),
-                    ));
+                    connection_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParseConnectionId {
+                                key: "connection_id",
+                                e,
+                            })?,
+                    );
                 }
                 unknown => {
                     return Err(Error::UnexpectedAttribute(
@@ -394,7 +489,7 @@ impl From<CloseInit> for Event {
                 ("channel_id", event.channel_id.0),
                 ("counterparty_port_id", event.counterparty_port_id.0),
                 ("counterparty_channel_id", event.counterparty_channel_id.0),
-                ("connection_id", event.connection_id.0),
+                ("connection_id", event.connection_id.into()),
             ],
         )
     }
@@ -418,26 +513,51 @@ impl TryFrom<Event> for CloseInit {
         for attr in event.attributes {
             match attr.key_bytes() {
                 b"port_id" => {
-                    port_id = Some(PortId(String::from_utf8_lossy(attr.value_bytes()).into()));
+                    port_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParsePortId { key: "port_id", e })?,
+                    );
                 }
                 b"channel_id" => {
-                    channel_id = Some(ChannelId(
-                        String::from_utf8_lossy(attr.value_bytes()).into(),
-                    ));
+                    channel_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParseChannelId {
+                                key: "channel_id",
+                                e,
+                            })?,
+                    );
                 }
                 b"counterparty_port_id" => {
-                    counterparty_port_id =
-                        Some(PortId(String::from_utf8_lossy(attr.value_bytes()).into()));
+                    counterparty_port_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParsePortId {
+                                key: "counterparty_port_id",
+                                e,
+                            })?,
+                    );
                 }
                 b"counterparty_channel_id" => {
-                    counterparty_channel_id = Some(ChannelId(
-                        String::from_utf8_lossy(attr.value_bytes()).into(),
-                    ));
+                    counterparty_channel_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParseChannelId {
+                                key: "counterparty_channel_id",
+                                e,
+                            })?,
+                    );
                 }
                 b"connection_id" => {
-                    connection_id = Some(ConnectionId(
-                        String::from_utf8_lossy(attr.value_bytes()).into(),
-                    ));
+                    connection_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParseConnectionId {
+                                key: "connection_id",
+                                e,
+                            })?,
+                    );
                 }
                 unknown => {
                     return Err(Error::UnexpectedAttribute(
@@ -483,7 +603,7 @@ impl From<CloseConfirm> for Event {
                 ("channel_id", event.channel_id.0),
                 ("counterparty_port_id", event.counterparty_port_id.0),
                 ("counterparty_channel_id", event.counterparty_channel_id.0),
-                ("connection_id", event.connection_id.0),
+                ("connection_id", event.connection_id.into()),
             ],
         )
     }
@@ -507,26 +627,51 @@ impl TryFrom<Event> for CloseConfirm {
         for attr in event.attributes {
             match attr.key_bytes() {
                 b"port_id" => {
-                    port_id = Some(PortId(String::from_utf8_lossy(attr.value_bytes()).into()));
+                    port_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParsePortId { key: "port_id", e })?,
+                    );
                 }
                 b"channel_id" => {
-                    channel_id = Some(ChannelId(
-                        String::from_utf8_lossy(attr.value_bytes()).into(),
-                    ));
+                    channel_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParseChannelId {
+                                key: "channel_id",
+                                e,
+                            })?,
+                    );
                 }
                 b"counterparty_port_id" => {
-                    counterparty_port_id =
-                        Some(PortId(String::from_utf8_lossy(attr.value_bytes()).into()));
+                    counterparty_port_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParsePortId {
+                                key: "counterparty_port_id",
+                                e,
+                            })?,
+                    );
                 }
                 b"counterparty_channel_id" => {
-                    counterparty_channel_id = Some(ChannelId(
-                        String::from_utf8_lossy(attr.value_bytes()).into(),
-                    ));
+                    counterparty_channel_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParseChannelId {
+                                key: "counterparty_channel_id",
+                                e,
+                            })?,
+                    );
                 }
                 b"connection_id" => {
-                    connection_id = Some(ConnectionId(
-                        String::from_utf8_lossy(attr.value_bytes()).into(),
-                    ));
+                    connection_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParseConnectionId {
+                                key: "connection_id",
+                                e,
+                            })?,
+                    );
                 }
                 unknown => {
                     return Err(Error::UnexpectedAttribute(
@@ -66,9 +66,10 @@ pub enum Error {
     MismatchedAcks,
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for Error {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for Error {
+    // subtle-encoding errors only implement `Error` with `std`
+    #[cfg(feature = "std")]
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         // Note: fill in if errors have causes
         match &self {
             Self::ParseChannelOrder { e, .. } => Some(e),
@@ -1,12 +1,13 @@
 use crate::{packet, prelude::*, Packet, TimeoutHeight};
 use alloc::borrow::ToOwned;
 
+use bytes::Bytes;
 use ibc_types_core_connection::ConnectionId;
 use ibc_types_timestamp::Timestamp;
 use subtle_encoding::hex;
 use tendermint::abci::{Event, TypedEvent};
 
-use crate::{channel::Order, ChannelId, PortId};
+use crate::{channel::Order, ChannelError, ChannelId, IdentifiedChannelEnd, PacketError, PortId};
 
 use super::Error;
 
@@ -51,7 +52,7 @@ impl From<ChannelClose> for Event {
                         .map(|id| id.0)
                         .unwrap_or_default(),
                 ),
-                ("connection_id", event.connection_id.0),
+                ("connection_id", event.connection_id.into()),
                 (
                     "packet_channel_ordering",
                     event.channel_ordering.as_str().to_owned(),
@@ -80,30 +81,55 @@ impl TryFrom<Event> for ChannelClose {
         for attr in event.attributes {
             match attr.key_bytes() {
                 b"port_id" => {
-                    port_id = Some(PortId(String::from_utf8_lossy(attr.value_bytes()).into()));
+                    port_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParsePortId { key: "port_id", e })?,
+                    );
                 }
                 b"channel_id" => {
-                    channel_id = Some(ChannelId(
-                        String::from_utf8_lossy(attr.value_bytes()).into(),
-                    ));
+                    channel_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParseChannelId {
+                                key: "channel_id",
+                                e,
+                            })?,
+                    );
                 }
                 b"counterparty_port_id" => {
-                    counterparty_port_id =
-                        Some(PortId(String::from_utf8_lossy(attr.value_bytes()).into()));
+                    counterparty_port_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParsePortId {
+                                key: "counterparty_port_id",
+                                e,
+                            })?,
+                    );
                 }
                 b"counterparty_channel_id" => {
                     counterparty_channel_id = if !attr.value_bytes().is_empty() {
-                        Some(ChannelId(
-                            String::from_utf8_lossy(attr.value_bytes()).into(),
-                        ))
+                        Some(
+                            String::from_utf8_lossy(attr.value_bytes())
+                                .parse()
+                                .map_err(|e| Error::ParseChannelId {
+                                    key: "counterparty_channel_id",
+                                    e,
+                                })?,
+                        )
                     } else {
                         None
                     };
                 }
                 b"connection_id" => {
-                    connection_id = Some(ConnectionId(
-                        String::from_utf8_lossy(attr.value_bytes()).into(),
-                    ));
+                    connection_id = Some(
+                        String::from_utf8_lossy(attr.value_bytes())
+                            .parse()
+                            .map_err(|e| Error::ParseConnectionId {
+                                key: "connection_id",
+                                e,
+                            })?,
+                    );
                 }
                 b"packet_channel_ordering" => {
                     channel_ordering = Some(
@@ -138,7 +164,7 @@ impl TryFrom<Event> for ChannelClose {
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct SendPacket {
-    pub packet_data: Vec<u8>,
+    pub packet_data: Bytes,
     pub timeout_height: TimeoutHeight,
     pub timeout_timestamp: Timestamp,
     pub sequence: packet::Sequence,
@@ -153,7 +179,11 @@ pub struct SendPacket {
 impl SendPacket {
     pub const TYPE_STR: &'static str = "send_packet";
 
-    pub fn new(packet: Packet, channel_ordering: Order, src_connection_id: ConnectionId) -> Self {
+    pub fn new(
+        packet: Packet,
+        channel_ordering: Order,
+        src_connection_id: impl Into<ConnectionId>,
+    ) -> Self {
         Self {
             packet_data: packet.data,
             timeout_height: packet.timeout_height_on_b,
@@ -164,8 +194,46 @@ impl SendPacket {
             dst_port_id: packet.port_on_b,
             dst_channel_id: packet.chan_on_b,
             channel_ordering,
-            src_connection_id,
+            src_connection_id: src_connection_id.into(),
+        }
+    }
+
+    /// Builds the event for sending `packet` over `channel`, taking the
+    /// ordering and connection id from the channel end.
+    ///
+    /// Unlike [`SendPacket::new`], this checks that the packet's source
+    /// port and channel are those of `channel`, and that its destination
+    /// port and channel are the channel's counterparty.
+    pub fn try_new(packet: Packet, channel: &IdentifiedChannelEnd) -> Result<Self, PacketError> {
+        if packet.port_on_a != channel.port_id || packet.chan_on_a != channel.channel_id {
+            return Err(PacketError::InvalidPacketSource {
+                port_id: packet.port_on_a,
+                channel_id: packet.chan_on_a,
+            });
         }
+
+        let end = &channel.channel_end;
+        if packet.port_on_b != end.remote.port_id
+            || end.remote.channel_id.as_ref() != Some(&packet.chan_on_b)
+        {
+            return Err(PacketError::InvalidPacketCounterparty {
+                port_id: packet.port_on_b,
+                channel_id: packet.chan_on_b,
+            });
+        }
+
+        let connection_id = end
+            .connection_hops
+            .first()
+            .cloned()
+            .ok_or(PacketError::Channel(
+                ChannelError::InvalidConnectionHopsLength {
+                    expected: 1,
+                    actual: 0,
+                },
+            ))?;
+
+        Ok(Self::new(packet, end.ordering, connection_id))
     }
 }
 
@@ -181,8 +249,8 @@ impl From<SendPacket> for Event {
         // Conditionally include packet_data only if UTF-8 encodable
         // TODO: what's the right behavior here?
         // original impl just errors out entirely, doesn't seem right
-        if let Ok(utf8_packet_data) = String::from_utf8(event.packet_data) {
-            attrs.push(("packet_data", utf8_packet_data));
+        if let Ok(utf8_packet_data) = core::str::from_utf8(&event.packet_data) {
+            attrs.push(("packet_data", utf8_packet_data.to_owned()));
         }
         attrs.push(("packet_timeout_height", event.timeout_height.to_string()));
         attrs.push((
@@ -198,7 +266,7 @@ impl From<SendPacket> for Event {
             "packet_channel_ordering",
             event.channel_ordering.as_str().to_owned(),
         ));
-        attrs.push(("packet_connection", event.src_connection_id.0));
+        attrs.push(("packet_connection", event.src_connection_id.into()));
 
         Event::new(SendPacket::TYPE_STR, attrs)
     }
@@ -213,7 +281,7 @@ impl TryFrom<Event> for SendPacket {
             });
         }
 
-        let mut packet_data = None;
+        let mut packet_data: Option<Bytes> = None;
         let mut timeout_height = None;
         let mut timeout_timestamp = None;
         let mut sequence = None;
@@ -228,26 +296,32 @@ impl TryFrom<Event> for SendPacket {
             match attr.key_bytes() {
                 b"packet_data" => {
                     let new_packet_data = attr.value_bytes();
-                    if let Some(existing_packet_data) = packet_data {
-                        if new_packet_data != existing_packet_data {
-                            return Err(Error::MismatchedPacketData);
-                        } else {
-                            packet_data = Some(new_packet_data.into());
+                    match &packet_data {
+                        Some(existing_packet_data)
+                            if new_packet_data != &existing_packet_data[..] =>
+                        {
+                            return Err(Error::MismatchedPacketData)
                         }
+                        Some(_) => {}
+                        // Only copy the attribute value when it becomes the packet data.
+                        None => packet_data = Some(Bytes::copy_from_slice(new_packet_data)),
                     }
                 }
                 b"packet_data_hex" => {
+                    // `Bytes::from` takes ownership of the decoded buffer without copying it.
                     let new_packet_data =
-                        hex::decode(attr.value_bytes()).map_err(|e| Error::ParseHex {
-                            key: "packet_data_hex",
-                            e,
-                        })?;
-                    if let Some(existing_packet_data) = packet_data {
-                        if new_packet_data != existing_packet_data {
-                            return Err(Error::MismatchedPacketData);
-                        } else {
-                            packet_data = Some(new_packet_data);
+                        Bytes::from(hex::decode(attr.value_bytes()).map_err(|e| {
+                            Error::ParseHex {
+                                key: "packet_data_hex",
+                                e,
+                            }
+                        })?);
+                    match &packet_data {
+                        Some(existing_packet_data) if new_packet_data != *existing_packet_data => {
+                            return Err(Error::MismatchedPacketData)
                         }
+                        Some(_) => {}
+                        None => packet_data = Some(new_packet_data),
                     }
                 }
                 b"packet_timeout_height" => {
@@ -376,7 +450,7 @@ impl TryFrom<Event> for SendPacket {
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ReceivePacket {
-    pub packet_data: Vec<u8>,
+    pub packet_data: Bytes,
     pub timeout_height: TimeoutHeight,
     pub timeout_timestamp: Timestamp,
     pub sequence: packet::Sequence,
@@ -391,7 +465,11 @@ pub struct ReceivePacket {
 impl ReceivePacket {
     pub const TYPE_STR: &'static str = "recv_packet";
 
-    pub fn new(packet: Packet, channel_ordering: Order, dst_connection_id: ConnectionId) -> Self {
+    pub fn new(
+        packet: Packet,
+        channel_ordering: Order,
+        dst_connection_id: impl Into<ConnectionId>,
+    ) -> Self {
         Self {
             packet_data: packet.data,
             timeout_height: packet.timeout_height_on_b,
@@ -402,7 +480,7 @@ impl ReceivePacket {
             dst_port_id: packet.port_on_b,
             dst_channel_id: packet.chan_on_b,
             channel_ordering,
-            dst_connection_id,
+            dst_connection_id: dst_connection_id.into(),
         }
     }
 }
@@ -419,8 +497,8 @@ impl From<ReceivePacket> for Event {
         // Conditionally include packet_data only if UTF-8 encodable
         // TODO: what's the right behavior here?
         // original impl just errors out entirely, doesn't seem right
-        if let Ok(utf8_packet_data) = String::from_utf8(event.packet_data) {
-            attrs.push(("packet_data", utf8_packet_data));
+        if let Ok(utf8_packet_data) = core::str::from_utf8(&event.packet_data) {
+            attrs.push(("packet_data", utf8_packet_data.to_owned()));
         }
         attrs.push(("packet_timeout_height", event.timeout_height.to_string()));
         attrs.push((
@@ -436,7 +514,7 @@ impl From<ReceivePacket> for Event {
             "packet_channel_ordering",
             event.channel_ordering.as_str().to_owned(),
         ));
-        attrs.push(("packet_connection", event.dst_connection_id.0));
+        attrs.push(("packet_connection", event.dst_connection_id.into()));
 
         Event::new(ReceivePacket::TYPE_STR, attrs)
     }
@@ -451,7 +529,7 @@ impl TryFrom<Event> for ReceivePacket {
             });
         }
 
-        let mut packet_data = None;
+        let mut packet_data: Option<Bytes> = None;
         let mut timeout_height = None;
         let mut timeout_timestamp = None;
         let mut sequence = None;
@@ -465,27 +543,33 @@ impl TryFrom<Event> for ReceivePacket {
         for attr in event.attributes {
             match attr.key_bytes() {
                 b"packet_data" => {
-                    let new_packet_data = attr.value_bytes().into();
-                    if let Some(existing_packet_data) = packet_data {
-                        if new_packet_data != existing_packet_data {
-                            return Err(Error::MismatchedPacketData);
-                        } else {
-                            packet_data = Some(new_packet_data);
+                    let new_packet_data = attr.value_bytes();
+                    match &packet_data {
+                        Some(existing_packet_data)
+                            if new_packet_data != &existing_packet_data[..] =>
+                        {
+                            return Err(Error::MismatchedPacketData)
                         }
+                        Some(_) => {}
+                        // Only copy the attribute value when it becomes the packet data.
+                        None => packet_data = Some(Bytes::copy_from_slice(new_packet_data)),
                     }
                 }
                 b"packet_data_hex" => {
+                    // `Bytes::from` takes ownership of the decoded buffer without copying it.
                     let new_packet_data =
-                        hex::decode(attr.value_bytes()).map_err(|e| Error::ParseHex {
-                            key: "packet_data_hex",
-                            e,
-                        })?;
-                    if let Some(existing_packet_data) = packet_data {
-                        if new_packet_data != existing_packet_data {
-                            return Err(Error::MismatchedPacketData);
-                        } else {
-                            packet_data = Some(new_packet_data);
+                        Bytes::from(hex::decode(attr.value_bytes()).map_err(|e| {
+                            Error::ParseHex {
+                                key: "packet_data_hex",
+                                e,
+                            }
+                        })?);
+                    match &packet_data {
+                        Some(existing_packet_data) if new_packet_data != *existing_packet_data => {
+                            return Err(Error::MismatchedPacketData)
                         }
+                        Some(_) => {}
+                        None => packet_data = Some(new_packet_data),
                     }
                 }
                 b"packet_timeout_height" => {
@@ -614,7 +698,7 @@ impl TryFrom<Event> for ReceivePacket {
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct WriteAcknowledgement {
-    pub packet_data: Vec<u8>,
+    pub packet_data: Bytes,
     pub timeout_height: TimeoutHeight,
     pub timeout_timestamp: Timestamp,
     pub sequence: packet::Sequence,
@@ -629,7 +713,11 @@ pub struct WriteAcknowledgement {
 impl WriteAcknowledgement {
     pub const TYPE_STR: &'static str = "write_acknowledgement";
 
-    pub fn new(packet: Packet, acknowledgement: Vec<u8>, dst_connection_id: ConnectionId) -> Self {
+    pub fn new(
+        packet: Packet,
+        acknowledgement: Vec<u8>,
+        dst_connection_id: impl Into<ConnectionId>,
+    ) -> Self {
         Self {
             packet_data: packet.data,
             timeout_height: packet.timeout_height_on_b,
@@ -640,7 +728,23 @@ impl WriteAcknowledgement {
             dst_port_id: packet.port_on_b,
             dst_channel_id: packet.chan_on_b,
             acknowledgement,
-            dst_connection_id,
+            dst_connection_id: dst_connection_id.into(),
+        }
+    }
+
+    /// Reconstructs the packet being acknowledged, i.e. the inverse of
+    /// [`WriteAcknowledgement::new`] without the acknowledgement and
+    /// connection id.
+    pub fn packet(&self) -> Packet {
+        Packet {
+            sequence: self.sequence,
+            port_on_a: self.src_port_id.clone(),
+            chan_on_a: self.src_channel_id.clone(),
+            port_on_b: self.dst_port_id.clone(),
+            chan_on_b: self.dst_channel_id.clone(),
+            data: self.packet_data.clone(),
+            timeout_height_on_b: self.timeout_height,
+            timeout_timestamp_on_b: self.timeout_timestamp,
         }
     }
 }
@@ -657,8 +761,8 @@ impl From<WriteAcknowledgement> for Event {
         // Conditionally include packet_data only if UTF-8 encodable
         // TODO: what's the right behavior here?
         // original impl just errors out entirely, doesn't seem right
-        if let Ok(utf8_packet_data) = String::from_utf8(event.packet_data) {
-            attrs.push(("packet_data", utf8_packet_data));
+        if let Ok(utf8_packet_data) = core::str::from_utf8(&event.packet_data) {
+            attrs.push(("packet_data", utf8_packet_data.to_owned()));
         }
         attrs.push(("packet_timeout_height", event.timeout_height.to_string()));
         attrs.push((
@@ -678,7 +782,7 @@ impl From<WriteAcknowledgement> for Event {
         if let Ok(utf8_ack_data) = String::from_utf8(event.acknowledgement) {
             attrs.push(("packet_ack", utf8_ack_data));
         }
-        attrs.push(("packet_connection", event.dst_connection_id.0));
+        attrs.push(("packet_connection", event.dst_connection_id.into()));
 
         Event::new(WriteAcknowledgement::TYPE_STR, attrs)
     }
@@ -693,7 +797,7 @@ impl TryFrom<Event> for WriteAcknowledgement {
             });
         }
 
-        let mut packet_data = None;
+        let mut packet_data: Option<Bytes> = None;
         let mut timeout_height = None;
         let mut timeout_timestamp = None;
         let mut sequence = None;
@@ -707,27 +811,33 @@ impl TryFrom<Event> for WriteAcknowledgement {
         for attr in event.attributes {
             match attr.key_bytes() {
                 b"packet_data" => {
-                    let new_packet_data = attr.value_bytes().into();
-                    if let Some(existing_packet_data) = packet_data {
-                        if new_packet_data != existing_packet_data {
-                            return Err(Error::MismatchedPacketData);
-                        } else {
-                            packet_data = Some(new_packet_data);
+                    let new_packet_data = attr.value_bytes();
+                    match &packet_data {
+                        Some(existing_packet_data)
+                            if new_packet_data != &existing_packet_data[..] =>
+                        {
+                            return Err(Error::MismatchedPacketData)
                         }
+                        Some(_) => {}
+                        // Only copy the attribute value when it becomes the packet data.
+                        None => packet_data = Some(Bytes::copy_from_slice(new_packet_data)),
                     }
                 }
                 b"packet_data_hex" => {
+                    // `Bytes::from` takes ownership of the decoded buffer without copying it.
                     let new_packet_data =
-                        hex::decode(attr.value_bytes()).map_err(|e| Error::ParseHex {
-                            key: "packet_data_hex",
-                            e,
-                        })?;
-                    if let Some(existing_packet_data) = packet_data {
-                        if new_packet_data != existing_packet_data {
-                            return Err(Error::MismatchedPacketData);
-                        } else {
-                            packet_data = Some(new_packet_data);
+                        Bytes::from(hex::decode(attr.value_bytes()).map_err(|e| {
+                            Error::ParseHex {
+                                key: "packet_data_hex",
+                                e,
+                            }
+                        })?);
+                    match &packet_data {
+                        Some(existing_packet_data) if new_packet_data != *existing_packet_data => {
+                            return Err(Error::MismatchedPacketData)
                         }
+                        Some(_) => {}
+                        None => packet_data = Some(new_packet_data),
                     }
                 }
                 b"packet_timeout_height" => {
@@ -807,13 +917,13 @@ impl TryFrom<Event> for WriteAcknowledgement {
                     );
                 }
                 b"packet_ack" => {
-                    let new_ack = attr.value_bytes().into();
-                    if let Some(existing_ack) = acknowledgement {
-                        if new_ack != existing_ack {
-                            return Err(Error::MismatchedAcks);
-                        } else {
-                            acknowledgement = Some(new_ack);
+                    let new_ack: Vec<u8> = attr.value_bytes().into();
+                    match &acknowledgement {
+                        Some(existing_ack) if new_ack != *existing_ack => {
+                            return Err(Error::MismatchedAcks)
                         }
+                        Some(_) => {}
+                        None => acknowledgement = Some(new_ack),
                     }
                 }
                 b"packet_ack_hex" => {
@@ -822,12 +932,12 @@ impl TryFrom<Event> for WriteAcknowledgement {
                         e,
                     })?;
 
-                    if let Some(existing_ack) = acknowledgement {
-                        if new_ack != existing_ack {
-                            return Err(Error::MismatchedAcks);
-                        } else {
-                            acknowledgement = Some(new_ack);
+                    match &acknowledgement {
+                        Some(existing_ack) if new_ack != *existing_ack => {
+                            return Err(Error::MismatchedAcks)
                         }
+                        Some(_) => {}
+                        None => acknowledgement = Some(new_ack),
                     }
                 }
                 b"packet_connection" => {
@@ -884,7 +994,11 @@ pub struct AcknowledgePacket {
 impl AcknowledgePacket {
     pub const TYPE_STR: &'static str = "acknowledge_packet";
 
-    pub fn new(packet: Packet, channel_ordering: Order, src_connection_id: ConnectionId) -> Self {
+    pub fn new(
+        packet: Packet,
+        channel_ordering: Order,
+        src_connection_id: impl Into<ConnectionId>,
+    ) -> Self {
         Self {
             timeout_height: packet.timeout_height_on_b,
             timeout_timestamp: packet.timeout_timestamp_on_b,
@@ -894,7 +1008,7 @@ impl AcknowledgePacket {
             dst_port_id: packet.port_on_b,
             dst_channel_id: packet.chan_on_b,
             channel_ordering,
-            src_connection_id,
+            src_connection_id: src_connection_id.into(),
         }
     }
 }
@@ -918,7 +1032,7 @@ impl From<AcknowledgePacket> for Event {
             "packet_channel_ordering",
             event.channel_ordering.as_str().to_owned(),
         ));
-        attrs.push(("packet_connection", event.src_connection_id.0));
+        attrs.push(("packet_connection", event.src_connection_id.into()));
 
         Event::new(AcknowledgePacket::TYPE_STR, attrs)
     }
@@ -1,9 +1,55 @@
-use crate::{prelude::*, ChannelId, PortId, Version};
+use core::fmt::Debug;
 
+use crate::{channel::Order, prelude::*, ChannelId, Packet, PortId, TimeoutHeight, Version};
+
+use ibc_types_core_client::Height;
 use ibc_types_core_connection::ConnectionId;
+use ibc_types_timestamp::Timestamp;
 use tendermint::abci::Event as AbciEvent;
 
 use super::channel::*;
+use super::packet::*;
+
+/// Converts `event` into an ABCI event and back, asserting nothing is lost.
+fn assert_abci_round_trip<E>(event: E)
+where
+    E: Clone + Debug + PartialEq + Into<AbciEvent> + TryFrom<AbciEvent>,
+    <E as TryFrom<AbciEvent>>::Error: Debug,
+{
+    let abci_event: AbciEvent = event.clone().into();
+    let parsed = E::try_from(abci_event.clone())
+        .unwrap_or_else(|e| panic!("failed to parse {abci_event:?}: {e:?}"));
+    assert_eq!(parsed, event);
+}
+
+fn dummy_packets() -> Vec<Packet> {
+    let packet = Packet {
+        sequence: 7u64.into(),
+        port_on_a: PortId::transfer(),
+        chan_on_a: ChannelId::new(0),
+        port_on_b: PortId::transfer(),
+        chan_on_b: ChannelId::new(1),
+        data: br#"{"amount":"100","denom":"upenumbra"}"#.to_vec().into(),
+        timeout_height_on_b: TimeoutHeight::At(Height::new(1, 100).unwrap()),
+        timeout_timestamp_on_b: Timestamp::from_nanoseconds(1_700_000_000_000_000_000).unwrap(),
+    };
+
+    vec![
+        // UTF-8 data is emitted under both `packet_data` and `packet_data_hex`
+        packet.clone(),
+        // non-UTF-8 data is only emitted under `packet_data_hex`
+        Packet {
+            data: vec![0xff, 0x00, 0xfe].into(),
+            ..packet.clone()
+        },
+        // no timeouts at all
+        Packet {
+            timeout_height_on_b: TimeoutHeight::Never,
+            timeout_timestamp_on_b: Timestamp::none(),
+            ..packet
+        },
+    ]
+}
 
 #[test]
 fn ibc_to_abci_channel_events() {
@@ -153,3 +199,327 @@ fn ibc_to_abci_channel_events() {
         }
     }
 }
+
+#[test]
+fn abci_channel_events_reject_invalid_identifiers() {
+    use super::Error;
+
+    let event = |port_id: &str, channel_id: &str| {
+        AbciEvent::new(
+            OpenInit::TYPE_STR,
+            [
+                ("port_id", port_id.to_string()),
+                ("channel_id", channel_id.to_string()),
+                ("counterparty_port_id", "transfer".to_string()),
+                ("connection_id", "connection-0".to_string()),
+                ("version", "ics20-1".to_string()),
+            ],
+        )
+    };
+
+    assert!(OpenInit::try_from(event("transfer", "channel-0")).is_ok());
+
+    assert!(matches!(
+        OpenInit::try_from(event("", "channel-0")),
+        Err(Error::ParsePortId { key: "port_id", .. })
+    ));
+
+    let over_length_channel_id = format!("channel-{}", "9".repeat(57));
+    assert!(matches!(
+        OpenInit::try_from(event("transfer", &over_length_channel_id)),
+        Err(Error::ParseChannelId {
+            key: "channel_id",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn abci_channel_close_rejects_invalid_identifiers() {
+    use super::Error;
+
+    let event = |channel_id: &str, counterparty_channel_id: &str, connection_id: &str| {
+        AbciEvent::new(
+            ChannelClose::TYPE_STR,
+            [
+                ("port_id", "transfer".to_string()),
+                ("channel_id", channel_id.to_string()),
+                ("counterparty_port_id", "transfer".to_string()),
+                (
+                    "counterparty_channel_id",
+                    counterparty_channel_id.to_string(),
+                ),
+                ("connection_id", connection_id.to_string()),
+                ("packet_channel_ordering", "ORDER_ORDERED".to_string()),
+            ],
+        )
+    };
+
+    assert!(ChannelClose::try_from(event("channel-0", "channel-1", "connection-0")).is_ok());
+    assert!(ChannelClose::try_from(event("channel-0", "", "connection-0")).is_ok());
+
+    assert!(matches!(
+        ChannelClose::try_from(event("channel/0", "channel-1", "connection-0")),
+        Err(Error::ParseChannelId {
+            key: "channel_id",
+            ..
+        })
+    ));
+    assert!(matches!(
+        ChannelClose::try_from(event("channel-0", "channel 1", "connection-0")),
+        Err(Error::ParseChannelId {
+            key: "counterparty_channel_id",
+            ..
+        })
+    ));
+    assert!(matches!(
+        ChannelClose::try_from(event("channel-0", "channel-1", "c")),
+        Err(Error::ParseConnectionId {
+            key: "connection_id",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn packet_proto_round_trip() {
+    use ibc_proto::ibc::core::channel::v1::Packet as RawPacket;
+
+    for packet in dummy_packets() {
+        let raw = RawPacket::from(packet.clone());
+        assert_eq!(Packet::try_from(raw).unwrap(), packet);
+    }
+}
+
+#[test]
+fn packet_events_abci_round_trip() {
+    let connection_id = ConnectionId::new(0);
+
+    for packet in dummy_packets() {
+        for ordering in [Order::Unordered, Order::Ordered] {
+            assert_abci_round_trip(SendPacket::new(
+                packet.clone(),
+                ordering,
+                connection_id.clone(),
+            ));
+            assert_abci_round_trip(ReceivePacket::new(
+                packet.clone(),
+                ordering,
+                connection_id.clone(),
+            ));
+            assert_abci_round_trip(AcknowledgePacket::new(
+                packet.clone(),
+                ordering,
+                connection_id.clone(),
+            ));
+            assert_abci_round_trip(TimeoutPacket::new(packet.clone(), ordering));
+        }
+
+        // UTF-8 and non-UTF-8 acknowledgements
+        for ack in [br#"{"result":"AQ=="}"#.to_vec(), vec![0xff, 0x01]] {
+            assert_abci_round_trip(WriteAcknowledgement::new(
+                packet.clone(),
+                ack,
+                connection_id.clone(),
+            ));
+        }
+    }
+
+    for counterparty_channel_id in [Some(ChannelId::new(1)), None] {
+        assert_abci_round_trip(ChannelClose {
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::new(0),
+            counterparty_port_id: PortId::transfer(),
+            counterparty_channel_id,
+            connection_id: connection_id.clone(),
+            channel_ordering: Order::Ordered,
+        });
+    }
+}
+
+#[test]
+fn send_packet_try_new() {
+    use crate::{ChannelEnd, Counterparty, IdentifiedChannelEnd, PacketError};
+
+    let packet = dummy_packets().remove(0);
+    let channel = IdentifiedChannelEnd::new(
+        PortId::transfer(),
+        ChannelId::new(0),
+        ChannelEnd::new_open(
+            Order::Ordered,
+            Counterparty::new(PortId::transfer(), Some(ChannelId::new(1))),
+            vec![ConnectionId::new(4)],
+            Version::new("ics20-1".to_string()),
+        ),
+        0,
+    );
+
+    assert_eq!(
+        SendPacket::try_new(packet.clone(), &channel).unwrap(),
+        SendPacket::new(packet.clone(), Order::Ordered, ConnectionId::new(4))
+    );
+
+    // the packet was sent on another channel
+    let other_source = Packet {
+        chan_on_a: ChannelId::new(2),
+        ..packet.clone()
+    };
+    assert!(matches!(
+        SendPacket::try_new(other_source, &channel),
+        Err(PacketError::InvalidPacketSource { .. })
+    ));
+
+    // the packet is destined for another channel than the counterparty
+    let other_destination = Packet {
+        chan_on_b: ChannelId::new(2),
+        ..packet
+    };
+    assert!(matches!(
+        SendPacket::try_new(other_destination, &channel),
+        Err(PacketError::InvalidPacketCounterparty { .. })
+    ));
+}
+
+#[test]
+fn write_acknowledgement_packet() {
+    for packet in dummy_packets() {
+        let event = WriteAcknowledgement::new(
+            packet.clone(),
+            br#"{"result":"AQ=="}"#.to_vec(),
+            ConnectionId::new(0),
+        );
+        assert_eq!(event.packet(), packet);
+
+        // the packet also survives the event being emitted and parsed back
+        let parsed = WriteAcknowledgement::try_from(AbciEvent::from(event)).unwrap();
+        assert_eq!(parsed.packet(), packet);
+    }
+}
+
+#[test]
+fn packet_events_reject_mismatched_data() {
+    use super::Error;
+
+    let packet = dummy_packets().remove(0);
+    let mut event: AbciEvent =
+        SendPacket::new(packet, Order::Unordered, ConnectionId::new(0)).into();
+    for attr in event.attributes.iter_mut() {
+        if attr.key_bytes() == b"packet_data" {
+            *attr = ("packet_data", "something else").into();
+        }
+    }
+
+    assert!(matches!(
+        SendPacket::try_from(event),
+        Err(Error::MismatchedPacketData)
+    ));
+}
+
+#[test]
+fn packet_events_parse_data_from_either_attribute() {
+    use bytes::Bytes;
+
+    /// Drops every attribute keyed `key` from `event`.
+    fn without(mut event: AbciEvent, key: &str) -> AbciEvent {
+        event
+            .attributes
+            .retain(|attr| attr.key_bytes() != key.as_bytes());
+        event
+    }
+
+    for packet in dummy_packets() {
+        let send: AbciEvent =
+            SendPacket::new(packet.clone(), Order::Unordered, ConnectionId::new(0)).into();
+        let recv: AbciEvent =
+            ReceivePacket::new(packet.clone(), Order::Unordered, ConnectionId::new(0)).into();
+        let write_ack: AbciEvent = WriteAcknowledgement::new(
+            packet.clone(),
+            br#"{"result":"AQ=="}"#.to_vec(),
+            ConnectionId::new(0),
+        )
+        .into();
+
+        // only the hex encoding
+        let data: Bytes = SendPacket::try_from(without(send.clone(), "packet_data"))
+            .unwrap()
+            .packet_data;
+        assert_eq!(data, packet.data);
+        let data: Bytes = ReceivePacket::try_from(without(recv.clone(), "packet_data"))
+            .unwrap()
+            .packet_data;
+        assert_eq!(data, packet.data);
+        let data: Bytes = WriteAcknowledgement::try_from(without(write_ack.clone(), "packet_data"))
+            .unwrap()
+            .packet_data;
+        assert_eq!(data, packet.data);
+
+        // only the UTF-8 form, which is not emitted for non-UTF-8 data
+        if core::str::from_utf8(&packet.data).is_ok() {
+            let data: Bytes = SendPacket::try_from(without(send, "packet_data_hex"))
+                .unwrap()
+                .packet_data;
+            assert_eq!(data, packet.data);
+            let data: Bytes = ReceivePacket::try_from(without(recv, "packet_data_hex"))
+                .unwrap()
+                .packet_data;
+            assert_eq!(data, packet.data);
+            let data: Bytes = WriteAcknowledgement::try_from(without(write_ack, "packet_data_hex"))
+                .unwrap()
+                .packet_data;
+            assert_eq!(data, packet.data);
+        }
+    }
+}
+
+#[test]
+fn channel_events_abci_round_trip() {
+    let port_id = PortId::transfer();
+    let channel_id = ChannelId::new(0);
+    let counterparty_port_id = PortId::transfer();
+    let counterparty_channel_id = ChannelId::new(1);
+    let connection_id = ConnectionId::new(0);
+
+    assert_abci_round_trip(OpenInit {
+        port_id: port_id.clone(),
+        channel_id: channel_id.clone(),
+        counterparty_port_id: counterparty_port_id.clone(),
+        connection_id: connection_id.clone(),
+        version: Version::new("ics20-1".to_string()),
+    });
+    assert_abci_round_trip(OpenTry {
+        port_id: port_id.clone(),
+        channel_id: channel_id.clone(),
+        counterparty_port_id: counterparty_port_id.clone(),
+        counterparty_channel_id: counterparty_channel_id.clone(),
+        connection_id: connection_id.clone(),
+        version: Version::new("ics20-1".to_string()),
+    });
+    assert_abci_round_trip(OpenAck {
+        port_id: port_id.clone(),
+        channel_id: channel_id.clone(),
+        counterparty_port_id: counterparty_port_id.clone(),
+        counterparty_channel_id: counterparty_channel_id.clone(),
+        connection_id: connection_id.clone(),
+    });
+    assert_abci_round_trip(OpenConfirm {
+        port_id: port_id.clone(),
+        channel_id: channel_id.clone(),
+        counterparty_port_id: counterparty_port_id.clone(),
+        counterparty_channel_id: counterparty_channel_id.clone(),
+        connection_id: connection_id.clone(),
+    });
+    assert_abci_round_trip(CloseInit {
+        port_id: port_id.clone(),
+        channel_id: channel_id.clone(),
+        counterparty_port_id: counterparty_port_id.clone(),
+        counterparty_channel_id: counterparty_channel_id.clone(),
+        connection_id: connection_id.clone(),
+    });
+    assert_abci_round_trip(CloseConfirm {
+        port_id,
+        channel_id,
+        counterparty_port_id,
+        counterparty_channel_id,
+        connection_id,
+    });
+}
@@ -57,6 +57,30 @@ impl Default for PortId {
     }
 }
 
+impl PartialEq<str> for PortId {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str().eq(other)
+    }
+}
+
+impl PartialEq<&str> for PortId {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str().eq(*other)
+    }
+}
+
+impl PartialEq<PortId> for str {
+    fn eq(&self, other: &PortId) -> bool {
+        other.as_str().eq(self)
+    }
+}
+
+impl PartialEq<PortId> for &str {
+    fn eq(&self, other: &PortId) -> bool {
+        other.as_str().eq(*self)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChannelId(pub String);
@@ -80,6 +104,52 @@ impl ChannelId {
         Self(id)
     }
 
+    /// Builds a channel identifier from a custom `prefix` and a `counter`,
+    /// for chains that do not use the default `channel` prefix.
+    ///
+    /// ```
+    /// use ibc_types_core_channel::ChannelId;
+    /// let chan_id = ChannelId::with_prefix("chan-penumbra", 5).unwrap();
+    /// assert_eq!(chan_id.to_string(), "chan-penumbra-5");
+    /// ```
+    pub fn with_prefix(prefix: &str, counter: u64) -> Result<Self, IdentifierError> {
+        let id = format!("{prefix}-{counter}");
+        Self::from_str(id.as_str())
+    }
+
+    /// Builds a channel identifier from `id` without validating it.
+    ///
+    /// This is an escape hatch for internal use, where `id` is already known
+    /// to be well-formed. Identifiers from untrusted sources should be parsed
+    /// with [`FromStr`] instead.
+    pub fn new_unchecked(id: String) -> Self {
+        Self(id)
+    }
+
+    /// Builds a channel identifier from its sequence number, i.e. the
+    /// numeric suffix following the `channel-` prefix.
+    ///
+    /// ```
+    /// use ibc_types_core_channel::ChannelId;
+    /// let chan_id = ChannelId::from_sequence(42);
+    /// assert_eq!(chan_id.to_string(), "channel-42");
+    /// assert_eq!(chan_id.sequence(), Some(42));
+    /// ```
+    pub fn from_sequence(sequence: u64) -> Self {
+        Self::new(sequence)
+    }
+
+    /// Returns the sequence number of this channel identifier, i.e. the
+    /// numeric suffix following the `channel-` prefix, or `None` if the
+    /// identifier is not of the form `channel-{n}`.
+    pub fn sequence(&self) -> Option<u64> {
+        let suffix = self.0.strip_prefix(Self::PREFIX)?;
+        if suffix.is_empty() || !suffix.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        suffix.parse().ok()
+    }
+
     /// Get this identifier as a borrowed `&str`
     pub fn as_str(&self) -> &str {
         &self.0
@@ -132,6 +202,24 @@ impl PartialEq<str> for ChannelId {
     }
 }
 
+impl PartialEq<&str> for ChannelId {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str().eq(*other)
+    }
+}
+
+impl PartialEq<ChannelId> for str {
+    fn eq(&self, other: &ChannelId) -> bool {
+        other.as_str().eq(self)
+    }
+}
+
+impl PartialEq<ChannelId> for &str {
+    fn eq(&self, other: &ChannelId) -> bool {
+        other.as_str().eq(*self)
+    }
+}
+
 /// A pair of [`PortId`] and [`ChannelId`] are used together for sending IBC packets.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct PortChannelId {
@@ -144,3 +232,85 @@ impl Display for PortChannelId {
         write!(f, "{}/{}", self.port_id, self.channel_id)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn channel_id_sequence() {
+        assert_eq!(ChannelId::new(0).sequence(), Some(0));
+        assert_eq!(ChannelId::from_sequence(0), ChannelId::new(0));
+
+        let max = ChannelId::from_sequence(u64::MAX);
+        assert_eq!(max.as_str(), "channel-18446744073709551615");
+        assert_eq!(max.sequence(), Some(u64::MAX));
+
+        for malformed in [
+            "channelId-0",
+            "channel-",
+            "channel-abc",
+            "channel-+1",
+            "channel-1-2",
+            "channel-18446744073709551616",
+        ] {
+            assert_eq!(
+                ChannelId(malformed.to_string()).sequence(),
+                None,
+                "{malformed}"
+            );
+        }
+    }
+
+    #[test]
+    fn channel_id_with_prefix() {
+        assert_eq!(
+            ChannelId::with_prefix("channel", 7).unwrap(),
+            ChannelId::new(7)
+        );
+        assert_eq!(
+            ChannelId::with_prefix("chan-penumbra", 5).unwrap(),
+            "chan-penumbra-5"
+        );
+        assert!(matches!(
+            ChannelId::with_prefix("chan/penumbra", 5),
+            Err(IdentifierError::ContainSeparator { .. })
+        ));
+    }
+
+    #[test]
+    fn identifiers_eq_str() {
+        let port_id = PortId::transfer();
+        assert!(port_id == *"transfer");
+        assert!(*"transfer" == port_id);
+        assert!(port_id == "transfer");
+        assert!("transfer" == port_id);
+        assert!(port_id != "transfer2");
+        assert!("transfer2" != port_id);
+
+        let channel_id = ChannelId::new(0);
+        assert!(channel_id == *"channel-0");
+        assert!(*"channel-0" == channel_id);
+        assert!(channel_id == "channel-0");
+        assert!("channel-0" == channel_id);
+        assert!(channel_id != "channel-1");
+        assert!("channel-1" != channel_id);
+    }
+
+    #[test]
+    fn parse_invalid_identifiers() {
+        assert!(PortId::from_str("").is_err());
+        assert!(ChannelId::from_str("").is_err());
+
+        let over_length = format!("channel-{}", "9".repeat(57));
+        assert_eq!(over_length.len(), 65);
+        assert!(ChannelId::from_str(&over_length).is_err());
+
+        // the escape hatch skips validation entirely
+        assert_eq!(
+            ChannelId::new_unchecked(over_length.clone()).as_str(),
+            over_length
+        );
+    }
+}
@@ -1,6 +1,6 @@
 //! IBC channel-related types.
 #![no_std]
-// Requires nightly.
+// Only enabled when building docs (e.g. on docs.rs), which requires nightly.
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 
 extern crate alloc;
@@ -18,9 +18,11 @@ mod timeout;
 mod version;
 
 pub use channel::{ChannelEnd, Counterparty, IdentifiedChannelEnd};
-pub use commitment::{AcknowledgementCommitment, PacketCommitment};
-pub use error::{ChannelError, PacketError};
-pub use identifier::{ChannelId, PortId};
+pub use commitment::{
+    compute_ack_commitment, compute_packet_commitment, AcknowledgementCommitment, PacketCommitment,
+};
+pub use error::{ChannelError, PacketError, ValidationErrors};
+pub use identifier::{ChannelId, PortChannelId, PortId};
 pub use packet::Packet;
 pub use timeout::TimeoutHeight;
 pub use version::Version;
@@ -1,6 +1,9 @@
 //! Message definitions for all ICS4 domain types: channel open & close handshake datagrams, as well
 //! as packets.
 
+use ibc_proto::google::protobuf::Any;
+use ibc_types_domain_type::{decode_as, to_any};
+
 // Opening handshake messages.
 mod chan_open_ack;
 mod chan_open_confirm;
@@ -48,3 +51,115 @@ pub enum PacketMsg {
     Timeout(MsgTimeout),
     TimeoutOnClose(MsgTimeoutOnClose),
 }
+
+/// Any ICS4 message, as carried in a transaction.
+///
+/// This can be decoded from an [`Any`] by dispatching on its type URL, so
+/// that a transaction handler can decode incoming IBC messages uniformly.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MsgEnvelope {
+    Channel(ChannelMsg),
+    Packet(PacketMsg),
+}
+
+impl From<ChannelMsg> for MsgEnvelope {
+    fn from(msg: ChannelMsg) -> Self {
+        Self::Channel(msg)
+    }
+}
+
+impl From<PacketMsg> for MsgEnvelope {
+    fn from(msg: PacketMsg) -> Self {
+        Self::Packet(msg)
+    }
+}
+
+impl TryFrom<Any> for MsgEnvelope {
+    type Error = anyhow::Error;
+
+    fn try_from(any: Any) -> Result<Self, Self::Error> {
+        if let Some(msg) = decode_as(&any) {
+            return Ok(ChannelMsg::OpenInit(msg?).into());
+        }
+        if let Some(msg) = decode_as(&any) {
+            return Ok(ChannelMsg::OpenTry(msg?).into());
+        }
+        if let Some(msg) = decode_as(&any) {
+            return Ok(ChannelMsg::OpenAck(msg?).into());
+        }
+        if let Some(msg) = decode_as(&any) {
+            return Ok(ChannelMsg::OpenConfirm(msg?).into());
+        }
+        if let Some(msg) = decode_as(&any) {
+            return Ok(ChannelMsg::CloseInit(msg?).into());
+        }
+        if let Some(msg) = decode_as(&any) {
+            return Ok(ChannelMsg::CloseConfirm(msg?).into());
+        }
+        if let Some(msg) = decode_as(&any) {
+            return Ok(PacketMsg::Recv(msg?).into());
+        }
+        if let Some(msg) = decode_as(&any) {
+            return Ok(PacketMsg::Ack(msg?).into());
+        }
+        if let Some(msg) = decode_as(&any) {
+            return Ok(PacketMsg::Timeout(msg?).into());
+        }
+        if let Some(msg) = decode_as(&any) {
+            return Ok(PacketMsg::TimeoutOnClose(msg?).into());
+        }
+        Err(anyhow::anyhow!(
+            "unknown channel message type URL: {}",
+            any.type_url
+        ))
+    }
+}
+
+impl From<MsgEnvelope> for Any {
+    fn from(envelope: MsgEnvelope) -> Self {
+        match envelope {
+            MsgEnvelope::Channel(ChannelMsg::OpenInit(msg)) => to_any(msg),
+            MsgEnvelope::Channel(ChannelMsg::OpenTry(msg)) => to_any(msg),
+            MsgEnvelope::Channel(ChannelMsg::OpenAck(msg)) => to_any(msg),
+            MsgEnvelope::Channel(ChannelMsg::OpenConfirm(msg)) => to_any(msg),
+            MsgEnvelope::Channel(ChannelMsg::CloseInit(msg)) => to_any(msg),
+            MsgEnvelope::Channel(ChannelMsg::CloseConfirm(msg)) => to_any(msg),
+            MsgEnvelope::Packet(PacketMsg::Recv(msg)) => to_any(msg),
+            MsgEnvelope::Packet(PacketMsg::Ack(msg)) => to_any(msg),
+            MsgEnvelope::Packet(PacketMsg::Timeout(msg)) => to_any(msg),
+            MsgEnvelope::Packet(PacketMsg::TimeoutOnClose(msg)) => to_any(msg),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use test_log::test;
+
+    use super::recv_packet::test_util::get_dummy_raw_msg_recv_packet;
+
+    #[test]
+    fn msg_envelope_from_any() {
+        let raw = get_dummy_raw_msg_recv_packet(15);
+        let msg = MsgRecvPacket::try_from(raw.clone()).unwrap();
+        let any = Any {
+            type_url: "/ibc.core.channel.v1.MsgRecvPacket".to_string(),
+            value: prost::Message::encode_to_vec(&raw),
+        };
+
+        let envelope = MsgEnvelope::try_from(any.clone()).unwrap();
+        assert_eq!(envelope, MsgEnvelope::Packet(PacketMsg::Recv(msg)));
+
+        let reencoded = Any::from(envelope.clone());
+        assert_eq!(reencoded.type_url, any.type_url);
+        assert_eq!(MsgEnvelope::try_from(reencoded).unwrap(), envelope);
+
+        let unknown = Any {
+            type_url: "/ibc.core.channel.v1.MsgChannelUpgradeInit".to_string(),
+            ..any
+        };
+        assert!(MsgEnvelope::try_from(unknown).is_err());
+    }
+}
@@ -2,10 +2,11 @@ use crate::prelude::*;
 
 use core::str::FromStr;
 
+use bytes::Bytes;
 use ibc_proto::ibc::core::channel::v1::Packet as RawPacket;
 
 use ibc_types_core_client::Height;
-use ibc_types_timestamp::{Expiry::Expired, Timestamp};
+use ibc_types_timestamp::Timestamp;
 
 use crate::{ChannelError, ChannelId, PacketError, PortId, TimeoutHeight};
 
@@ -37,6 +38,7 @@ impl core::fmt::Display for PacketMsgType {
 
 /// The sequence number of a packet enforces ordering among packets from the same source.
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "with_serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Sequence(pub u64);
 
 impl FromStr for Sequence {
@@ -60,6 +62,18 @@ impl Sequence {
     pub fn increment(&self) -> Sequence {
         Sequence(self.0 + 1)
     }
+
+    /// Returns the big-endian encoding of this sequence, as used by ibc-go
+    /// (`sdk.Uint64ToBigEndian`) for the sequence values stored in state.
+    pub fn to_be_bytes(&self) -> [u8; 8] {
+        self.0.to_be_bytes()
+    }
+
+    /// Decodes a sequence from its big-endian encoding; the inverse of
+    /// [`Sequence::to_be_bytes`].
+    pub fn from_be_bytes(bytes: [u8; 8]) -> Self {
+        Sequence(u64::from_be_bytes(bytes))
+    }
 }
 
 impl From<u64> for Sequence {
@@ -80,14 +94,24 @@ impl core::fmt::Display for Sequence {
     }
 }
 
+/// Serializes via [`RawPacket`], so the packet data is encoded as a base64
+/// string and the timeout height uses its proto form, with `0-0` meaning
+/// [`TimeoutHeight::Never`].
 #[derive(Clone, Default, Hash, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "with_serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "RawPacket", into = "RawPacket")
+)]
 pub struct Packet {
     pub sequence: Sequence,
     pub port_on_a: PortId,
     pub chan_on_a: ChannelId,
     pub port_on_b: PortId,
     pub chan_on_b: ChannelId,
-    pub data: Vec<u8>,
+    /// The opaque packet payload. Cloning it is cheap, so a packet can be
+    /// fanned out into several events without copying its data.
+    pub data: Bytes,
     pub timeout_height_on_b: TimeoutHeight,
     pub timeout_timestamp_on_b: Timestamp,
 }
@@ -134,6 +158,29 @@ impl core::fmt::Debug for Packet {
 }
 
 impl Packet {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        sequence: impl Into<Sequence>,
+        port_on_a: impl Into<PortId>,
+        chan_on_a: impl Into<ChannelId>,
+        port_on_b: impl Into<PortId>,
+        chan_on_b: impl Into<ChannelId>,
+        data: impl Into<Bytes>,
+        timeout_height_on_b: TimeoutHeight,
+        timeout_timestamp_on_b: Timestamp,
+    ) -> Self {
+        Self {
+            sequence: sequence.into(),
+            port_on_a: port_on_a.into(),
+            chan_on_a: chan_on_a.into(),
+            port_on_b: port_on_b.into(),
+            chan_on_b: chan_on_b.into(),
+            data: data.into(),
+            timeout_height_on_b,
+            timeout_timestamp_on_b,
+        }
+    }
+
     /// Checks whether a packet from a
     /// [`SendPacket`](crate::events::packet::SendPacket)
     /// event is timed-out relative to the current state of the
@@ -151,7 +198,9 @@ impl Packet {
         let height_timed_out = self.timeout_height_on_b.has_expired(dst_chain_height);
 
         let timestamp_timed_out = self.timeout_timestamp_on_b != Timestamp::none()
-            && dst_chain_ts.check_expiry(&self.timeout_timestamp_on_b) == Expired;
+            && dst_chain_ts
+                .check_expiry(&self.timeout_timestamp_on_b)
+                .is_expired();
 
         height_timed_out || timestamp_timed_out
     }
@@ -174,6 +223,23 @@ impl core::fmt::Display for Packet {
     }
 }
 
+/// Identifies a [`Packet`] by its source port, source channel, and sequence,
+/// which together are unique on the sending chain.
+///
+/// Useful as a map key for indexing packets without cloning their data.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PacketKey(pub PortId, pub ChannelId, pub Sequence);
+
+impl From<&Packet> for PacketKey {
+    fn from(packet: &Packet) -> Self {
+        PacketKey(
+            packet.port_on_a.clone(),
+            packet.chan_on_a.clone(),
+            packet.sequence,
+        )
+    }
+}
+
 impl TryFrom<RawPacket> for Packet {
     type Error = PacketError;
 
@@ -220,7 +286,7 @@ impl TryFrom<RawPacket> for Packet {
                 .destination_channel
                 .parse()
                 .map_err(PacketError::Identifier)?,
-            data: raw_pkt.data,
+            data: raw_pkt.data.into(),
             timeout_height_on_b: packet_timeout_height,
             timeout_timestamp_on_b,
         })
@@ -235,7 +301,7 @@ impl From<Packet> for RawPacket {
             source_channel: packet.chan_on_a.to_string(),
             destination_port: packet.port_on_b.to_string(),
             destination_channel: packet.chan_on_b.to_string(),
-            data: packet.data,
+            data: packet.data.into(),
             timeout_height: packet.timeout_height_on_b.into(),
             timeout_timestamp: packet.timeout_timestamp_on_b.nanoseconds(),
         }
@@ -277,7 +343,10 @@ mod tests {
     use super::test_utils::get_dummy_raw_packet;
     use super::*;
 
+    use crate::channel::Order;
+    use crate::events::packet::SendPacket;
     use crate::packet::Packet;
+    use ibc_types_core_connection::ConnectionId;
 
     #[test]
     fn packet_try_from_raw() {
@@ -451,4 +520,152 @@ mod tests {
         assert_eq!(raw, raw_back);
         assert_eq!(msg, msg_back);
     }
+
+    #[test]
+    fn sequence_be_bytes() {
+        // Golden vectors from ibc-go's `sdk.Uint64ToBigEndian`.
+        let tests: [(u64, [u8; 8]); 5] = [
+            (0, [0, 0, 0, 0, 0, 0, 0, 0]),
+            (1, [0, 0, 0, 0, 0, 0, 0, 1]),
+            (256, [0, 0, 0, 0, 0, 0, 1, 0]),
+            (
+                0x0102_0304_0506_0708,
+                [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08],
+            ),
+            (u64::MAX, [0xff; 8]),
+        ];
+
+        for (seq, bytes) in tests {
+            let sequence = Sequence::from(seq);
+            assert_eq!(sequence.to_be_bytes(), bytes, "{seq}");
+            assert_eq!(Sequence::from_be_bytes(bytes), sequence, "{seq}");
+
+            // The proto form carries the sequence as a plain `u64`.
+            let raw = RawPacket {
+                sequence: seq,
+                ..get_dummy_raw_packet(15, 0)
+            };
+            if seq == 0 {
+                // zero is not a valid packet sequence
+                assert!(Packet::try_from(raw).is_err());
+                continue;
+            }
+            let packet = Packet::try_from(raw).unwrap();
+            assert_eq!(packet.sequence, sequence);
+            assert_eq!(RawPacket::from(packet).sequence, seq);
+        }
+    }
+
+    #[test]
+    fn packet_new_from_parsed_identifiers() {
+        let port: PortId = "transfer".parse().unwrap();
+        let channel: ChannelId = "channel-0".parse().unwrap();
+
+        let packet = Packet::new(
+            7,
+            port.clone(),
+            channel.clone(),
+            PortId::transfer(),
+            ChannelId::new(3),
+            b"packet data".to_vec(),
+            TimeoutHeight::Never,
+            Timestamp::none(),
+        );
+
+        assert_eq!(packet.sequence, Sequence::from(7));
+        assert_eq!(packet.port_on_a, port);
+        assert_eq!(packet.chan_on_a, channel);
+        assert_eq!(packet.port_on_b, "transfer");
+        assert_eq!(packet.chan_on_b, "channel-3");
+        assert_eq!(packet.data, Bytes::from_static(b"packet data"));
+
+        let event = SendPacket::new(packet.clone(), Order::Unordered, ConnectionId::new(0));
+        assert_eq!(event.src_port_id, port);
+        assert_eq!(event.src_connection_id, "connection-0");
+        assert_eq!(
+            Packet::try_from(RawPacket::from(packet.clone())).unwrap(),
+            packet
+        );
+    }
+
+    #[test]
+    fn packet_data_clones_are_shallow() {
+        let packet = Packet {
+            data: vec![1, 2, 3].into(),
+            ..Packet::try_from(get_dummy_raw_packet(15, 0)).unwrap()
+        };
+
+        // Cloning the packet shares the underlying payload rather than copying it.
+        let cloned = packet.clone();
+        assert_eq!(cloned.data.as_ptr(), packet.data.as_ptr());
+
+        let raw = RawPacket::from(cloned);
+        assert_eq!(raw.data, vec![1, 2, 3]);
+        assert_eq!(Packet::try_from(raw).unwrap(), packet);
+    }
+
+    #[test]
+    fn packet_key_as_map_key() {
+        use alloc::collections::BTreeMap;
+
+        let packet = Packet::try_from(get_dummy_raw_packet(15, 0)).unwrap();
+        let next_packet = Packet {
+            sequence: packet.sequence.increment(),
+            ..packet.clone()
+        };
+
+        let key = PacketKey::from(&packet);
+        assert_eq!(
+            key,
+            PacketKey(
+                packet.port_on_a.clone(),
+                packet.chan_on_a.clone(),
+                packet.sequence
+            )
+        );
+
+        let mut pending = BTreeMap::new();
+        pending.insert(PacketKey::from(&packet), "pending");
+        pending.insert(PacketKey::from(&next_packet), "acked");
+
+        assert_eq!(pending.len(), 2);
+        assert_eq!(pending.get(&key), Some(&"pending"));
+        assert_eq!(pending.get(&PacketKey::from(&next_packet)), Some(&"acked"));
+    }
+
+    #[cfg(feature = "with_serde")]
+    #[test]
+    fn serde_round_trip() {
+        let with_timeout = Packet::try_from(get_dummy_raw_packet(15, 0)).unwrap();
+        let without_timeout = Packet {
+            data: Bytes::from_static(b"some packet data"),
+            timeout_height_on_b: TimeoutHeight::Never,
+            ..with_timeout.clone()
+        };
+
+        for packet in [with_timeout, without_timeout] {
+            let json = serde_json::to_value(&packet).unwrap();
+            assert!(json["data"].is_string(), "data should be a string: {json}");
+
+            let packet_back: Packet = serde_json::from_value(json).unwrap();
+            assert_eq!(packet, packet_back);
+        }
+    }
+
+    #[cfg(feature = "with_serde")]
+    #[test]
+    fn serde_never_timeout_is_zero_height() {
+        let json = serde_json::to_value(TimeoutHeight::Never).unwrap();
+        let raw: RawHeight = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(
+            raw,
+            RawHeight {
+                revision_number: 0,
+                revision_height: 0,
+            }
+        );
+
+        let timeout_height: TimeoutHeight = serde_json::from_value(json).unwrap();
+        assert_eq!(timeout_height, TimeoutHeight::Never);
+    }
 }
@@ -18,8 +18,14 @@ use crate::prelude::*;
 /// is legal and meaningful, even though the Tendermint spec rejects this height
 /// as invalid. Thus, it must be parsed specially, where this special case means
 /// "no timeout".
-#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "with_serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "RawHeight", into = "RawHeight")
+)]
 pub enum TimeoutHeight {
+    #[default]
     Never,
     At(Height),
 }
@@ -29,6 +35,36 @@ impl TimeoutHeight {
         Self::Never
     }
 
+    /// Returns a `TimeoutHeight` that never expires.
+    pub fn never() -> Self {
+        Self::Never
+    }
+
+    /// Returns `true` if this timeout height never expires.
+    pub fn is_never(&self) -> bool {
+        matches!(self, Self::Never)
+    }
+
+    /// Returns the timeout height, or `None` if it never expires.
+    pub fn into_height(&self) -> Option<Height> {
+        match self {
+            Self::At(height) => Some(*height),
+            Self::Never => None,
+        }
+    }
+
+    /// Returns the revision number of the timeout height, or `None` if it
+    /// never expires.
+    pub fn revision_number(&self) -> Option<u64> {
+        self.into_height().map(|height| height.revision_number())
+    }
+
+    /// Returns the revision height of the timeout height, or `None` if it
+    /// never expires.
+    pub fn revision_height(&self) -> Option<u64> {
+        self.into_height().map(|height| height.revision_height())
+    }
+
     /// Revision number to be used in packet commitment computation
     pub fn commitment_revision_number(&self) -> u64 {
         match self {
@@ -81,12 +117,6 @@ impl FromStr for TimeoutHeight {
     }
 }
 
-impl Default for TimeoutHeight {
-    fn default() -> Self {
-        Self::Never
-    }
-}
-
 impl TryFrom<RawHeight> for TimeoutHeight {
     type Error = ClientError;
 
@@ -114,19 +144,24 @@ impl TryFrom<Option<RawHeight>> for TimeoutHeight {
         }
     }
 }
-/// We map "no timeout height" to `Some(RawHeight::zero)` due to a quirk
+
+/// We map "no timeout height" to `RawHeight::zero` due to a quirk
 /// in ICS-4. See <https://github.com/cosmos/ibc/issues/776>.
-impl From<TimeoutHeight> for Option<RawHeight> {
+impl From<TimeoutHeight> for RawHeight {
     fn from(timeout_height: TimeoutHeight) -> Self {
-        let raw_height = match timeout_height {
+        match timeout_height {
             TimeoutHeight::At(height) => height.into(),
             TimeoutHeight::Never => RawHeight {
                 revision_number: 0,
                 revision_height: 0,
             },
-        };
+        }
+    }
+}
 
-        Some(raw_height)
+impl From<TimeoutHeight> for Option<RawHeight> {
+    fn from(timeout_height: TimeoutHeight) -> Self {
+        Some(timeout_height.into())
     }
 }
 
@@ -135,3 +170,54 @@ impl From<Height> for TimeoutHeight {
         Self::At(height)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn never_timeout_height() {
+        let never = TimeoutHeight::never();
+        assert_eq!(never, TimeoutHeight::no_timeout());
+        assert!(never.is_never());
+        assert_eq!(never.into_height(), None);
+        assert_eq!(never.revision_number(), None);
+        assert_eq!(never.revision_height(), None);
+        assert_eq!(never.to_string(), "0-0");
+        assert_eq!("0-0".parse::<TimeoutHeight>().unwrap(), never);
+    }
+
+    #[test]
+    fn concrete_timeout_height() {
+        let height = Height::new(1, 10).unwrap();
+        let at = TimeoutHeight::from(height);
+        assert!(!at.is_never());
+        assert_eq!(at.into_height(), Some(height));
+        assert_eq!(at.revision_number(), Some(1));
+        assert_eq!(at.revision_height(), Some(10));
+        assert_eq!(at.to_string(), "1-10");
+        assert_eq!("1-10".parse::<TimeoutHeight>().unwrap(), at);
+    }
+
+    #[test]
+    fn from_str_inverts_display() {
+        let mut timeout_heights = vec![TimeoutHeight::Never];
+        for revision_number in [0, 1, 2, 1_000, u64::MAX] {
+            for revision_height in [1, 2, 10, 12_345_678, u64::MAX] {
+                let height = Height::new(revision_number, revision_height).unwrap();
+                timeout_heights.push(TimeoutHeight::At(height));
+            }
+        }
+
+        for timeout_height in timeout_heights {
+            let s = timeout_height.to_string();
+            assert_eq!(
+                TimeoutHeight::from_str(&s).ok(),
+                Some(timeout_height),
+                "{s}"
+            );
+            assert_eq!(s, timeout_height.to_event_attribute_value());
+        }
+    }
+}
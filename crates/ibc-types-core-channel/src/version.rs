@@ -18,12 +18,12 @@ use crate::prelude::*;
 pub struct Version(pub String);
 
 impl Version {
-    pub fn new(v: String) -> Self {
-        Self(v)
+    pub fn new(v: impl Into<String>) -> Self {
+        Self(v.into())
     }
 
     pub fn empty() -> Self {
-        Self::new("".to_string())
+        Self::new("")
     }
 
     pub fn is_empty(&self) -> bool {
@@ -33,6 +33,12 @@ impl Version {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// Returns `true` if this is exactly the version string `other`, e.g.
+    /// when checking a counterparty's proposed version during a handshake.
+    pub fn matches(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
 }
 
 impl From<String> for Version {
@@ -41,11 +47,29 @@ impl From<String> for Version {
     }
 }
 
+impl From<&str> for Version {
+    fn from(s: &str) -> Self {
+        Self::new(s)
+    }
+}
+
+impl PartialEq<str> for Version {
+    fn eq(&self, other: &str) -> bool {
+        self.matches(other)
+    }
+}
+
+impl PartialEq<&str> for Version {
+    fn eq(&self, other: &&str) -> bool {
+        self.matches(other)
+    }
+}
+
 impl FromStr for Version {
     type Err = Infallible;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self::new(s.to_string()))
+        Ok(Self::new(s))
     }
 }
 
@@ -61,3 +85,24 @@ impl Display for Version {
         write!(f, "{}", self.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn version_helpers() {
+        let version = Version::new("ics20-1");
+        assert_eq!(version, Version::new("ics20-1".to_string()));
+        assert_eq!(version, Version::from("ics20-1"));
+        assert!(version.matches("ics20-1"));
+        assert!(!version.matches("ics20-2"));
+        assert_eq!(version, "ics20-1");
+        assert!(!version.is_empty());
+
+        assert!(Version::empty().is_empty());
+        assert_eq!(Version::default(), Version::empty());
+        assert_eq!(Version::empty(), "");
+    }
+}
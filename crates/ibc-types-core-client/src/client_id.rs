@@ -3,7 +3,8 @@ use core::{
     str::FromStr,
 };
 
-use derive_more::Into;
+use alloc::sync::Arc;
+
 use ibc_types_identifier::{validate_client_identifier, IdentifierError};
 
 use crate::{client_type::ClientType, prelude::*};
@@ -13,16 +14,19 @@ use crate::{client_type::ClientType, prelude::*};
 /// Client identifiers are deterministically formed from two elements: a prefix
 /// derived from the client type `ctype`, and a monotonically increasing
 /// `counter`; these are separated by a dash "-".
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Into)]
+///
+/// The identifier is stored in an [`Arc<str>`], so clones share the same
+/// string rather than copying it.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct ClientId(pub(crate) String);
+pub struct ClientId(pub(crate) Arc<str>);
 
 impl ClientId {
     /// Construct a new client identifier from a client type and a counter.
     ///
     /// ```
     /// # use ibc_types_core_client::{ClientId, ClientType};
-    /// let tm_client_id = ClientId::new(ClientType::new("07-tendermint".to_string()), 0);
+    /// let tm_client_id = ClientId::new(ClientType::tendermint(), 0);
     /// assert!(tm_client_id.is_ok());
     /// tm_client_id.map(|id| { assert_eq!(&id, "07-tendermint-0") });
     /// ```
@@ -42,7 +46,34 @@ impl ClientId {
         self.0.as_bytes()
     }
 
-    // TODO: add accessors for counter, client type
+    /// Splits this identifier into its client type prefix and counter, if it
+    /// is of the form `{client_type}-{counter}`.
+    fn split(&self) -> Option<(&str, u64)> {
+        let (prefix, counter) = self.0.rsplit_once('-')?;
+        if prefix.is_empty() || counter.is_empty() || !counter.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        Some((prefix, counter.parse().ok()?))
+    }
+
+    /// Returns the counter of this client identifier, i.e. the numeric suffix
+    /// following the client type, or `None` if the identifier is not of the
+    /// form `{client_type}-{counter}`.
+    ///
+    /// ```
+    /// # use ibc_types_core_client::ClientId;
+    /// assert_eq!(ClientId::default().sequence(), Some(0));
+    /// ```
+    pub fn sequence(&self) -> Option<u64> {
+        self.split().map(|(_, counter)| counter)
+    }
+
+    /// Returns the client type prefix of this client identifier, e.g.
+    /// `07-tendermint` for `07-tendermint-0`, or `None` if the identifier is
+    /// not of the form `{client_type}-{counter}`.
+    pub fn client_type_prefix(&self) -> Option<&str> {
+        self.split().map(|(prefix, _)| prefix)
+    }
 }
 
 /// This implementation provides a `to_string` method.
@@ -56,7 +87,7 @@ impl FromStr for ClientId {
     type Err = IdentifierError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        validate_client_identifier(s).map(|_| Self(s.to_string()))
+        validate_client_identifier(s).map(|_| Self(s.into()))
     }
 }
 
@@ -64,13 +95,19 @@ impl TryFrom<String> for ClientId {
     type Error = IdentifierError;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        validate_client_identifier(&value).map(|_| Self(value))
+        validate_client_identifier(&value).map(|_| Self(value.into()))
     }
 }
 
 impl Default for ClientId {
     fn default() -> Self {
-        Self("07-tendermint-0".to_string())
+        Self("07-tendermint-0".into())
+    }
+}
+
+impl From<ClientId> for String {
+    fn from(value: ClientId) -> Self {
+        value.as_str().into()
     }
 }
 
@@ -93,3 +130,62 @@ impl PartialEq<ClientId> for str {
         other.as_str().eq(self)
     }
 }
+
+impl PartialEq<&str> for ClientId {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str().eq(*other)
+    }
+}
+
+impl PartialEq<ClientId> for &str {
+    fn eq(&self, other: &ClientId) -> bool {
+        other.as_str().eq(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn client_id_clones_share_storage() {
+        let client_id = ClientId::from_str("07-tendermint-0").unwrap();
+        let clone = client_id.clone();
+
+        assert!(Arc::ptr_eq(&client_id.0, &clone.0));
+        assert_eq!(clone.as_str(), "07-tendermint-0");
+        assert_eq!(String::from(clone), "07-tendermint-0");
+    }
+
+    #[test]
+    fn client_id_eq_str() {
+        let client_id = ClientId::from_str("07-tendermint-0").unwrap();
+
+        assert!(client_id == *"07-tendermint-0");
+        assert!(*"07-tendermint-0" == client_id);
+        assert!(client_id == "07-tendermint-0");
+        assert!("07-tendermint-0" == client_id);
+        assert!(client_id != "07-tendermint-1");
+        assert!("07-tendermint-1" != client_id);
+    }
+
+    #[test]
+    fn client_id_sequence_and_prefix() {
+        let client_id = ClientId::new(ClientType::tendermint(), 0).unwrap();
+        assert_eq!(client_id, "07-tendermint-0");
+        assert_eq!(client_id.sequence(), Some(0));
+        assert_eq!(client_id.client_type_prefix(), Some("07-tendermint"));
+
+        let client_id = ClientId::new(ClientType("09-localhost".to_string()), 42).unwrap();
+        assert_eq!(client_id.sequence(), Some(42));
+        assert_eq!(client_id.client_type_prefix(), Some("09-localhost"));
+
+        for malformed in ["clientidtwo", "07-tendermint-", "07-tendermint-+1", "-7"] {
+            let client_id = ClientId(malformed.into());
+            assert_eq!(client_id.sequence(), None, "{malformed}");
+            assert_eq!(client_id.client_type_prefix(), None, "{malformed}");
+        }
+
+        assert!(ClientId::new(ClientType("07/tendermint".to_string()), 0).is_err());
+    }
+}
@@ -1,15 +1,51 @@
 use crate::prelude::*;
 use core::fmt::{Display, Error as FmtError, Formatter};
 
+use ibc_types_identifier::IdentifierError;
+
 /// Type of the client, depending on the specific consensus algorithm.
+///
+/// Prefer constructing client types with [`ClientType::try_new`], which
+/// validates its input, or with a known-type constructor such as
+/// [`ClientType::tendermint`]. [`ClientType::new`] and the public tuple field
+/// remain for compatibility, but bypass validation.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ClientType(pub String);
 
 impl ClientType {
+    /// The client type of the Tendermint light client (ICS-07).
+    pub const TENDERMINT: &'static str = "07-tendermint";
+
+    /// Builds a client type from `s` without validating it.
     pub fn new(s: String) -> Self {
         Self(s)
     }
 
+    /// Builds a client type, rejecting empty strings and strings containing
+    /// the path separator `/`, which could not be used in client
+    /// identifiers.
+    ///
+    /// ```
+    /// # use ibc_types_core_client::ClientType;
+    /// let client_type = ClientType::try_new("07-tendermint".to_string()).unwrap();
+    /// assert_eq!(client_type, ClientType::tendermint());
+    /// assert!(ClientType::try_new("".to_string()).is_err());
+    /// ```
+    pub fn try_new(s: String) -> Result<Self, IdentifierError> {
+        if s.is_empty() {
+            return Err(IdentifierError::Empty);
+        }
+        if s.contains('/') {
+            return Err(IdentifierError::ContainSeparator { id: s });
+        }
+        Ok(Self(s))
+    }
+
+    /// Returns the client type of the Tendermint light client, `07-tendermint`.
+    pub fn tendermint() -> Self {
+        Self(Self::TENDERMINT.to_string())
+    }
+
     /// Yields this identifier as a borrowed `&str`
     pub fn as_str(&self) -> &str {
         &self.0
@@ -21,3 +57,29 @@ impl Display for ClientType {
         write!(f, "{}", self.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn client_type_new() {
+        assert_eq!(ClientType::tendermint().as_str(), "07-tendermint");
+        assert_eq!(
+            ClientType::try_new("07-tendermint".to_string()).unwrap(),
+            ClientType::tendermint()
+        );
+
+        assert!(matches!(
+            ClientType::try_new("".to_string()),
+            Err(IdentifierError::Empty)
+        ));
+        assert!(matches!(
+            ClientType::try_new("07/tendermint".to_string()),
+            Err(IdentifierError::ContainSeparator { .. })
+        ));
+
+        // `new` does not validate
+        assert_eq!(ClientType::new("".to_string()).as_str(), "");
+    }
+}
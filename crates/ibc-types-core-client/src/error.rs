@@ -111,9 +111,10 @@ pub enum Error {
     Other { description: String },
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for Error {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for Error {
+    // tendermint-proto and prost errors only implement `Error` with `std`
+    #[cfg(feature = "std")]
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match &self {
             Self::ClientIdentifierConstructor {
                 validation_error: e,
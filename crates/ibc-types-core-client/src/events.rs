@@ -43,9 +43,8 @@ pub enum Error {
     },
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for Error {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         // Note: fill in if errors have causes
         match &self {
             Self::ParseHeight { e, .. } => Some(e),
@@ -73,7 +72,7 @@ impl From<CreateClient> for abci::Event {
         Event::new(
             CreateClient::TYPE_STR,
             [
-                ("client_id", c.client_id.0),
+                ("client_id", c.client_id.into()),
                 ("client_type", c.client_type.0),
                 ("consensus_height", c.consensus_height.to_string()),
             ],
@@ -152,7 +151,7 @@ impl From<UpdateClient> for abci::Event {
         Event::new(
             UpdateClient::TYPE_STR,
             [
-                ("client_id", u.client_id.0),
+                ("client_id", u.client_id.into()),
                 ("client_type", u.client_type.0),
                 ("consensus_height", u.consensus_height.to_string()),
                 ("header", String::from_utf8(hex::encode(u.header)).unwrap()),
@@ -236,7 +235,7 @@ impl From<ClientMisbehaviour> for abci::Event {
         Event::new(
             ClientMisbehaviour::TYPE_STR,
             [
-                ("client_id", c.client_id.0),
+                ("client_id", c.client_id.into()),
                 ("client_type", c.client_type.0),
             ],
         )
@@ -297,7 +296,7 @@ impl From<UpgradeClient> for abci::Event {
         Event::new(
             UpgradeClient::TYPE_STR,
             [
-                ("client_id", u.client_id.0),
+                ("client_id", u.client_id.into()),
                 ("client_type", u.client_type.0),
                 ("consensus_height", u.consensus_height.to_string()),
             ],
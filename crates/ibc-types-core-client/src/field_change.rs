@@ -0,0 +1,60 @@
+use crate::prelude::*;
+
+use core::fmt::{Debug, Display, Error as FmtError, Formatter};
+
+/// A single field that differs between two versions of a client state,
+/// connection end, or channel end, as reported by their `diff` methods.
+///
+/// Values are rendered with their `Debug` representation, so that a diff
+/// can be shown to an operator without knowing the types involved.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldChange {
+    pub field: &'static str,
+    pub old: String,
+    pub new: String,
+}
+
+impl FieldChange {
+    /// Returns a change for `field` if `old` and `new` differ.
+    pub fn compare<T: PartialEq + Debug + ?Sized>(
+        field: &'static str,
+        old: &T,
+        new: &T,
+    ) -> Option<Self> {
+        if old == new {
+            return None;
+        }
+        Some(Self {
+            field,
+            old: format!("{old:?}"),
+            new: format!("{new:?}"),
+        })
+    }
+}
+
+impl Display for FieldChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        write!(f, "{}: {} -> {}", self.field, self.old, self.new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compare_reports_only_differences() {
+        assert_eq!(FieldChange::compare("delay", &3u64, &3u64), None);
+
+        let change = FieldChange::compare("delay", &3u64, &5u64).unwrap();
+        assert_eq!(
+            change,
+            FieldChange {
+                field: "delay",
+                old: "3".to_string(),
+                new: "5".to_string(),
+            }
+        );
+        assert_eq!(change.to_string(), "delay: 3 -> 5");
+    }
+}
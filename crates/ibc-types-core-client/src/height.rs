@@ -37,6 +37,20 @@ impl Height {
         })
     }
 
+    /// Creates a height in a `const` context, e.g. to embed a fixed height in
+    /// chain parameters.
+    ///
+    /// Unlike [`Height::new`], this does not return a `Result`: it panics if
+    /// `revision_height` is zero, which in a `const` context is a compile
+    /// error.
+    pub const fn new_const(revision_number: u64, revision_height: u64) -> Self {
+        assert!(revision_height != 0, "revision height must be non-zero");
+        Self {
+            revision_number,
+            revision_height,
+        }
+    }
+
     pub fn revision_number(&self) -> u64 {
         self.revision_number
     }
@@ -70,6 +84,33 @@ impl Height {
     pub fn decrement(&self) -> Result<Height, Error> {
         self.sub(1)
     }
+
+    /// Encodes this height as its revision number followed by its revision
+    /// height, both big-endian.
+    ///
+    /// Encoded heights compare bytewise in the same order as the heights
+    /// themselves, so they can be used directly as sorted storage keys.
+    pub fn to_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0u8; 16];
+        bytes[..8].copy_from_slice(&self.revision_number.to_be_bytes());
+        bytes[8..].copy_from_slice(&self.revision_height.to_be_bytes());
+        bytes
+    }
+
+    /// Decodes a height from its encoding; the inverse of
+    /// [`Height::to_bytes`].
+    ///
+    /// Like [`Height::new`], this rejects a zero revision height.
+    pub fn from_bytes(bytes: [u8; 16]) -> Result<Self, Error> {
+        let mut revision_number = [0u8; 8];
+        let mut revision_height = [0u8; 8];
+        revision_number.copy_from_slice(&bytes[..8]);
+        revision_height.copy_from_slice(&bytes[8..]);
+        Height::new(
+            u64::from_be_bytes(revision_number),
+            u64::from_be_bytes(revision_height),
+        )
+    }
 }
 
 impl PartialOrd for Height {
@@ -137,15 +178,14 @@ pub enum HeightParseError {
         height: String,
         error: ParseIntError,
     },
-    /// attempted to parse a height with invalid format (not in the form `revision_number-revision_height`)
+    /// attempted to parse a height with invalid format (not in the form `revision_number-revision_height` or `revision_number/revision_height`)
     InvalidFormat,
     /// attempted to parse an invalid zero height
     ZeroHeight,
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for HeightParseError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for HeightParseError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match &self {
             HeightParseError::HeightConversion { error: e, .. } => Some(e),
             HeightParseError::ZeroHeight => None,
@@ -154,11 +194,14 @@ impl std::error::Error for HeightParseError {
     }
 }
 
+/// Parses a height of the form `revision_number-revision_height`, as emitted
+/// by [`Display`](core::fmt::Display), or of the form
+/// `revision_number/revision_height`, as used by some other tooling.
 impl TryFrom<&str> for Height {
     type Error = HeightParseError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let split: vec::Vec<&str> = value.split('-').collect();
+        let split: vec::Vec<&str> = value.split(['-', '/']).collect();
 
         if split.len() != 2 {
             return Err(HeightParseError::InvalidFormat);
@@ -196,3 +239,81 @@ impl FromStr for Height {
         Height::try_from(s)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn height_new_const() {
+        const UPGRADE_HEIGHT: Height = Height::new_const(1, 100);
+
+        assert_eq!(UPGRADE_HEIGHT, Height::new(1, 100).unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "revision height must be non-zero")]
+    fn height_new_const_rejects_zero() {
+        Height::new_const(1, 0);
+    }
+
+    #[test]
+    fn height_bytes_round_trip() {
+        let height = Height::new(0x0102_0304_0506_0708, 0x1112_1314_1516_1718).unwrap();
+        assert_eq!(
+            height.to_bytes(),
+            [
+                0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16,
+                0x17, 0x18,
+            ]
+        );
+
+        let heights = [
+            Height::new(0, 1).unwrap(),
+            Height::new(0, 256).unwrap(),
+            Height::new(1, 1).unwrap(),
+            Height::new(u64::MAX, u64::MAX).unwrap(),
+        ];
+        for height in heights {
+            assert_eq!(Height::from_bytes(height.to_bytes()).unwrap(), height);
+        }
+        // the encoding preserves the ordering of heights
+        for pair in heights.windows(2) {
+            assert!(pair[0].to_bytes() < pair[1].to_bytes());
+        }
+
+        assert!(matches!(
+            Height::from_bytes([0u8; 16]),
+            Err(Error::InvalidHeight)
+        ));
+    }
+
+    #[test]
+    fn height_from_str() {
+        let height = Height::new(0, 10).unwrap();
+        assert_eq!("0-10".parse::<Height>().unwrap(), height);
+        assert_eq!("0/10".parse::<Height>().unwrap(), height);
+        assert_eq!(Height::try_from("0/10").unwrap(), height);
+        assert_eq!(height.to_string(), "0-10");
+
+        assert_eq!(
+            "4/7".parse::<Height>().unwrap().to_string(),
+            Height::new(4, 7).unwrap().to_string()
+        );
+
+        for s in ["10", "0-1/2", "0-1-2", "0//1", ""] {
+            assert!(
+                matches!(s.parse::<Height>(), Err(HeightParseError::InvalidFormat)),
+                "{s}"
+            );
+        }
+        assert!(matches!(
+            "0/0".parse::<Height>(),
+            Err(HeightParseError::ZeroHeight)
+        ));
+        assert!(matches!(
+            "a/1".parse::<Height>(),
+            Err(HeightParseError::HeightConversion { .. })
+        ));
+    }
+}
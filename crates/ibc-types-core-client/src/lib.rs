@@ -1,6 +1,6 @@
 //! IBC client-related types.
 #![no_std]
-// Requires nightly.
+// Only enabled when building docs (e.g. on docs.rs), which requires nightly.
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 
 extern crate alloc;
@@ -10,6 +10,7 @@ extern crate std;
 mod client_id;
 mod client_type;
 mod error;
+mod field_change;
 mod height;
 
 mod prelude;
@@ -20,6 +21,7 @@ pub mod msgs;
 pub use client_id::ClientId;
 pub use client_type::ClientType;
 pub use error::Error;
+pub use field_change::FieldChange;
 pub use height::{Height, HeightParseError};
 
 #[cfg(any(test, feature = "mocks", feature = "mocks-no-std"))]
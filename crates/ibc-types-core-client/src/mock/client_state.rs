@@ -11,7 +11,7 @@ pub const MOCK_CLIENT_STATE_TYPE_URL: &str = "/ibc.mock.ClientState";
 pub const MOCK_CLIENT_TYPE: &str = "9999-mock";
 
 pub fn client_type() -> ClientType {
-    ClientType::new(MOCK_CLIENT_TYPE.to_string())
+    ClientType(MOCK_CLIENT_TYPE.to_string())
 }
 
 /// A mock of a client state. For an example of a real structure that this mocks, you can see
@@ -96,9 +96,7 @@ impl TryFrom<Any> for MockClientState {
         }
 
         match raw.type_url.as_str() {
-            MOCK_CLIENT_STATE_TYPE_URL => {
-                decode_client_state(raw.value.deref()).map_err(Into::into)
-            }
+            MOCK_CLIENT_STATE_TYPE_URL => decode_client_state(raw.value.deref()),
             _ => Err(Error::UnknownClientStateType {
                 client_state_type: raw.type_url,
             }),
@@ -63,9 +63,7 @@ impl TryFrom<Any> for Misbehaviour {
         }
 
         match raw.type_url.as_str() {
-            MOCK_MISBEHAVIOUR_TYPE_URL => {
-                decode_misbehaviour(raw.value.deref()).map_err(Into::into)
-            }
+            MOCK_MISBEHAVIOUR_TYPE_URL => decode_misbehaviour(raw.value.deref()),
             _ => Err(Error::UnknownMisbehaviourType {
                 misbehaviour_type: raw.type_url,
             }),
@@ -6,6 +6,9 @@
 //! calls into the chain-specific (e.g., ICS 07) client handler. See:
 //! <https://github.com/cosmos/ibc/tree/master/spec/core/ics-002-client-semantics#create>.
 
+use ibc_proto::google::protobuf::Any;
+use ibc_types_domain_type::{decode_as, to_any};
+
 mod create_client;
 mod misbehaviour;
 mod update_client;
@@ -16,10 +19,45 @@ pub use misbehaviour::MsgSubmitMisbehaviour;
 pub use update_client::MsgUpdateClient;
 pub use upgrade_client::MsgUpgradeClient;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum ClientMsg {
     CreateClient(MsgCreateClient),
     UpdateClient(MsgUpdateClient),
     Misbehaviour(MsgSubmitMisbehaviour),
     UpgradeClient(MsgUpgradeClient),
 }
+
+/// Decodes a client message from an [`Any`] by dispatching on its type URL.
+impl TryFrom<Any> for ClientMsg {
+    type Error = anyhow::Error;
+
+    fn try_from(any: Any) -> Result<Self, Self::Error> {
+        if let Some(msg) = decode_as(&any) {
+            return Ok(Self::CreateClient(msg?));
+        }
+        if let Some(msg) = decode_as(&any) {
+            return Ok(Self::UpdateClient(msg?));
+        }
+        if let Some(msg) = decode_as(&any) {
+            return Ok(Self::Misbehaviour(msg?));
+        }
+        if let Some(msg) = decode_as(&any) {
+            return Ok(Self::UpgradeClient(msg?));
+        }
+        Err(anyhow::anyhow!(
+            "unknown client message type URL: {}",
+            any.type_url
+        ))
+    }
+}
+
+impl From<ClientMsg> for Any {
+    fn from(msg: ClientMsg) -> Self {
+        match msg {
+            ClientMsg::CreateClient(msg) => to_any(msg),
+            ClientMsg::UpdateClient(msg) => to_any(msg),
+            ClientMsg::Misbehaviour(msg) => to_any(msg),
+            ClientMsg::UpgradeClient(msg) => to_any(msg),
+        }
+    }
+}
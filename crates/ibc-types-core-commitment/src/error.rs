@@ -1,17 +1,28 @@
-use crate::prelude::*;
-
 use displaydoc::Display;
 
-/// A catch-all error type.
+/// Errors arising from commitment proof verification.
 #[derive(Debug, Display)]
 pub enum Error {
-    /// Unused.
-    Unused,
+    /// empty merkle proof
+    EmptyMerkleProof,
+    /// empty or sentinel merkle root, which nothing can be verified against
+    EmptyMerkleRoot,
+    /// empty verified value
+    EmptyVerifiedValue,
+    /// mismatch between the number of proofs (`{proofs}`) and specs (`{specs}`)
+    NumberOfSpecsMismatch { proofs: usize, specs: usize },
+    /// mismatch between the number of proofs (`{proofs}`) and keys (`{keys}`)
+    NumberOfKeysMismatch { proofs: usize, keys: usize },
+    /// invalid merkle proof
+    InvalidMerkleProof,
+    /// merkle proof verification failed
+    VerificationFailure,
+    /// merkle proof verification failed: root hash does not match
+    RootMismatch,
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for Error {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         None
     }
 }
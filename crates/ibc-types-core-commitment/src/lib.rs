@@ -1,6 +1,6 @@
 //! IBC client-related types.
 #![no_std]
-// Requires nightly.
+// Only enabled when building docs (e.g. on docs.rs), which requires nightly.
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 
 extern crate alloc;
@@ -16,10 +16,10 @@ mod proof;
 mod root;
 
 pub use error::Error;
-pub use path::MerklePath;
+pub use path::{MerkleKeyPath, MerklePath};
 pub use prefix::MerklePrefix;
 pub use proof::MerkleProof;
-pub use root::MerkleRoot;
+pub use root::{MerkleRoot, SENTINEL_ROOT};
 
 #[cfg(any(test, feature = "mocks", feature = "mocks-no-std"))]
 pub mod mock;
@@ -1,4 +1,7 @@
 use crate::prelude::*;
+use crate::MerklePrefix;
+
+use core::fmt::Display;
 
 use ibc_proto::ibc::core::commitment::v1::MerklePath as RawMerklePath;
 use ibc_types_domain_type::DomainType;
@@ -8,6 +11,48 @@ pub struct MerklePath {
     pub key_path: Vec<String>,
 }
 
+impl MerklePath {
+    /// Builds the [`MerklePath`] at which `path` is committed under `prefix`.
+    ///
+    /// `path` is typically an ICS-24 `Path` from `ibc-types-path`, but any
+    /// value whose string form is the store key can be used. The resulting
+    /// key path is ordered root-to-leaf, i.e. `[prefix, path]`, matching the
+    /// layout used by the cosmos-sdk.
+    pub fn apply_prefix(prefix: &MerklePrefix, path: impl Display) -> MerklePath {
+        prefix.apply(vec![path.to_string()])
+    }
+}
+
+/// A [`MerklePath`] whose keys are raw bytes rather than strings.
+///
+/// IBC store keys need not be valid UTF-8, so proofs are always verified
+/// against the byte form of a path. Any [`MerklePath`] converts losslessly
+/// into a `MerkleKeyPath`; keys that are not UTF-8 can only be expressed in
+/// this form.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MerkleKeyPath {
+    pub key_path: Vec<Vec<u8>>,
+}
+
+impl MerkleKeyPath {
+    /// Builds the [`MerkleKeyPath`] at which the raw store `key` is committed
+    /// under `prefix`, ordered root-to-leaf like
+    /// [`MerklePath::apply_prefix`].
+    pub fn apply_prefix(prefix: &MerklePrefix, key: impl Into<Vec<u8>>) -> MerkleKeyPath {
+        MerkleKeyPath {
+            key_path: vec![prefix.key_prefix.clone(), key.into()],
+        }
+    }
+}
+
+impl From<MerklePath> for MerkleKeyPath {
+    fn from(value: MerklePath) -> MerkleKeyPath {
+        MerkleKeyPath {
+            key_path: value.key_path.into_iter().map(String::into_bytes).collect(),
+        }
+    }
+}
+
 impl DomainType for MerklePath {
     type Proto = RawMerklePath;
 }
@@ -28,3 +73,35 @@ impl TryFrom<RawMerklePath> for MerklePath {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn apply_prefix() {
+        let prefix = MerklePrefix::from(b"ibc".to_vec());
+        let path = MerklePath::apply_prefix(&prefix, "clients/07-tendermint-0/clientState");
+
+        assert_eq!(
+            path.key_path,
+            vec!["ibc", "clients/07-tendermint-0/clientState"]
+        );
+    }
+
+    #[test]
+    fn key_path_from_merkle_path() {
+        let prefix = MerklePrefix::from(b"ibc".to_vec());
+        let path = MerklePath::apply_prefix(&prefix, "clients/07-tendermint-0/clientState");
+
+        assert_eq!(
+            MerkleKeyPath::from(path),
+            MerkleKeyPath::apply_prefix(&prefix, "clients/07-tendermint-0/clientState")
+        );
+        assert_eq!(
+            MerkleKeyPath::apply_prefix(&prefix, b"receipts/\xff\x00".to_vec()).key_path,
+            vec![b"ibc".to_vec(), b"receipts/\xff\x00".to_vec()]
+        );
+    }
+}
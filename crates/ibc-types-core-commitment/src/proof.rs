@@ -1,6 +1,7 @@
 use crate::prelude::*;
 
-use crate::MerklePath;
+use crate::Error;
+use crate::MerkleKeyPath;
 use crate::MerkleRoot;
 
 use ibc_proto::ibc::core::commitment::v1::MerkleProof as RawMerkleProof;
@@ -48,48 +49,70 @@ impl TryFrom<RawMerkleProof> for MerkleProof {
 }
 
 // TODO move to ics23
-fn calculate_non_existence_root(proof: &NonExistenceProof) -> Result<Vec<u8>, anyhow::Error> {
+fn calculate_non_existence_root(proof: &NonExistenceProof) -> Result<Vec<u8>, Error> {
     if let Some(left) = &proof.left {
         calculate_existence_root::<ics23::HostFunctionsManager>(left)
-            .map_err(|_| anyhow::anyhow!("invalid merkle proof"))
+            .map_err(|_| Error::InvalidMerkleProof)
     } else if let Some(right) = &proof.right {
         calculate_existence_root::<ics23::HostFunctionsManager>(right)
-            .map_err(|_| anyhow::anyhow!("invalid merkle proof"))
+            .map_err(|_| Error::InvalidMerkleProof)
     } else {
-        Err(anyhow::anyhow!("invalid merkle proof"))
+        Err(Error::InvalidMerkleProof)
     }
 }
 
 impl MerkleProof {
+    /// Returns `true` if this proof holds no commitment proofs, or only ones
+    /// with no content, as results from decoding an empty byte string.
+    ///
+    /// Both verification methods reject such proofs with
+    /// [`Error::EmptyMerkleProof`].
+    pub fn is_empty(&self) -> bool {
+        self.proofs.iter().all(|p| p.proof.is_none())
+    }
+
+    /// Verifies that `value` is committed to under `keys` in the tree with the
+    /// given `root`, checking the proofs from `start_index` onwards against
+    /// the corresponding `specs`.
+    ///
+    /// The proofs are ordered from leaf-to-root, while `keys` are ordered from
+    /// root-to-leaf. `keys` may be a [`MerklePath`](crate::MerklePath) or,
+    /// for keys that are not UTF-8, a [`MerkleKeyPath`]; either way the raw
+    /// key bytes are verified.
     pub fn verify_membership(
         &self,
         specs: &[ics23::ProofSpec],
         root: MerkleRoot,
-        keys: MerklePath,
+        keys: impl Into<MerkleKeyPath>,
         value: Vec<u8>,
         start_index: usize,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<(), Error> {
+        let keys = keys.into();
         // validate arguments
-        if self.proofs.is_empty() {
-            return Err(anyhow::anyhow!("proofs cannot be empty"));
+        if self.is_empty() {
+            return Err(Error::EmptyMerkleProof);
         }
-        if root.hash.is_empty() {
-            return Err(anyhow::anyhow!("root hash cannot be empty"));
+        // An empty root, or the sentinel root of an upgraded client's
+        // consensus state, commits to nothing, so proofs must never verify
+        // against it.
+        if root.is_empty_or_sentinel() {
+            return Err(Error::EmptyMerkleRoot);
         }
         let num = self.proofs.len();
-        let ics23_specs = Vec::<ics23::ProofSpec>::from(specs);
-        if ics23_specs.len() != num {
-            return Err(anyhow::anyhow!(
-                "number of specs does not match number of proofs"
-            ));
+        if specs.len() != num {
+            return Err(Error::NumberOfSpecsMismatch {
+                proofs: num,
+                specs: specs.len(),
+            });
         }
         if keys.key_path.len() != num {
-            return Err(anyhow::anyhow!(
-                "number of keys does not match number of proofs"
-            ));
+            return Err(Error::NumberOfKeysMismatch {
+                proofs: num,
+                keys: keys.key_path.len(),
+            });
         }
         if value.is_empty() {
-            return Err(anyhow::anyhow!("value cannot be empty"));
+            return Err(Error::EmptyVerifiedValue);
         }
 
         let mut subroot = value.clone();
@@ -98,7 +121,7 @@ impl MerkleProof {
         for ((proof, spec), key) in self
             .proofs
             .iter()
-            .zip(ics23_specs.iter())
+            .zip(specs.iter())
             .zip(keys.key_path.iter().rev())
             .skip(start_index)
         {
@@ -106,88 +129,467 @@ impl MerkleProof {
                 Some(Proof::Exist(existence_proof)) => {
                     subroot =
                         calculate_existence_root::<ics23::HostFunctionsManager>(existence_proof)
-                            .map_err(|_| anyhow::anyhow!("invalid merkle proof"))?;
+                            .map_err(|_| Error::InvalidMerkleProof)?;
 
                     if !verify_membership::<ics23::HostFunctionsManager>(
-                        proof,
-                        spec,
-                        &subroot,
-                        key.as_bytes(),
-                        &value,
+                        proof, spec, &subroot, key, &value,
                     ) {
-                        return Err(anyhow::anyhow!("merkle proof verification failed"));
+                        return Err(Error::VerificationFailure);
                     }
                     value = subroot.clone();
                 }
-                _ => return Err(anyhow::anyhow!("invalid merkle proof")),
+                _ => return Err(Error::InvalidMerkleProof),
             }
         }
 
         if root.hash != subroot {
-            return Err(anyhow::anyhow!(
-                "merkle proof verification failed: root hash does not match"
-            ));
+            return Err(Error::RootMismatch);
         }
 
         Ok(())
     }
 
+    /// Verifies that nothing is committed to under `keys` in the tree with the
+    /// given `root`.
+    ///
+    /// The first proof must be a non-existence proof for the last key, and the
+    /// remaining proofs must be existence proofs of the resulting subroots.
+    /// As with [`MerkleProof::verify_membership`], `keys` is verified as raw
+    /// bytes.
     pub fn verify_non_membership(
         &self,
         specs: &[ics23::ProofSpec],
         root: MerkleRoot,
-        keys: MerklePath,
-    ) -> Result<(), anyhow::Error> {
+        keys: impl Into<MerkleKeyPath>,
+    ) -> Result<(), Error> {
+        let keys = keys.into();
         // validate arguments
-        if self.proofs.is_empty() {
-            return Err(anyhow::anyhow!("proofs cannot be empty"));
+        if self.is_empty() {
+            return Err(Error::EmptyMerkleProof);
         }
-        if root.hash.is_empty() {
-            return Err(anyhow::anyhow!("root hash cannot be empty"));
+        // An empty root, or the sentinel root of an upgraded client's
+        // consensus state, commits to nothing, so proofs must never verify
+        // against it.
+        if root.is_empty_or_sentinel() {
+            return Err(Error::EmptyMerkleRoot);
         }
         let num = self.proofs.len();
-        let ics23_specs = Vec::<ics23::ProofSpec>::from(specs);
-        if ics23_specs.len() != num {
-            return Err(anyhow::anyhow!(
-                "number of specs does not match number of proofs"
-            ));
+        if specs.len() != num {
+            return Err(Error::NumberOfSpecsMismatch {
+                proofs: num,
+                specs: specs.len(),
+            });
         }
         if keys.key_path.len() != num {
-            return Err(anyhow::anyhow!(
-                "number of keys does not match number of proofs"
-            ));
+            return Err(Error::NumberOfKeysMismatch {
+                proofs: num,
+                keys: keys.key_path.len(),
+            });
         }
 
         // verify the absence of key in lowest subtree
-        let proof = self
-            .proofs
-            .first()
-            .ok_or(anyhow::anyhow!("invalid merkle proof"))?;
-        let spec = ics23_specs
-            .first()
-            .ok_or(anyhow::anyhow!("invalid merkle proof"))?;
+        let proof = self.proofs.first().ok_or(Error::InvalidMerkleProof)?;
+        let spec = specs.first().ok_or(Error::InvalidMerkleProof)?;
         // keys are represented from root-to-leaf
         let key = keys
             .key_path
             .get(num - 1)
-            .ok_or(anyhow::anyhow!("invalid merkle proof"))?;
+            .ok_or(Error::InvalidMerkleProof)?;
         match &proof.proof {
             Some(Proof::Nonexist(non_existence_proof)) => {
                 let subroot = calculate_non_existence_root(non_existence_proof)?;
 
-                if !verify_non_membership::<ics23::HostFunctionsManager>(
-                    proof,
-                    spec,
-                    &subroot,
-                    key.as_bytes(),
-                ) {
-                    return Err(anyhow::anyhow!("merkle proof verification failed"));
+                if !verify_non_membership::<ics23::HostFunctionsManager>(proof, spec, &subroot, key)
+                {
+                    return Err(Error::VerificationFailure);
                 }
 
                 // verify membership proofs starting from index 1 with value = subroot
                 self.verify_membership(specs, root, keys, subroot, 1)
             }
-            _ => Err(anyhow::anyhow!("invalid merkle proof")),
+            _ => Err(Error::InvalidMerkleProof),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    use ics23::{ExistenceProof, HashOp, InnerOp};
+
+    use crate::{MerklePath, MerklePrefix, SENTINEL_ROOT};
+
+    fn root_of(proof: &ExistenceProof) -> Vec<u8> {
+        calculate_existence_root::<ics23::HostFunctionsManager>(proof).unwrap()
+    }
+
+    fn leaf(key: &[u8], value: &[u8]) -> ExistenceProof {
+        ExistenceProof {
+            key: key.to_vec(),
+            value: value.to_vec(),
+            leaf: ics23::tendermint_spec().leaf_spec,
+            path: vec![],
+        }
+    }
+
+    fn exist(proof: ExistenceProof) -> CommitmentProof {
+        CommitmentProof {
+            proof: Some(Proof::Exist(proof)),
+        }
+    }
+
+    fn path(keys: &[&str]) -> MerklePath {
+        MerklePath {
+            key_path: keys.iter().map(|k| k.to_string()).collect(),
+        }
+    }
+
+    /// An IAVL leaf at height 0 and size 1, written at version 1.
+    ///
+    /// IAVL node prefixes are the zigzag varints of the node's height, size
+    /// and version, which `ics23` checks when verifying against `iavl_spec`.
+    fn iavl_leaf(key: &[u8], value: &[u8]) -> ExistenceProof {
+        ExistenceProof {
+            key: key.to_vec(),
+            value: value.to_vec(),
+            leaf: Some(ics23::LeafOp {
+                prefix: vec![0, 2, 2],
+                ..ics23::iavl_spec().leaf_spec.unwrap()
+            }),
+            path: vec![],
+        }
+    }
+
+    /// Test vectors laid out the way an ibc-go chain commits to IBC state: an
+    /// IAVL store holding `a` and `b`, itself committed to under the key `ibc`
+    /// in the Tendermint-spec multistore whose root is the app hash.
+    struct Vectors {
+        specs: Vec<ics23::ProofSpec>,
+        root: MerkleRoot,
+        /// existence proof of `a` (the left-most leaf) in the store
+        a: ExistenceProof,
+        /// existence proof of `b` (the right-most leaf) in the store
+        b: ExistenceProof,
+        /// existence proof of the store root in the app hash
+        store: ExistenceProof,
+    }
+
+    fn vectors() -> Vectors {
+        let a_hash = root_of(&iavl_leaf(b"a", b"value-a"));
+        let b_hash = root_of(&iavl_leaf(b"b", b"value-b"));
+
+        // the inner node has height 1 and size 2, and each child hash is
+        // length-prefixed
+        let a = ExistenceProof {
+            path: vec![InnerOp {
+                hash: HashOp::Sha256.into(),
+                prefix: vec![2, 4, 2, 32],
+                suffix: [vec![32], b_hash].concat(),
+            }],
+            ..iavl_leaf(b"a", b"value-a")
+        };
+        let b = ExistenceProof {
+            path: vec![InnerOp {
+                hash: HashOp::Sha256.into(),
+                prefix: [vec![2, 4, 2, 32], a_hash, vec![32]].concat(),
+                suffix: vec![],
+            }],
+            ..iavl_leaf(b"b", b"value-b")
+        };
+        let store_root = root_of(&a);
+        assert_eq!(store_root, root_of(&b));
+
+        let store = leaf(b"ibc", &store_root);
+        let root = MerkleRoot {
+            hash: root_of(&store),
+        };
+
+        Vectors {
+            specs: vec![ics23::iavl_spec(), ics23::tendermint_spec()],
+            root,
+            a,
+            b,
+            store,
+        }
+    }
+
+    #[test]
+    fn verify_membership() {
+        let v = vectors();
+
+        for (key, value, proof) in [("a", "value-a", &v.a), ("b", "value-b", &v.b)] {
+            let merkle_proof = MerkleProof {
+                proofs: vec![exist(proof.clone()), exist(v.store.clone())],
+            };
+            merkle_proof
+                .verify_membership(
+                    &v.specs,
+                    v.root.clone(),
+                    path(&["ibc", key]),
+                    value.as_bytes().to_vec(),
+                    0,
+                )
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn verify_membership_failures() {
+        let v = vectors();
+        let proof = MerkleProof {
+            proofs: vec![exist(v.a.clone()), exist(v.store.clone())],
+        };
+        let verify = |proof: &MerkleProof,
+                      specs: &[ics23::ProofSpec],
+                      root: &[u8],
+                      keys: &[&str],
+                      value: &[u8]| {
+            proof.verify_membership(
+                specs,
+                MerkleRoot {
+                    hash: root.to_vec(),
+                },
+                path(keys),
+                value.to_vec(),
+                0,
+            )
+        };
+
+        assert!(matches!(
+            verify(&proof, &v.specs, &v.root.hash, &["ibc", "a"], b"other"),
+            Err(Error::VerificationFailure)
+        ));
+        assert!(matches!(
+            verify(&proof, &v.specs, &v.root.hash, &["ibc", "b"], b"value-a"),
+            Err(Error::VerificationFailure)
+        ));
+        assert!(matches!(
+            verify(&proof, &v.specs, &v.root.hash, &["other", "a"], b"value-a"),
+            Err(Error::VerificationFailure)
+        ));
+        assert!(matches!(
+            verify(&proof, &v.specs, &[0; 32], &["ibc", "a"], b"value-a"),
+            Err(Error::RootMismatch)
+        ));
+        assert!(matches!(
+            verify(
+                &MerkleProof { proofs: vec![] },
+                &[],
+                &v.root.hash,
+                &[],
+                b"value-a"
+            ),
+            Err(Error::EmptyMerkleProof)
+        ));
+        assert!(matches!(
+            verify(&proof, &v.specs, &[], &["ibc", "a"], b"value-a"),
+            Err(Error::EmptyMerkleRoot)
+        ));
+        assert!(matches!(
+            verify(&proof, &v.specs, SENTINEL_ROOT, &["ibc", "a"], b"value-a"),
+            Err(Error::EmptyMerkleRoot)
+        ));
+        assert!(matches!(
+            verify(
+                &proof,
+                &v.specs[..1],
+                &v.root.hash,
+                &["ibc", "a"],
+                b"value-a"
+            ),
+            Err(Error::NumberOfSpecsMismatch {
+                proofs: 2,
+                specs: 1
+            })
+        ));
+        assert!(matches!(
+            verify(&proof, &v.specs, &v.root.hash, &["a"], b"value-a"),
+            Err(Error::NumberOfKeysMismatch { proofs: 2, keys: 1 })
+        ));
+        assert!(matches!(
+            verify(&proof, &v.specs, &v.root.hash, &["ibc", "a"], b""),
+            Err(Error::EmptyVerifiedValue)
+        ));
+    }
+
+    #[test]
+    fn empty_proofs() {
+        let v = vectors();
+
+        let decoded = MerkleProof::decode(&[][..]).unwrap();
+        let contentless = MerkleProof {
+            proofs: vec![CommitmentProof { proof: None }; 2],
+        };
+        for proof in [decoded, contentless] {
+            assert!(proof.is_empty());
+            assert!(matches!(
+                proof.verify_membership(
+                    &v.specs,
+                    v.root.clone(),
+                    path(&["ibc", "a"]),
+                    b"value-a".to_vec(),
+                    0
+                ),
+                Err(Error::EmptyMerkleProof)
+            ));
+            assert!(matches!(
+                proof.verify_non_membership(&v.specs, v.root.clone(), path(&["ibc", "c"])),
+                Err(Error::EmptyMerkleProof)
+            ));
+        }
+    }
+
+    #[test]
+    fn single_step_proof() {
+        let v = vectors();
+        let proof = MerkleProof {
+            proofs: vec![exist(v.a.clone())],
+        };
+        assert!(!proof.is_empty());
+
+        let store_root = MerkleRoot {
+            hash: root_of(&v.a),
+        };
+        proof
+            .verify_membership(
+                &v.specs[..1],
+                store_root.clone(),
+                path(&["a"]),
+                b"value-a".to_vec(),
+                0,
+            )
+            .unwrap();
+        assert!(matches!(
+            proof.verify_membership(
+                &v.specs[..1],
+                store_root,
+                path(&["a"]),
+                b"other".to_vec(),
+                0
+            ),
+            Err(Error::VerificationFailure)
+        ));
+    }
+
+    #[test]
+    fn verify_membership_non_utf8_key() {
+        // a receipt-style key ending in a big-endian sequence number
+        let key = [b"receipts/".as_slice(), &[0x00, 0xff, 0xfe, 0x80]].concat();
+        assert!(core::str::from_utf8(&key).is_err());
+
+        let receipt = leaf(&key, &[1]);
+        let store = leaf(b"ibc", &root_of(&receipt));
+        let root = MerkleRoot {
+            hash: root_of(&store),
+        };
+        let proof = MerkleProof {
+            proofs: vec![exist(receipt), exist(store)],
+        };
+        let specs = [ics23::tendermint_spec(), ics23::tendermint_spec()];
+        let prefix = MerklePrefix::from(b"ibc".to_vec());
+
+        proof
+            .verify_membership(
+                &specs,
+                root.clone(),
+                MerkleKeyPath::apply_prefix(&prefix, key.clone()),
+                vec![1],
+                0,
+            )
+            .unwrap();
+        // a lossy UTF-8 rendering of the key is a different key
+        let lossy = String::from_utf8_lossy(&key).into_owned();
+        assert!(matches!(
+            proof.verify_membership(
+                &specs,
+                root,
+                MerklePath::apply_prefix(&prefix, lossy),
+                vec![1],
+                0
+            ),
+            Err(Error::VerificationFailure)
+        ));
+    }
+
+    #[test]
+    fn verify_non_membership() {
+        let v = vectors();
+        let non_exist =
+            |key: &[u8], left: Option<&ExistenceProof>, right: Option<&ExistenceProof>| {
+                MerkleProof {
+                    proofs: vec![
+                        CommitmentProof {
+                            proof: Some(Proof::Nonexist(NonExistenceProof {
+                                key: key.to_vec(),
+                                left: left.cloned(),
+                                right: right.cloned(),
+                            })),
+                        },
+                        exist(v.store.clone()),
+                    ],
+                }
+            };
+
+        // `0` sorts before the left-most leaf `a`
+        non_exist(b"0", None, Some(&v.a))
+            .verify_non_membership(&v.specs, v.root.clone(), path(&["ibc", "0"]))
+            .unwrap();
+        // `ab` sorts between the neighbouring leaves `a` and `b`
+        non_exist(b"ab", Some(&v.a), Some(&v.b))
+            .verify_non_membership(&v.specs, v.root.clone(), path(&["ibc", "ab"]))
+            .unwrap();
+        // `c` sorts after the right-most leaf `b`
+        non_exist(b"c", Some(&v.b), None)
+            .verify_non_membership(&v.specs, v.root.clone(), path(&["ibc", "c"]))
+            .unwrap();
+
+        // `b` is present, so no non-existence proof can be valid for it
+        assert!(matches!(
+            non_exist(b"b", Some(&v.b), None).verify_non_membership(
+                &v.specs,
+                v.root.clone(),
+                path(&["ibc", "b"])
+            ),
+            Err(Error::VerificationFailure)
+        ));
+        // `a` is not the right-most leaf
+        assert!(matches!(
+            non_exist(b"c", Some(&v.a), None).verify_non_membership(
+                &v.specs,
+                v.root.clone(),
+                path(&["ibc", "c"])
+            ),
+            Err(Error::VerificationFailure)
+        ));
+        // a non-existence proof against the wrong root
+        assert!(matches!(
+            non_exist(b"c", Some(&v.b), None).verify_non_membership(
+                &v.specs,
+                MerkleRoot { hash: vec![0; 32] },
+                path(&["ibc", "c"])
+            ),
+            Err(Error::RootMismatch)
+        ));
+        // nothing can be verified against an empty or sentinel root
+        for root in [vec![], SENTINEL_ROOT.to_vec()] {
+            assert!(matches!(
+                non_exist(b"c", Some(&v.b), None).verify_non_membership(
+                    &v.specs,
+                    MerkleRoot::from_bytes(root),
+                    path(&["ibc", "c"])
+                ),
+                Err(Error::EmptyMerkleRoot)
+            ));
         }
+        // an existence proof cannot be used to prove non-membership
+        let proof = MerkleProof {
+            proofs: vec![exist(v.a.clone()), exist(v.store.clone())],
+        };
+        assert!(matches!(
+            proof.verify_non_membership(&v.specs, v.root.clone(), path(&["ibc", "a"])),
+            Err(Error::InvalidMerkleProof)
+        ));
     }
 }
@@ -4,6 +4,7 @@ use crate::prelude::*;
 
 use ibc_proto::ibc::core::commitment::v1::MerkleRoot as RawMerkleRoot;
 use ibc_types_domain_type::DomainType;
+use tendermint::hash::{AppHash, Hash};
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct MerkleRoot {
@@ -16,6 +17,53 @@ impl Debug for MerkleRoot {
     }
 }
 
+/// The root of the consensus state stored for an upgraded client, which only
+/// marks the upgrade and does not commit to any state.
+pub const SENTINEL_ROOT: &[u8] = b"sentinel_root";
+
+impl MerkleRoot {
+    /// Constructs a root from the raw bytes of a commitment, e.g. an app hash.
+    pub fn from_bytes(hash: Vec<u8>) -> Self {
+        MerkleRoot { hash }
+    }
+
+    /// Returns the raw bytes of this root.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.hash
+    }
+
+    /// Returns whether this root is empty or the upgrade [`SENTINEL_ROOT`],
+    /// i.e. whether it commits to no state at all.
+    pub fn is_empty_or_sentinel(&self) -> bool {
+        self.hash.is_empty() || self.hash == SENTINEL_ROOT
+    }
+}
+
+impl AsRef<[u8]> for MerkleRoot {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl From<Vec<u8>> for MerkleRoot {
+    fn from(hash: Vec<u8>) -> Self {
+        MerkleRoot::from_bytes(hash)
+    }
+}
+
+impl From<AppHash> for MerkleRoot {
+    fn from(app_hash: AppHash) -> Self {
+        MerkleRoot::from_bytes(app_hash.as_bytes().to_vec())
+    }
+}
+
+/// A [`Hash::None`] converts to an empty root.
+impl From<Hash> for MerkleRoot {
+    fn from(hash: Hash) -> Self {
+        MerkleRoot::from_bytes(hash.as_bytes().to_vec())
+    }
+}
+
 impl DomainType for MerkleRoot {
     type Proto = RawMerkleRoot;
 }
@@ -32,3 +80,33 @@ impl TryFrom<RawMerkleRoot> for MerkleRoot {
         Ok(MerkleRoot { hash: value.hash })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    use tendermint::hash::Algorithm;
+
+    #[test]
+    fn root_from_app_hash() {
+        let bytes: Vec<u8> = (0..32).collect();
+
+        let root = MerkleRoot::from_bytes(bytes.clone());
+        assert_eq!(root.as_bytes(), bytes.as_slice());
+        assert_eq!(root.as_ref(), bytes.as_slice());
+        assert_eq!(root, MerkleRoot::from(bytes.clone()));
+
+        let app_hash = AppHash::try_from(bytes.clone()).unwrap();
+        assert_eq!(MerkleRoot::from(app_hash), root);
+
+        let hash = Hash::from_bytes(Algorithm::Sha256, &bytes).unwrap();
+        assert_eq!(MerkleRoot::from(hash), root);
+        assert!(MerkleRoot::from(Hash::None).as_bytes().is_empty());
+
+        // the proto conversions are unaffected
+        let raw = RawMerkleRoot::from(root.clone());
+        assert_eq!(raw.hash, bytes);
+        assert_eq!(MerkleRoot::try_from(raw).unwrap(), root);
+    }
+}
@@ -1,10 +1,10 @@
 use crate::prelude::*;
 
+use alloc::collections::{BTreeMap, BTreeSet};
 use core::{
     fmt::{Display, Error as FmtError, Formatter},
     str::FromStr,
     time::Duration,
-    u64,
 };
 
 use ibc_proto::ibc::core::connection::v1::{
@@ -14,21 +14,91 @@ use ibc_proto::ibc::core::connection::v1::{
 
 use ibc_proto::Protobuf;
 
-use ibc_types_core_client::ClientId;
+use bytes::BufMut;
+use ibc_types_core_client::{ClientId, FieldChange};
 use ibc_types_core_commitment::MerklePrefix;
-use ibc_types_domain_type::DomainType;
+use ibc_types_domain_type::{encoding, DomainType};
 use ibc_types_timestamp::ZERO_DURATION;
 
 use crate::{ConnectionError, ConnectionId, Version};
 
 /// ClientPaths contains a list of all of the connection paths that are associated with a specific
 /// client
-#[derive(Clone, Debug, PartialEq, Eq)]
+///
+/// This is the value stored under `clients/{client_id}/connections`, and
+/// returned by the `ClientConnections` query, for the client whose id is in
+/// that path. Use [`ClientPaths::index`] to build these values for every
+/// client from a set of connections.
+///
+/// [`ClientPaths::push`] and the `FromIterator` conversion skip ids that are
+/// already present, keeping the first occurrence of each id in order.
+/// [`ClientPaths::new`], the `From<Vec<ConnectionId>>` conversion, and
+/// decoding from protobuf keep the paths exactly as given.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "with_serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClientPaths {
     pub paths: Vec<ConnectionId>,
 }
 
+impl ClientPaths {
+    pub fn new(paths: Vec<ConnectionId>) -> Self {
+        Self { paths }
+    }
+
+    /// The ids of the connections associated with the client.
+    pub fn connection_ids(&self) -> &[ConnectionId] {
+        &self.paths
+    }
+
+    /// Records a connection for the client, ignoring ids already present.
+    ///
+    /// This scans the existing paths; to build paths from many ids at once,
+    /// collect them into a [`ClientPaths`] instead.
+    pub fn push(&mut self, connection_id: ConnectionId) {
+        if !self.paths.contains(&connection_id) {
+            self.paths.push(connection_id);
+        }
+    }
+
+    /// Groups `connections` by the id of the client each one is built on,
+    /// giving the [`ClientPaths`] to store under each client's
+    /// `clients/{client_id}/connections` path.
+    pub fn index<'a>(
+        connections: impl IntoIterator<Item = &'a IdentifiedConnectionEnd>,
+    ) -> BTreeMap<ClientId, ClientPaths> {
+        let mut index = BTreeMap::<ClientId, ClientPaths>::new();
+        let mut seen = BTreeSet::new();
+        for connection in connections {
+            let client_id = &connection.end().client_id;
+            if seen.insert((client_id, connection.id())) {
+                index
+                    .entry(client_id.clone())
+                    .or_default()
+                    .paths
+                    .push(connection.id().clone());
+            }
+        }
+        index
+    }
+}
+
+impl From<Vec<ConnectionId>> for ClientPaths {
+    fn from(paths: Vec<ConnectionId>) -> Self {
+        Self::new(paths)
+    }
+}
+
+impl FromIterator<ConnectionId> for ClientPaths {
+    fn from_iter<I: IntoIterator<Item = ConnectionId>>(iter: I) -> Self {
+        let mut seen = BTreeSet::new();
+        let paths = iter
+            .into_iter()
+            .filter(|connection_id| seen.insert(connection_id.clone()))
+            .collect();
+        Self::new(paths)
+    }
+}
+
 impl DomainType for ClientPaths {
     type Proto = RawClientPaths;
 }
@@ -69,6 +139,16 @@ impl IdentifiedConnectionEnd {
         }
     }
 
+    /// Like [`IdentifiedConnectionEnd::new`], but rejects a `connection_end`
+    /// that fails [`ConnectionEnd::validate_basic`].
+    pub fn try_new(
+        connection_id: ConnectionId,
+        connection_end: ConnectionEnd,
+    ) -> Result<Self, ConnectionError> {
+        connection_end.validate_basic()?;
+        Ok(Self::new(connection_id, connection_end))
+    }
+
     pub fn id(&self) -> &ConnectionId {
         &self.connection_id
     }
@@ -195,6 +275,35 @@ impl From<ConnectionEnd> for RawConnectionEnd {
 }
 
 impl ConnectionEnd {
+    /// Creates a new ConnectionEnd in state Open, e.g. to describe an
+    /// established connection in tests.
+    pub fn new_open(
+        client_id: ClientId,
+        counterparty: Counterparty,
+        versions: Vec<Version>,
+        delay_period: Duration,
+    ) -> Self {
+        Self {
+            state: State::Open,
+            client_id,
+            counterparty,
+            versions,
+            delay_period,
+        }
+    }
+
+    /// Returns a [`ConnectionEndBuilder`], starting from the default
+    /// (uninitialized) end.
+    pub fn builder() -> ConnectionEndBuilder {
+        ConnectionEndBuilder::default()
+    }
+
+    /// Verifies that `version` is supported by this end's versions; see
+    /// [`Version::verify_is_supported`].
+    pub fn verify_version_supported(&self, version: &Version) -> Result<(), ConnectionError> {
+        version.verify_is_supported(&self.versions)
+    }
+
     /// Helper function to compare the counterparty of this end with another counterparty.
     pub fn counterparty_matches(&self, other: &Counterparty) -> bool {
         self.counterparty.eq(other)
@@ -215,10 +324,141 @@ impl ConnectionEnd {
         self.state_matches(&State::Uninitialized)
     }
 
+    /// Helper function to determine whether the connection is in state `Init`.
+    pub fn is_init(&self) -> bool {
+        self.state_matches(&State::Init)
+    }
+
+    /// Helper function to determine whether the connection is in state `TryOpen`.
+    pub fn is_tryopen(&self) -> bool {
+        self.state_matches(&State::TryOpen)
+    }
+
     /// Helper function to compare the state of this end with another state.
     pub fn state_matches(&self, other: &State) -> bool {
         self.state.eq(other)
     }
+
+    /// Checks the internal consistency of this end: any end past
+    /// [`State::Uninitialized`] must list at least one version, and an end in
+    /// `TryOpen` or `Open` must know its counterparty's connection id.
+    pub fn validate_basic(&self) -> Result<(), ConnectionError> {
+        if !self.is_uninitialized() && self.versions.is_empty() {
+            return Err(ConnectionError::EmptyVersions);
+        }
+        if (self.is_tryopen() || self.is_open()) && self.counterparty.connection_id.is_none() {
+            return Err(ConnectionError::MissingCounterparty);
+        }
+        Ok(())
+    }
+
+    /// Returns the length of this end's [`RawConnectionEnd`] encoding,
+    /// without cloning it into a [`RawConnectionEnd`] first.
+    pub fn encoded_len_borrowed(&self) -> usize {
+        let versions_len: usize = self
+            .versions
+            .iter()
+            .map(|v| encoding::length_delimited_len(2, v.raw_encoded_len()))
+            .sum();
+
+        encoding::bytes_len(1, self.client_id.as_bytes())
+            + versions_len
+            + encoding::enumeration_len(3, self.state as i32)
+            + encoding::length_delimited_len(4, self.counterparty.raw_encoded_len())
+            + encoding::uint64_len(5, self.delay_period.as_nanos() as u64)
+    }
+
+    /// Encodes this end as a [`RawConnectionEnd`] into `buf`, without cloning
+    /// it first. The output is identical to encoding the owned proto type.
+    pub fn encode_borrowed(&self, buf: &mut impl BufMut) {
+        encoding::encode_bytes(1, self.client_id.as_bytes(), buf);
+        for version in &self.versions {
+            encoding::encode_length_delimited_header(2, version.raw_encoded_len(), buf);
+            version.encode_raw(buf);
+        }
+        encoding::encode_enumeration(3, self.state as i32, buf);
+        encoding::encode_length_delimited_header(4, self.counterparty.raw_encoded_len(), buf);
+        self.counterparty.encode_raw(buf);
+        encoding::encode_uint64(5, self.delay_period.as_nanos() as u64, buf);
+    }
+
+    /// Encodes this end as a [`RawConnectionEnd`] into a new byte vector,
+    /// without cloning it first.
+    pub fn encode_to_vec_borrowed(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(self.encoded_len_borrowed());
+        self.encode_borrowed(&mut buf);
+        buf
+    }
+
+    /// Appends this end's [`RawConnectionEnd`] encoding to `buf`, reserving the
+    /// encoded length up front so that `buf` is grown at most once.
+    pub fn encode_to(&self, buf: &mut Vec<u8>) {
+        buf.reserve(self.encoded_len_borrowed());
+        self.encode_borrowed(buf);
+    }
+
+    /// Returns the bytes a counterparty chain commits to for this end, i.e.
+    /// the expected value when verifying a proof of this connection.
+    ///
+    /// This is the [`RawConnectionEnd`] encoding, as in ibc-go.
+    pub fn encode_for_proof(&self) -> Vec<u8> {
+        self.encode_to_vec_borrowed()
+    }
+
+    /// Lists the fields that differ between this end and `other`, e.g. to
+    /// review a proposed connection upgrade.
+    pub fn diff(&self, other: &ConnectionEnd) -> Vec<FieldChange> {
+        [
+            FieldChange::compare("state", &self.state, &other.state),
+            FieldChange::compare("client_id", &self.client_id, &other.client_id),
+            FieldChange::compare("counterparty", &self.counterparty, &other.counterparty),
+            FieldChange::compare("versions", &self.versions, &other.versions),
+            FieldChange::compare("delay_period", &self.delay_period, &other.delay_period),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+/// Builds a [`ConnectionEnd`] field by field; see [`ConnectionEnd::builder`].
+#[derive(Clone, Debug, Default)]
+pub struct ConnectionEndBuilder {
+    end: ConnectionEnd,
+}
+
+impl ConnectionEndBuilder {
+    pub fn state(mut self, state: State) -> Self {
+        self.end.state = state;
+        self
+    }
+
+    pub fn client_id(mut self, client_id: ClientId) -> Self {
+        self.end.client_id = client_id;
+        self
+    }
+
+    pub fn counterparty(mut self, counterparty: Counterparty) -> Self {
+        self.end.counterparty = counterparty;
+        self
+    }
+
+    pub fn versions(mut self, versions: Vec<Version>) -> Self {
+        self.end.versions = versions;
+        self
+    }
+
+    pub fn delay_period(mut self, delay_period: Duration) -> Self {
+        self.end.delay_period = delay_period;
+        self
+    }
+
+    /// Builds the [`ConnectionEnd`], failing if it does not pass
+    /// [`ConnectionEnd::validate_basic`].
+    pub fn build(self) -> Result<ConnectionEnd, ConnectionError> {
+        self.end.validate_basic()?;
+        Ok(self.end)
+    }
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
@@ -229,6 +469,60 @@ pub struct Counterparty {
     pub prefix: MerklePrefix,
 }
 
+impl Counterparty {
+    pub fn new(
+        client_id: ClientId,
+        connection_id: Option<ConnectionId>,
+        prefix: MerklePrefix,
+    ) -> Self {
+        Self {
+            client_id,
+            connection_id,
+            prefix,
+        }
+    }
+
+    pub fn client_id(&self) -> &ClientId {
+        &self.client_id
+    }
+
+    /// The counterparty's connection identifier, which is `None` until the
+    /// counterparty has opened its end of the connection (i.e. while the
+    /// handshake is in `Init`).
+    pub fn connection_id(&self) -> Option<&ConnectionId> {
+        self.connection_id.as_ref()
+    }
+
+    pub fn prefix(&self) -> &MerklePrefix {
+        &self.prefix
+    }
+
+    fn connection_id_bytes(&self) -> &[u8] {
+        self.connection_id
+            .as_ref()
+            .map_or(&[][..], |id| id.as_bytes())
+    }
+
+    /// Length of this counterparty's [`RawCounterparty`] encoding.
+    fn raw_encoded_len(&self) -> usize {
+        encoding::bytes_len(1, self.client_id.as_bytes())
+            + encoding::bytes_len(2, self.connection_id_bytes())
+            + encoding::length_delimited_len(3, encoding::bytes_len(1, &self.prefix.key_prefix))
+    }
+
+    /// Encodes this counterparty as a [`RawCounterparty`], without cloning it first.
+    fn encode_raw(&self, buf: &mut impl BufMut) {
+        encoding::encode_bytes(1, self.client_id.as_bytes(), buf);
+        encoding::encode_bytes(2, self.connection_id_bytes(), buf);
+        encoding::encode_length_delimited_header(
+            3,
+            encoding::bytes_len(1, &self.prefix.key_prefix),
+            buf,
+        );
+        encoding::encode_bytes(1, &self.prefix.key_prefix, buf);
+    }
+}
+
 impl Protobuf<RawCounterparty> for Counterparty {}
 
 // Converts from the wire format RawCounterparty. Typically used from the relayer side
@@ -283,7 +577,8 @@ pub enum State {
 }
 
 impl State {
-    /// Yields the State as a string.
+    /// Yields the State as a string, using the short state names ibc-go
+    /// gives the proto enum variants (e.g. `OPEN` for `STATE_OPEN`).
     pub fn as_str(&self) -> &'static str {
         match self {
             Self::Uninitialized => "UNINITIALIZED",
@@ -300,7 +595,7 @@ impl State {
             1 => Ok(Self::Init),
             2 => Ok(Self::TryOpen),
             3 => Ok(Self::Open),
-            _ => Err(ConnectionError::InvalidState { state: s }),
+            _ => Err(ConnectionError::UnknownState { state: s }),
         }
     }
 
@@ -309,6 +604,11 @@ impl State {
         self == State::Open
     }
 
+    /// Returns whether or not this connection state is `Init`.
+    pub fn is_init(self) -> bool {
+        self == State::Init
+    }
+
     /// Returns whether or not this connection with this state
     /// has progressed less or the same than the argument.
     ///
@@ -329,6 +629,25 @@ impl Display for State {
     }
 }
 
+/// Parses the short state names produced by [`Display`], as well as the
+/// proto enum variant names (e.g. `STATE_OPEN`), ignoring case.
+impl FromStr for State {
+    type Err = ConnectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s_lower = s.to_lowercase();
+        match s_lower.strip_prefix("state_").unwrap_or(&s_lower) {
+            "uninitialized" | "uninitialized_unspecified" => Ok(Self::Uninitialized),
+            "init" => Ok(Self::Init),
+            "tryopen" => Ok(Self::TryOpen),
+            "open" => Ok(Self::Open),
+            _ => Err(ConnectionError::ParseState {
+                state: s.to_string(),
+            }),
+        }
+    }
+}
+
 impl TryFrom<i32> for State {
     type Error = ConnectionError;
     fn try_from(value: i32) -> Result<Self, Self::Error> {
@@ -337,13 +656,395 @@ impl TryFrom<i32> for State {
             1 => Ok(Self::Init),
             2 => Ok(Self::TryOpen),
             3 => Ok(Self::Open),
-            _ => Err(ConnectionError::InvalidState { state: value }),
+            _ => Err(ConnectionError::UnknownState { state: value }),
         }
     }
 }
 
 impl From<State> for i32 {
     fn from(value: State) -> Self {
-        value.into()
+        value as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    use prost::Message;
+
+    #[test]
+    fn encode_borrowed_matches_owned() {
+        let open = ConnectionEnd {
+            state: State::Open,
+            client_id: ClientId::default(),
+            counterparty: Counterparty {
+                client_id: "07-tendermint-12".parse().unwrap(),
+                connection_id: Some(ConnectionId::new(7)),
+                prefix: b"ibc".to_vec().into(),
+            },
+            versions: vec![
+                Version::default(),
+                Version {
+                    identifier: "2".to_string(),
+                    features: vec![],
+                },
+            ],
+            delay_period: Duration::from_secs(300),
+        };
+        let init = ConnectionEnd {
+            state: State::Init,
+            counterparty: Counterparty {
+                connection_id: None,
+                prefix: Vec::new().into(),
+                ..open.counterparty.clone()
+            },
+            versions: vec![],
+            delay_period: ZERO_DURATION,
+            ..open.clone()
+        };
+
+        for end in [open, init, ConnectionEnd::default()] {
+            let owned = RawConnectionEnd::from(end.clone()).encode_to_vec();
+            assert_eq!(end.encode_to_vec_borrowed(), owned);
+            assert_eq!(end.encoded_len_borrowed(), owned.len());
+        }
+    }
+
+    #[test]
+    fn encode_to_matches_encode_to_vec() {
+        let end = ConnectionEnd::new_open(
+            "07-tendermint-0".parse().unwrap(),
+            Counterparty::new(
+                "07-tendermint-12".parse().unwrap(),
+                Some(ConnectionId::new(7)),
+                b"ibc".to_vec().into(),
+            ),
+            vec![Version::default()],
+            Duration::from_secs(300),
+        );
+
+        let mut buf = Vec::new();
+        end.encode_to(&mut buf);
+        assert_eq!(buf, end.clone().encode_vec());
+
+        // encodings are appended to what is already in the buffer
+        end.encode_to(&mut buf);
+        assert_eq!(
+            buf,
+            [end.clone().encode_vec(), end.clone().encode_vec()].concat()
+        );
+    }
+
+    #[test]
+    fn connection_end_decode_rejects_unknown_state() {
+        let open = ConnectionEnd::new_open(
+            ClientId::default(),
+            Counterparty {
+                client_id: "07-tendermint-12".parse().unwrap(),
+                connection_id: Some(ConnectionId::new(7)),
+                prefix: b"ibc".to_vec().into(),
+            },
+            vec![Version::default()],
+            ZERO_DURATION,
+        );
+
+        // prost keeps unknown enum discriminants as raw `i32`s, so they must
+        // be rejected on conversion rather than decoding as `Uninitialized`.
+        let bytes = RawConnectionEnd {
+            state: 4,
+            ..RawConnectionEnd::from(open)
+        }
+        .encode_to_vec();
+        let raw = RawConnectionEnd::decode(bytes.as_slice()).unwrap();
+        assert_eq!(raw.state, 4);
+        assert!(matches!(
+            ConnectionEnd::try_from(raw),
+            Err(ConnectionError::UnknownState { state: 4 })
+        ));
+        assert!(<ConnectionEnd as Protobuf<RawConnectionEnd>>::decode_vec(&bytes).is_err());
+    }
+
+    #[test]
+    fn counterparty_accessors() {
+        let client_id: ClientId = "07-tendermint-12".parse().unwrap();
+        let prefix: MerklePrefix = b"ibc".to_vec().into();
+
+        let counterparty = Counterparty::new(
+            client_id.clone(),
+            Some(ConnectionId::new(7)),
+            prefix.clone(),
+        );
+        assert_eq!(counterparty.client_id(), &client_id);
+        assert_eq!(counterparty.connection_id(), Some(&ConnectionId::new(7)));
+        assert_eq!(counterparty.prefix(), &prefix);
+
+        // the counterparty has not opened its end yet
+        let pending = Counterparty::new(client_id, None, prefix);
+        assert_eq!(pending.connection_id(), None);
+        let raw = RawCounterparty::from(pending.clone());
+        assert!(raw.connection_id.is_empty());
+        assert_eq!(Counterparty::try_from(raw).unwrap(), pending);
+    }
+
+    #[test]
+    fn connection_end_builder() {
+        let counterparty = Counterparty {
+            client_id: "07-tendermint-12".parse().unwrap(),
+            connection_id: Some(ConnectionId::new(7)),
+            prefix: b"ibc".to_vec().into(),
+        };
+
+        let end = ConnectionEnd::builder()
+            .state(State::Open)
+            .client_id(ClientId::default())
+            .counterparty(counterparty.clone())
+            .versions(vec![Version::default()])
+            .delay_period(Duration::from_secs(300))
+            .build()
+            .unwrap();
+        assert_eq!(
+            end,
+            ConnectionEnd::new_open(
+                ClientId::default(),
+                counterparty.clone(),
+                vec![Version::default()],
+                Duration::from_secs(300),
+            )
+        );
+
+        assert!(matches!(
+            ConnectionEnd::builder()
+                .state(State::Open)
+                .counterparty(counterparty.clone())
+                .build(),
+            Err(ConnectionError::EmptyVersions)
+        ));
+        assert!(matches!(
+            ConnectionEnd::builder()
+                .state(State::TryOpen)
+                .counterparty(Counterparty {
+                    connection_id: None,
+                    ..counterparty
+                })
+                .versions(vec![Version::default()])
+                .build(),
+            Err(ConnectionError::MissingCounterparty)
+        ));
+        assert_eq!(
+            ConnectionEnd::builder().build().unwrap(),
+            ConnectionEnd::default()
+        );
+    }
+
+    #[test]
+    fn client_paths_from_connection_ids() {
+        let ids = vec![
+            ConnectionId::new(0),
+            ConnectionId::new(3),
+            ConnectionId::new(0),
+        ];
+        let client_paths: ClientPaths = ids.iter().cloned().collect();
+        assert_eq!(
+            client_paths.connection_ids(),
+            [ConnectionId::new(0), ConnectionId::new(3)]
+        );
+
+        let mut pushed = ClientPaths::default();
+        for id in ids.iter().cloned() {
+            pushed.push(id);
+        }
+        assert_eq!(pushed, client_paths);
+
+        // `new` and `From<Vec<_>>` keep the paths as given, like decoding
+        // from protobuf does
+        assert_eq!(
+            ClientPaths::new(ids.clone()).connection_ids(),
+            ids.as_slice()
+        );
+        assert_eq!(ClientPaths::from(ids.clone()), ClientPaths::new(ids));
+
+        let raw = RawClientPaths::from(client_paths.clone());
+        assert_eq!(raw.paths, vec!["connection-0", "connection-3"]);
+        assert_eq!(ClientPaths::try_from(raw).unwrap(), client_paths);
+    }
+
+    #[test]
+    fn client_paths_index() {
+        let client_a: ClientId = "07-tendermint-0".parse().unwrap();
+        let client_b: ClientId = "07-tendermint-1".parse().unwrap();
+        let connection = |id: u64, client_id: &ClientId| {
+            IdentifiedConnectionEnd::new(
+                ConnectionId::new(id),
+                ConnectionEnd {
+                    client_id: client_id.clone(),
+                    ..Default::default()
+                },
+            )
+        };
+        let connections = [
+            connection(0, &client_a),
+            connection(1, &client_b),
+            connection(2, &client_a),
+            connection(0, &client_a),
+        ];
+
+        let index = ClientPaths::index(&connections);
+        assert_eq!(index.len(), 2);
+        assert_eq!(
+            index[&client_a].connection_ids(),
+            [ConnectionId::new(0), ConnectionId::new(2)]
+        );
+        assert_eq!(index[&client_b].connection_ids(), [ConnectionId::new(1)]);
+    }
+
+    #[test]
+    fn identified_connection_end_try_new() {
+        let end = ConnectionEnd::new_open(
+            ClientId::default(),
+            Counterparty::new(
+                ClientId::default(),
+                Some(ConnectionId::new(1)),
+                b"ibc".to_vec().into(),
+            ),
+            vec![Version::default()],
+            Duration::from_secs(0),
+        );
+        let identified = IdentifiedConnectionEnd::try_new(ConnectionId::new(0), end.clone())
+            .expect("valid end is accepted");
+        assert_eq!(identified.end(), &end);
+
+        let no_versions = ConnectionEnd {
+            versions: vec![],
+            ..end.clone()
+        };
+        assert!(matches!(
+            IdentifiedConnectionEnd::try_new(ConnectionId::new(0), no_versions),
+            Err(ConnectionError::EmptyVersions)
+        ));
+
+        let no_counterparty_id = ConnectionEnd {
+            counterparty: Counterparty::new(ClientId::default(), None, b"ibc".to_vec().into()),
+            ..end
+        };
+        assert!(matches!(
+            IdentifiedConnectionEnd::try_new(ConnectionId::new(0), no_counterparty_id),
+            Err(ConnectionError::MissingCounterparty)
+        ));
+    }
+
+    #[test]
+    fn connection_end_state_predicates() {
+        let states = [
+            State::Uninitialized,
+            State::Init,
+            State::TryOpen,
+            State::Open,
+        ];
+
+        for state in states {
+            let end = ConnectionEnd {
+                state,
+                ..Default::default()
+            };
+            assert_eq!(end.is_uninitialized(), state == State::Uninitialized);
+            assert_eq!(end.is_init(), state == State::Init);
+            assert_eq!(end.is_tryopen(), state == State::TryOpen);
+            assert_eq!(end.is_open(), state == State::Open);
+        }
+    }
+
+    #[test]
+    fn state_as_str() {
+        let tests = [
+            (State::Uninitialized, "UNINITIALIZED"),
+            (State::Init, "INIT"),
+            (State::TryOpen, "TRYOPEN"),
+            (State::Open, "OPEN"),
+        ];
+
+        for (state, expected) in tests {
+            assert_eq!(state.as_str(), expected);
+            assert_eq!(state.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn state_from_str() {
+        let states = [
+            State::Uninitialized,
+            State::Init,
+            State::TryOpen,
+            State::Open,
+        ];
+        for state in states {
+            assert_eq!(state.to_string().parse::<State>().unwrap(), state);
+            assert_eq!(state.is_open(), state == State::Open);
+            assert_eq!(state.is_init(), state == State::Init);
+        }
+
+        let tests = [
+            ("STATE_UNINITIALIZED_UNSPECIFIED", State::Uninitialized),
+            ("STATE_INIT", State::Init),
+            ("STATE_TRYOPEN", State::TryOpen),
+            ("STATE_OPEN", State::Open),
+            ("open", State::Open),
+        ];
+        for (s, state) in tests {
+            assert_eq!(s.parse::<State>().unwrap(), state, "{s}");
+        }
+
+        for s in ["", "CLOSED", "OPENED", "STATE_", "3", "state_state_open"] {
+            assert!(
+                matches!(s.parse::<State>(), Err(ConnectionError::ParseState { state }) if state == s),
+                "{s}"
+            );
+        }
+    }
+
+    #[test]
+    fn connection_end_encode_for_proof() {
+        let end = ConnectionEnd::new_open(
+            ClientId::default(),
+            Counterparty {
+                client_id: "07-tendermint-12".parse().unwrap(),
+                connection_id: Some(ConnectionId::new(7)),
+                prefix: b"ibc".to_vec().into(),
+            },
+            vec![Version::default()],
+            Duration::from_secs(300),
+        );
+
+        let expected = subtle_encoding::hex::decode(
+            "0a0f30372d74656e6465726d696e742d3012230a0131120d4f524445525f4f52444552\
+             4544120f4f524445525f554e4f524445524544180322270a1030372d74656e6465726d\
+             696e742d3132120c636f6e6e656374696f6e2d371a050a036962632880f092cbdd08",
+        )
+        .unwrap();
+        assert_eq!(end.encode_for_proof(), expected);
+        assert_eq!(end.encode_for_proof(), end.encode_vec());
+    }
+
+    #[test]
+    fn connection_end_new_open() {
+        let counterparty = Counterparty {
+            client_id: "07-tendermint-12".parse().unwrap(),
+            connection_id: Some(ConnectionId::new(7)),
+            prefix: b"ibc".to_vec().into(),
+        };
+        let end = ConnectionEnd::new_open(
+            ClientId::default(),
+            counterparty.clone(),
+            vec![Version::default()],
+            Duration::from_secs(300),
+        );
+
+        assert_eq!(end.state, State::Open);
+        assert!(end.is_open());
+        assert!(end.client_id_matches(&ClientId::default()));
+        assert!(end.counterparty_matches(&counterparty));
+        assert_eq!(end.versions, vec![Version::default()]);
+        assert_eq!(end.delay_period, Duration::from_secs(300));
     }
 }
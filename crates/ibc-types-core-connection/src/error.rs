@@ -9,8 +9,10 @@ use displaydoc::Display;
 pub enum ConnectionError {
     /// client error: `{0}`
     Client(ClientError),
-    /// connection state is unknown: `{state}`
-    InvalidState { state: i32 },
+    /// connection state unknown: `{state}`
+    UnknownState { state: i32 },
+    /// failed to parse connection state from `{state}`
+    ParseState { state: String },
     /// connection end for identifier `{connection_id}` was never initialized
     ConnectionMismatch { connection_id: ConnectionId },
     /// consensus height claimed by the client on the other party is too advanced: `{target_height}` (host chain current height: `{current_height}`)
@@ -65,9 +67,8 @@ pub enum ConnectionError {
     Other { description: String },
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for ConnectionError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for ConnectionError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match &self {
             Self::Client(e) => Some(e),
             Self::InvalidIdentifier(e) => Some(e),
@@ -46,9 +46,8 @@ pub enum Error {
     },
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for Error {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         // Note: fill in if errors have causes
         match &self {
             Self::ParseConnectionId { e, .. } => Some(e),
@@ -67,7 +66,10 @@ struct Attributes {
     counterparty_client_id: ClientId,
 }
 
-/// Convert attributes to Tendermint ABCI tags
+/// Convert attributes to Tendermint ABCI tags.
+///
+/// The attributes are emitted in the same order as ibc-go: `connection_id`,
+/// `client_id`, `counterparty_client_id`, `counterparty_connection_id`.
 impl From<Attributes> for Vec<abci::EventAttribute> {
     fn from(a: Attributes) -> Self {
         let conn_id = ("connection_id", a.connection_id.as_str()).into();
@@ -167,6 +169,7 @@ impl TryFrom<Vec<abci::EventAttribute>> for Attributes {
 }
 
 /// Per our convention, this event is generated on chain A.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ConnectionOpenInit {
     pub connection_id: ConnectionId,
     pub client_id_on_a: ClientId,
@@ -214,6 +217,7 @@ impl TryFrom<Event> for ConnectionOpenInit {
 }
 
 /// Per our convention, this event is generated on chain B.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ConnectionOpenTry {
     pub conn_id_on_b: ConnectionId,
     pub client_id_on_b: ClientId,
@@ -265,6 +269,7 @@ impl TryFrom<Event> for ConnectionOpenTry {
 }
 
 /// Per our convention, this event is generated on chain A.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ConnectionOpenAck {
     pub conn_id_on_a: ConnectionId,
     pub client_id_on_a: ClientId,
@@ -316,6 +321,7 @@ impl TryFrom<Event> for ConnectionOpenAck {
 }
 
 /// Per our convention, this event is generated on chain B.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ConnectionOpenConfirm {
     pub conn_id_on_b: ConnectionId,
     pub client_id_on_b: ClientId,
@@ -366,28 +372,24 @@ impl TryFrom<Event> for ConnectionOpenConfirm {
     }
 }
 
-/*
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::ics02_client::client_type::ClientType;
-    use tendermint::abci::Event as AbciEvent;
+    use test_log::test;
 
     #[test]
     fn ibc_to_abci_connection_events() {
         struct Test {
-            kind: IbcEventType,
-            event: AbciEvent,
-            expected_keys: Vec<&'static str>,
+            kind: &'static str,
+            event: Event,
             expected_values: Vec<&'static str>,
         }
 
-        let client_type = ClientType::new("07-tendermint".to_string());
-        let conn_id_on_a = ConnectionId::default();
-        let client_id_on_a = ClientId::new(client_type.clone(), 0).unwrap();
+        let conn_id_on_a = ConnectionId::new(0);
+        let client_id_on_a: ClientId = "07-tendermint-0".parse().unwrap();
         let conn_id_on_b = ConnectionId::new(1);
-        let client_id_on_b = ClientId::new(client_type, 1).unwrap();
-        let expected_keys = vec![
+        let client_id_on_b: ClientId = "07-tendermint-1".parse().unwrap();
+        let expected_keys = [
             "connection_id",
             "client_id",
             "counterparty_client_id",
@@ -402,14 +404,13 @@ mod tests {
 
         let tests: Vec<Test> = vec![
             Test {
-                kind: IbcEventType::OpenInitConnection,
-                event: OpenInit::new(
-                    conn_id_on_a.clone(),
-                    client_id_on_a.clone(),
-                    client_id_on_b.clone(),
-                )
+                kind: ConnectionOpenInit::TYPE_STR,
+                event: ConnectionOpenInit {
+                    connection_id: conn_id_on_a.clone(),
+                    client_id_on_a: client_id_on_a.clone(),
+                    client_id_on_b: client_id_on_b.clone(),
+                }
                 .into(),
-                expected_keys: expected_keys.clone(),
                 expected_values: expected_values
                     .iter()
                     .enumerate()
@@ -417,58 +418,109 @@ mod tests {
                     .collect(),
             },
             Test {
-                kind: IbcEventType::OpenTryConnection,
-                event: OpenTry::new(
-                    conn_id_on_b.clone(),
-                    client_id_on_b.clone(),
-                    conn_id_on_a.clone(),
-                    client_id_on_a.clone(),
-                )
+                kind: ConnectionOpenTry::TYPE_STR,
+                event: ConnectionOpenTry {
+                    conn_id_on_b: conn_id_on_b.clone(),
+                    client_id_on_b: client_id_on_b.clone(),
+                    conn_id_on_a: conn_id_on_a.clone(),
+                    client_id_on_a: client_id_on_a.clone(),
+                }
                 .into(),
-                expected_keys: expected_keys.clone(),
                 expected_values: expected_values.iter().rev().cloned().collect(),
             },
             Test {
-                kind: IbcEventType::OpenAckConnection,
-                event: OpenAck::new(
-                    conn_id_on_a.clone(),
-                    client_id_on_a.clone(),
-                    conn_id_on_b.clone(),
-                    client_id_on_b.clone(),
-                )
+                kind: ConnectionOpenAck::TYPE_STR,
+                event: ConnectionOpenAck {
+                    conn_id_on_a: conn_id_on_a.clone(),
+                    client_id_on_a: client_id_on_a.clone(),
+                    conn_id_on_b: conn_id_on_b.clone(),
+                    client_id_on_b: client_id_on_b.clone(),
+                }
                 .into(),
-                expected_keys: expected_keys.clone(),
                 expected_values: expected_values.clone(),
             },
             Test {
-                kind: IbcEventType::OpenConfirmConnection,
-                event: OpenConfirm::new(conn_id_on_b, client_id_on_b, conn_id_on_a, client_id_on_a)
-                    .into(),
-                expected_keys: expected_keys.clone(),
+                kind: ConnectionOpenConfirm::TYPE_STR,
+                event: ConnectionOpenConfirm {
+                    conn_id_on_b,
+                    client_id_on_b,
+                    conn_id_on_a,
+                    client_id_on_a,
+                }
+                .into(),
                 expected_values: expected_values.iter().rev().cloned().collect(),
             },
         ];
 
         for t in tests {
-            assert_eq!(t.kind.as_str(), t.event.kind);
-            assert_eq!(t.expected_keys.len(), t.event.attributes.len());
+            assert_eq!(t.kind, t.event.kind);
+            assert_eq!(expected_keys.len(), t.event.attributes.len());
             for (i, e) in t.event.attributes.iter().enumerate() {
                 assert_eq!(
-                    e.key,
-                    t.expected_keys[i],
+                    e.key_bytes(),
+                    expected_keys[i].as_bytes(),
                     "key mismatch for {:?}",
-                    t.kind.as_str()
+                    t.kind
                 );
-            }
-            for (i, e) in t.event.attributes.iter().enumerate() {
                 assert_eq!(
-                    e.value,
-                    t.expected_values[i],
+                    e.value_bytes(),
+                    t.expected_values[i].as_bytes(),
                     "value mismatch for {:?}",
-                    t.kind.as_str()
+                    t.kind
                 );
             }
         }
     }
+
+    #[test]
+    fn connection_events_abci_round_trip() {
+        let init = ConnectionOpenInit {
+            connection_id: ConnectionId::new(0),
+            client_id_on_a: "07-tendermint-0".parse().unwrap(),
+            client_id_on_b: "07-tendermint-1".parse().unwrap(),
+        };
+        assert_eq!(
+            ConnectionOpenInit::try_from(Event::from(init.clone())).unwrap(),
+            init
+        );
+
+        let try_ = ConnectionOpenTry {
+            conn_id_on_b: ConnectionId::new(1),
+            client_id_on_b: "07-tendermint-1".parse().unwrap(),
+            conn_id_on_a: ConnectionId::new(0),
+            client_id_on_a: "07-tendermint-0".parse().unwrap(),
+        };
+        assert_eq!(
+            ConnectionOpenTry::try_from(Event::from(try_.clone())).unwrap(),
+            try_
+        );
+
+        let ack = ConnectionOpenAck {
+            conn_id_on_a: ConnectionId::new(0),
+            client_id_on_a: "07-tendermint-0".parse().unwrap(),
+            conn_id_on_b: ConnectionId::new(1),
+            client_id_on_b: "07-tendermint-1".parse().unwrap(),
+        };
+        assert_eq!(
+            ConnectionOpenAck::try_from(Event::from(ack.clone())).unwrap(),
+            ack
+        );
+
+        let confirm = ConnectionOpenConfirm {
+            conn_id_on_b: ConnectionId::new(1),
+            client_id_on_b: "07-tendermint-1".parse().unwrap(),
+            conn_id_on_a: ConnectionId::new(0),
+            client_id_on_a: "07-tendermint-0".parse().unwrap(),
+        };
+        assert_eq!(
+            ConnectionOpenConfirm::try_from(Event::from(confirm.clone())).unwrap(),
+            confirm
+        );
+
+        // events of one kind are not parsed as another
+        assert!(matches!(
+            ConnectionOpenAck::try_from(Event::from(confirm)),
+            Err(Error::WrongType { .. })
+        ));
+    }
 }
-*/
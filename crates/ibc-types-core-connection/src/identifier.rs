@@ -4,6 +4,8 @@ use core::{
     str::FromStr,
 };
 
+use alloc::sync::Arc;
+
 use ibc_types_identifier::{validate_connection_identifier, IdentifierError};
 
 use crate::prelude::*;
@@ -17,6 +19,20 @@ use crate::prelude::*;
 ///       See: <https://github.com/informalsystems/ibc-rs/pull/304#discussion_r503917283>.
 ///
 /// Also, contrast with tendermint-rs `ChainId` type.
+///
+/// # Revision zero
+///
+/// Following ibc-go's `IsRevisionFormat`, only ids ending in `-{n}` with
+/// `n >= 1` are in epoch format (see [`ChainId::is_epoch_format`]). An id such
+/// as `chainA-0` is therefore *not* in epoch format, but its version is `0`
+/// either way, so a `ChainId` built with `ChainId::new(name, 0)` round-trips
+/// through its string form:
+///
+/// ```
+/// # use ibc_types_core_connection::ChainId;
+/// let id = ChainId::new("chainA".to_string(), 0);
+/// assert_eq!(ChainId::from_string(id.as_str()), id);
+/// ```
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChainId {
@@ -41,6 +57,8 @@ impl ChainId {
         }
     }
 
+    /// Parses a `ChainId` from its string form, extracting the version if
+    /// the id is in epoch format and defaulting it to `0` otherwise.
     pub fn from_string(id: &str) -> Self {
         let version = if Self::is_epoch_format(id) {
             Self::chain_version(id)
@@ -66,12 +84,16 @@ impl ChainId {
     }
 
     /// Extract the version from the given chain identifier.
+    ///
+    /// Ids that are not in epoch format, including revision-zero ids like
+    /// `chainA-0`, have version `0`.
     /// ```
     /// # use ibc_types_core_connection::ChainId;
     /// assert_eq!(ChainId::chain_version("chain--a-0"), 0);
     /// assert_eq!(ChainId::chain_version("ibc-10"), 10);
     /// assert_eq!(ChainId::chain_version("cosmos-hub-97"), 97);
     /// assert_eq!(ChainId::chain_version("testnet-helloworld-2"), 2);
+    /// assert_eq!(ChainId::chain_version("chainA-0"), 0);
     /// ```
     pub fn chain_version(chain_id: &str) -> u64 {
         if !ChainId::is_epoch_format(chain_id) {
@@ -96,8 +118,22 @@ impl ChainId {
     /// assert_eq!(ChainId::is_epoch_format("c-1"), true);
     /// ```
     pub fn is_epoch_format(chain_id: &str) -> bool {
-        let re = safe_regex::regex!(br".*[^-]-[1-9][0-9]*");
-        re.is_match(chain_id.as_bytes())
+        // Equivalent to matching the whole input against `.*[^-]-[1-9][0-9]*`:
+        // the version is everything after the last `-`, which must be a
+        // decimal number without leading zeros, preceded by a non-empty name
+        // that does not itself end in `-`.
+        let bytes = chain_id.as_bytes();
+        let sep = match bytes.iter().rposition(|&b| b == b'-') {
+            Some(sep) => sep,
+            None => return false,
+        };
+        let (name, version) = (&bytes[..sep], &bytes[sep + 1..]);
+
+        let name_ok = matches!(name.last(), Some(&b) if b != b'-');
+        let version_ok =
+            matches!(version.first(), Some(b'1'..=b'9')) && version.iter().all(u8::is_ascii_digit);
+
+        name_ok && version_ok
     }
 
     /// with_version() checks if a chain_id is in the format required for parsing epochs, and if so
@@ -159,9 +195,13 @@ impl From<String> for ChainId {
     }
 }
 
+/// An IBC connection identifier.
+///
+/// The identifier is stored in an [`Arc<str>`], so clones share the same
+/// string rather than copying it.
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct ConnectionId(pub String);
+pub struct ConnectionId(pub Arc<str>);
 
 impl ConnectionId {
     /// Builds a new connection identifier. Connection identifiers are deterministically formed from
@@ -179,11 +219,43 @@ impl ConnectionId {
         Self::from_str(id.as_str()).unwrap()
     }
 
+    /// Builds a connection identifier from a custom `prefix` and a `counter`,
+    /// for chains that do not use the default `connection` prefix.
+    ///
+    /// ```
+    /// # use ibc_types_core_connection::ConnectionId;
+    /// let conn_id = ConnectionId::with_prefix("conn-penumbra", 3).unwrap();
+    /// assert_eq!(&conn_id, "conn-penumbra-3");
+    /// ```
+    pub fn with_prefix(prefix: &str, counter: u64) -> Result<Self, IdentifierError> {
+        let id = format!("{prefix}-{counter}");
+        Self::from_str(id.as_str())
+    }
+
     /// Returns the static prefix to be used across all connection identifiers.
     pub fn prefix() -> &'static str {
         "connection"
     }
 
+    /// Returns the counter of this connection identifier, i.e. the numeric
+    /// suffix following the `connection-` prefix, or `None` if the identifier
+    /// is not of the form `connection-{n}`.
+    ///
+    /// ```
+    /// # use ibc_types_core_connection::ConnectionId;
+    /// assert_eq!(ConnectionId::new(11).sequence(), Some(11));
+    /// ```
+    pub fn sequence(&self) -> Option<u64> {
+        let suffix = self
+            .0
+            .strip_prefix(Self::prefix())
+            .and_then(|s| s.strip_prefix('-'))?;
+        if suffix.is_empty() || !suffix.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        suffix.parse().ok()
+    }
+
     /// Get this identifier as a borrowed `&str`
     pub fn as_str(&self) -> &str {
         &self.0
@@ -206,7 +278,13 @@ impl FromStr for ConnectionId {
     type Err = IdentifierError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        validate_connection_identifier(s).map(|_| Self(s.to_string()))
+        validate_connection_identifier(s).map(|_| Self(s.into()))
+    }
+}
+
+impl From<ConnectionId> for String {
+    fn from(value: ConnectionId) -> Self {
+        value.as_str().into()
     }
 }
 
@@ -229,3 +307,209 @@ impl PartialEq<str> for ConnectionId {
         self.as_str().eq(other)
     }
 }
+
+impl PartialEq<&str> for ConnectionId {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str().eq(*other)
+    }
+}
+
+impl PartialEq<ConnectionId> for str {
+    fn eq(&self, other: &ConnectionId) -> bool {
+        other.as_str().eq(self)
+    }
+}
+
+impl PartialEq<ConnectionId> for &str {
+    fn eq(&self, other: &ConnectionId) -> bool {
+        other.as_str().eq(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn connection_id_clones_share_storage() {
+        let connection_id = ConnectionId::new(7);
+        let clone = connection_id.clone();
+
+        assert!(Arc::ptr_eq(&connection_id.0, &clone.0));
+        assert_eq!(clone.as_str(), "connection-7");
+        assert_eq!(String::from(clone), "connection-7");
+    }
+
+    #[test]
+    fn is_epoch_format() {
+        struct Test {
+            chain_id: &'static str,
+            want: bool,
+        }
+
+        let tests = vec![
+            // cases from the doc-test
+            Test {
+                chain_id: "chainA-0",
+                want: false,
+            },
+            Test {
+                chain_id: "chainA",
+                want: false,
+            },
+            Test {
+                chain_id: "chainA-1",
+                want: true,
+            },
+            Test {
+                chain_id: "c-1",
+                want: true,
+            },
+            // multi-digit versions, with and without inner zeros
+            Test {
+                chain_id: "cosmoshub-4",
+                want: true,
+            },
+            Test {
+                chain_id: "testnet-100",
+                want: true,
+            },
+            Test {
+                chain_id: "chain-a-10",
+                want: true,
+            },
+            // leading zero in the version
+            Test {
+                chain_id: "chainA-01",
+                want: false,
+            },
+            // name must be non-empty and must not end in `-`
+            Test {
+                chain_id: "-1",
+                want: false,
+            },
+            Test {
+                chain_id: "chainA--1",
+                want: false,
+            },
+            Test {
+                chain_id: "--1",
+                want: false,
+            },
+            // version must be present and purely numeric
+            Test {
+                chain_id: "chainA-",
+                want: false,
+            },
+            Test {
+                chain_id: "chainA-1a",
+                want: false,
+            },
+            Test {
+                chain_id: "chainA-1-",
+                want: false,
+            },
+            Test {
+                chain_id: "chainA-+1",
+                want: false,
+            },
+            Test {
+                chain_id: "",
+                want: false,
+            },
+            // only the last separator matters
+            Test {
+                chain_id: "chain-0-1",
+                want: true,
+            },
+            Test {
+                chain_id: "chain-1-a",
+                want: false,
+            },
+            // non-ASCII names are fine
+            Test {
+                chain_id: "chäin-7",
+                want: true,
+            },
+        ];
+
+        for test in tests {
+            assert_eq!(
+                ChainId::is_epoch_format(test.chain_id),
+                test.want,
+                "is_epoch_format({:?})",
+                test.chain_id
+            );
+        }
+    }
+
+    #[test]
+    fn connection_id_eq_str() {
+        let conn_id = ConnectionId::new(0);
+
+        assert!(conn_id == *"connection-0");
+        assert!(*"connection-0" == conn_id);
+        assert!(conn_id == "connection-0");
+        assert!("connection-0" == conn_id);
+        assert!(conn_id != "connection-1");
+        assert!("connection-1" != conn_id);
+    }
+
+    #[test]
+    fn connection_id_sequence() {
+        assert_eq!(ConnectionId::new(0).sequence(), Some(0));
+        assert_eq!(ConnectionId::new(11).sequence(), Some(11));
+
+        for malformed in [
+            "conn007",
+            "connection-",
+            "connection7",
+            "connection-+7",
+            "connectionId-0",
+        ] {
+            assert_eq!(
+                ConnectionId(malformed.into()).sequence(),
+                None,
+                "{malformed}"
+            );
+        }
+    }
+
+    #[test]
+    fn connection_id_with_prefix() {
+        assert_eq!(
+            ConnectionId::with_prefix("connection", 7).unwrap(),
+            ConnectionId::new(7)
+        );
+        assert_eq!(
+            ConnectionId::with_prefix("conn-penumbra", 3).unwrap(),
+            "conn-penumbra-3"
+        );
+
+        assert!(matches!(
+            ConnectionId::with_prefix("conn/penumbra", 3),
+            Err(IdentifierError::ContainSeparator { .. })
+        ));
+        assert!(matches!(
+            ConnectionId::with_prefix("conn", 3),
+            Err(IdentifierError::InvalidLength { .. })
+        ));
+    }
+
+    #[test]
+    fn chain_id_round_trips_through_string() {
+        for version in [0, 1, 10] {
+            let id = ChainId::new("chainA".to_string(), version);
+            assert_eq!(id.version(), version);
+            assert_eq!(ChainId::chain_version(id.as_str()), version);
+
+            let parsed = ChainId::from_string(id.as_str());
+            assert_eq!(parsed, id, "version {version}");
+            assert_eq!(parsed.version(), version);
+
+            let parsed: ChainId = id.to_string().parse().unwrap();
+            assert_eq!(parsed, id, "version {version}");
+        }
+    }
+}
@@ -1,6 +1,6 @@
 //! IBC connection-related types.
 #![no_std]
-// Requires nightly.
+// Only enabled when building docs (e.g. on docs.rs), which requires nightly.
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 
 extern crate alloc;
@@ -13,7 +13,9 @@ mod identifier;
 mod prelude;
 mod version;
 
-pub use connection::{ClientPaths, ConnectionEnd, Counterparty, IdentifiedConnectionEnd, State};
+pub use connection::{
+    ClientPaths, ConnectionEnd, ConnectionEndBuilder, Counterparty, IdentifiedConnectionEnd, State,
+};
 pub use error::ConnectionError;
 pub use identifier::{ChainId, ConnectionId};
 pub use version::Version;
@@ -12,6 +12,9 @@
 //! Another difference to ICS3 specs is that each message comprises an additional field called
 //! `signer` which is specific to Cosmos-SDK.
 
+use ibc_proto::google::protobuf::Any;
+use ibc_types_domain_type::{decode_as, to_any};
+
 mod conn_open_ack;
 mod conn_open_confirm;
 mod conn_open_init;
@@ -31,6 +34,41 @@ pub enum ConnectionMsg {
     OpenConfirm(MsgConnectionOpenConfirm),
 }
 
+/// Decodes a connection message from an [`Any`] by dispatching on its type URL.
+impl TryFrom<Any> for ConnectionMsg {
+    type Error = anyhow::Error;
+
+    fn try_from(any: Any) -> Result<Self, Self::Error> {
+        if let Some(msg) = decode_as(&any) {
+            return Ok(Self::OpenInit(msg?));
+        }
+        if let Some(msg) = decode_as(&any) {
+            return Ok(Self::OpenTry(msg?));
+        }
+        if let Some(msg) = decode_as(&any) {
+            return Ok(Self::OpenAck(msg?));
+        }
+        if let Some(msg) = decode_as(&any) {
+            return Ok(Self::OpenConfirm(msg?));
+        }
+        Err(anyhow::anyhow!(
+            "unknown connection message type URL: {}",
+            any.type_url
+        ))
+    }
+}
+
+impl From<ConnectionMsg> for Any {
+    fn from(msg: ConnectionMsg) -> Self {
+        match msg {
+            ConnectionMsg::OpenInit(msg) => to_any(msg),
+            ConnectionMsg::OpenTry(msg) => to_any(msg),
+            ConnectionMsg::OpenAck(msg) => to_any(msg),
+            ConnectionMsg::OpenConfirm(msg) => to_any(msg),
+        }
+    }
+}
+
 #[cfg(test)]
 pub mod test_util {
     use crate::{prelude::*, ConnectionId};
@@ -8,7 +8,7 @@ use ibc_types_core_client::Height;
 use ibc_types_core_commitment::MerkleProof;
 use ibc_types_domain_type::DomainType;
 
-use crate::{ConnectionError, ConnectionId, Version};
+use crate::{ConnectionEnd, ConnectionError, ConnectionId, Version};
 
 /// Per our convention, this message is sent to chain A.
 /// The handler will check proofs of chain B.
@@ -36,6 +36,20 @@ pub struct MsgConnectionOpenAck {
     pub signer: String,
 }
 
+impl MsgConnectionOpenAck {
+    /// Returns the optional proof of the consensus state of the host chain.
+    pub fn host_consensus_state_proof(&self) -> Option<&MerkleProof> {
+        self.host_consensus_state_proof.as_ref()
+    }
+
+    /// Checks that the single version chosen by chain B is compatible with
+    /// one of the versions chain A offered in `conn_end_on_a`, by the rules
+    /// of [`Version::verify_is_supported`].
+    pub fn verify_version(&self, conn_end_on_a: &ConnectionEnd) -> Result<(), ConnectionError> {
+        self.version.verify_is_supported(&conn_end_on_a.versions)
+    }
+}
+
 impl DomainType for MsgConnectionOpenAck {
     type Proto = RawMsgConnectionOpenAck;
 }
@@ -143,6 +157,25 @@ pub mod test_util {
     pub fn get_dummy_raw_msg_conn_open_ack(
         proof_height: u64,
         consensus_height: u64,
+    ) -> RawMsgConnectionOpenAck {
+        get_dummy_raw_msg_conn_open_ack_with(
+            proof_height,
+            consensus_height,
+            Version::default(),
+            get_dummy_bech32_account(),
+            false,
+        )
+    }
+
+    /// Like [`get_dummy_raw_msg_conn_open_ack`], but with the given `version`
+    /// and `signer`, and with a dummy `host_consensus_state_proof` if
+    /// `with_host_consensus_state_proof` is set.
+    pub fn get_dummy_raw_msg_conn_open_ack_with(
+        proof_height: u64,
+        consensus_height: u64,
+        version: Version,
+        signer: String,
+        with_host_consensus_state_proof: bool,
     ) -> RawMsgConnectionOpenAck {
         let client_state_height = Height::new(0, consensus_height).unwrap();
         RawMsgConnectionOpenAck {
@@ -160,9 +193,13 @@ pub mod test_util {
             }),
             client_state: Some(MockClientState::new(MockHeader::new(client_state_height)).into()),
             proof_client: get_dummy_proof(),
-            version: Some(Version::default().into()),
-            signer: get_dummy_bech32_account(),
-            host_consensus_state_proof: vec![],
+            version: Some(version.into()),
+            signer,
+            host_consensus_state_proof: if with_host_consensus_state_proof {
+                get_dummy_proof()
+            } else {
+                vec![]
+            },
         }
     }
 }
@@ -174,7 +211,8 @@ mod tests {
     use test_log::test;
 
     use ibc_proto::ibc::core::{
-        client::v1::Height, connection::v1::MsgConnectionOpenAck as RawMsgConnectionOpenAck,
+        client::v1::Height,
+        connection::v1::{MsgConnectionOpenAck as RawMsgConnectionOpenAck, Version as RawVersion},
     };
 
     use super::*;
@@ -212,6 +250,28 @@ mod tests {
                 },
                 want_pass: false,
             },
+            Test {
+                name: "Bad version, empty identifier".to_string(),
+                raw: RawMsgConnectionOpenAck {
+                    version: Some(RawVersion {
+                        identifier: "".to_string(),
+                        features: vec!["ORDER_ORDERED".to_string()],
+                    }),
+                    ..default_ack_msg.clone()
+                },
+                want_pass: false,
+            },
+            Test {
+                name: "Bad version, empty feature".to_string(),
+                raw: RawMsgConnectionOpenAck {
+                    version: Some(RawVersion {
+                        identifier: "1".to_string(),
+                        features: vec!["".to_string()],
+                    }),
+                    ..default_ack_msg.clone()
+                },
+                want_pass: false,
+            },
             Test {
                 name: "Bad proof height, height is 0".to_string(),
                 raw: RawMsgConnectionOpenAck {
@@ -261,4 +321,98 @@ mod tests {
         assert_eq!(raw, raw_back);
         assert_eq!(msg, msg_back);
     }
+
+    #[test]
+    fn dummy_with_version_signer_and_host_proof() {
+        let version = Version {
+            identifier: "1".to_string(),
+            features: vec!["ORDER_UNORDERED".to_string()],
+        };
+        let signer = "osmo1qnk2n4nlkpw9xfqntladh74w6ujtulwnmxnh3k".to_string();
+
+        let raw = test_util::get_dummy_raw_msg_conn_open_ack_with(
+            5,
+            5,
+            version.clone(),
+            signer.clone(),
+            true,
+        );
+        let msg = MsgConnectionOpenAck::try_from(raw.clone()).unwrap();
+        assert_eq!(msg.version, version);
+        assert_eq!(msg.signer, signer);
+        assert!(msg.host_consensus_state_proof().is_some());
+        assert_eq!(RawMsgConnectionOpenAck::from(msg), raw);
+
+        let msg = MsgConnectionOpenAck::new_dummy(5, 5);
+        assert_eq!(msg.version, Version::default());
+        assert!(msg.host_consensus_state_proof().is_none());
+    }
+
+    #[test]
+    fn empty_version_identifier() {
+        let raw = RawMsgConnectionOpenAck {
+            version: Some(RawVersion {
+                identifier: " ".to_string(),
+                features: vec![],
+            }),
+            ..test_util::get_dummy_raw_msg_conn_open_ack(5, 5)
+        };
+
+        assert!(matches!(
+            MsgConnectionOpenAck::try_from(raw),
+            Err(ConnectionError::EmptyVersions)
+        ));
+    }
+
+    #[test]
+    fn verify_version() {
+        let msg = MsgConnectionOpenAck::new_dummy(5, 5);
+        let conn_end_on_a = |versions: Vec<Version>| ConnectionEnd {
+            versions,
+            ..Default::default()
+        };
+
+        // the default version is offered
+        assert!(msg
+            .verify_version(&conn_end_on_a(Version::compatible_versions()))
+            .is_ok());
+
+        // a subset of the offered features is acceptable
+        let msg_ordered = MsgConnectionOpenAck {
+            version: Version {
+                identifier: "1".to_string(),
+                features: vec!["ORDER_ORDERED".to_string()],
+            },
+            ..msg.clone()
+        };
+        assert!(msg_ordered
+            .verify_version(&conn_end_on_a(Version::compatible_versions()))
+            .is_ok());
+
+        // a feature that was not offered is rejected
+        let only_unordered = Version {
+            identifier: "1".to_string(),
+            features: vec!["ORDER_UNORDERED".to_string()],
+        };
+        assert!(matches!(
+            msg_ordered.verify_version(&conn_end_on_a(vec![only_unordered])),
+            Err(ConnectionError::VersionNotSupported { .. })
+        ));
+
+        // a different identifier is rejected
+        let other_identifier = Version {
+            identifier: "2".to_string(),
+            ..Version::default()
+        };
+        assert!(matches!(
+            msg.verify_version(&conn_end_on_a(vec![other_identifier])),
+            Err(ConnectionError::VersionNotSupported { .. })
+        ));
+
+        // nothing is supported when no versions were offered
+        assert!(matches!(
+            msg.verify_version(&conn_end_on_a(vec![])),
+            Err(ConnectionError::VersionNotSupported { .. })
+        ));
+    }
 }
@@ -2,7 +2,9 @@ use core::fmt::Display;
 
 use crate::prelude::*;
 
+use bytes::BufMut;
 use ibc_proto::{ibc::core::connection::v1::Version as RawVersion, Protobuf};
+use ibc_types_domain_type::encoding;
 
 // TODO; move this to channel crate
 //use crate::core::ics04_channel::channel::Order;
@@ -27,6 +29,18 @@ impl Version {
     pub fn is_supported_feature(&self, feature: String) -> bool {
         self.features.contains(&feature)
     }
+
+    /// Length of this version's [`RawVersion`] encoding.
+    pub(crate) fn raw_encoded_len(&self) -> usize {
+        encoding::bytes_len(1, self.identifier.as_bytes())
+            + encoding::repeated_bytes_len(2, self.features.iter().map(|f| f.as_bytes()))
+    }
+
+    /// Encodes this version as a [`RawVersion`], without cloning it first.
+    pub(crate) fn encode_raw(&self, buf: &mut impl BufMut) {
+        encoding::encode_bytes(1, self.identifier.as_bytes(), buf);
+        encoding::encode_repeated_bytes(2, self.features.iter().map(|f| f.as_bytes()), buf);
+    }
 }
 
 impl Protobuf<RawVersion> for Version {}
@@ -84,6 +98,13 @@ impl Version {
     }
 
     /// Selects a version from the intersection of locally supported and counterparty versions.
+    ///
+    /// Of the supported versions whose identifier the counterparty also
+    /// offers, this returns the one with the lowest identifier, with its
+    /// features unchanged. Unlike [`Version::pick_version`], it neither
+    /// follows the local order of preference nor intersects the feature sets,
+    /// so new code negotiating a version as ibc-go does should use
+    /// [`Version::pick_version`] instead.
     pub fn select(
         supported_versions: &[Version],
         counterparty_versions: &[Version],
@@ -108,6 +129,61 @@ impl Version {
         }
         Ok(intersection[0].clone())
     }
+
+    /// Picks a version to propose, following the ICS-03 negotiation
+    /// algorithm as implemented by ibc-go.
+    ///
+    /// Locally supported versions are tried in order of preference. The first
+    /// one whose identifier the counterparty also supports, and whose feature
+    /// set has a non-empty intersection with the counterparty's, is picked,
+    /// restricted to that intersection (in local order).
+    ///
+    /// See [`Version::select`] for the older selection rule this replaces.
+    pub fn pick_version(
+        supported: &[Version],
+        counterparty: &[Version],
+    ) -> Result<Version, ConnectionError> {
+        for s in supported {
+            let c = match counterparty.iter().find(|c| c.identifier == s.identifier) {
+                Some(c) => c,
+                None => continue,
+            };
+            let features: Vec<String> = s
+                .features
+                .iter()
+                .filter(|f| c.features.contains(f))
+                .cloned()
+                .collect();
+            if features.is_empty() {
+                continue;
+            }
+            return Ok(Version {
+                identifier: s.identifier.clone(),
+                features,
+            });
+        }
+        Err(ConnectionError::NoCommonVersion)
+    }
+
+    /// Verifies that this proposed version is supported: one of the
+    /// `supported` versions has the same identifier, and supports every one
+    /// of its features. As in ibc-go, an empty feature set is never
+    /// supported.
+    pub fn verify_is_supported(&self, supported: &[Version]) -> Result<(), ConnectionError> {
+        let is_supported = match supported.iter().find(|s| s.identifier == self.identifier) {
+            Some(s) => {
+                !self.features.is_empty() && self.features.iter().all(|f| s.features.contains(f))
+            }
+            None => false,
+        };
+        if is_supported {
+            Ok(())
+        } else {
+            Err(ConnectionError::VersionNotSupported {
+                version: self.clone(),
+            })
+        }
+    }
 }
 
 #[cfg(test)]
@@ -121,6 +197,26 @@ mod tests {
     use super::*;
     use crate::ConnectionError;
 
+    #[test]
+    fn default_version_matches_cosmos_sdk() {
+        // ibc-go's `DefaultIBCVersion`
+        let default = Version::default();
+        assert_eq!(default.identifier, "1");
+        assert_eq!(default.features, vec!["ORDER_ORDERED", "ORDER_UNORDERED"]);
+        assert!(default.is_supported_feature("ORDER_ORDERED".to_string()));
+        assert!(default.is_supported_feature("ORDER_UNORDERED".to_string()));
+        assert!(!default.is_supported_feature("ORDER_ORDERED_ALLOW_TIMEOUT".to_string()));
+
+        assert_eq!(Version::compatible_versions(), vec![default.clone()]);
+        assert_eq!(
+            RawVersion::from(default),
+            RawVersion {
+                identifier: "1".to_string(),
+                features: vec!["ORDER_ORDERED".to_string(), "ORDER_UNORDERED".to_string()],
+            }
+        );
+    }
+
     fn good_versions() -> Vec<RawVersion> {
         vec![
             Version::default().into(),
@@ -306,6 +402,148 @@ mod tests {
             }
         }
     }
+
+    fn version(identifier: &str, features: &[&str]) -> Version {
+        Version {
+            identifier: identifier.to_string(),
+            features: features.iter().map(|f| f.to_string()).collect(),
+        }
+    }
+
+    /// (name, supported versions, counterparty versions, expected pick)
+    type PickVersionTest = (&'static str, Vec<Version>, Vec<Version>, Option<Version>);
+
+    #[test]
+    fn pick_version() {
+        let tests: Vec<PickVersionTest> = vec![
+            (
+                "valid default ibc version",
+                Version::compatible_versions(),
+                Version::compatible_versions(),
+                Some(Version::default()),
+            ),
+            (
+                "valid version in counterparty versions",
+                Version::compatible_versions(),
+                vec![
+                    version("version1", &[]),
+                    version("2.0.0", &["ORDER_UNORDERED-ZK"]),
+                    Version::default(),
+                ],
+                Some(Version::default()),
+            ),
+            (
+                "valid identifier match but empty feature set not allowed",
+                Version::compatible_versions(),
+                vec![version("1", &["DAG", "ORDERED-ZK", "UNORDERED-zk"])],
+                None,
+            ),
+            (
+                "empty counterparty versions",
+                Version::compatible_versions(),
+                vec![],
+                None,
+            ),
+            (
+                "non-matching counterparty versions",
+                Version::compatible_versions(),
+                vec![version("2.0.0", &[])],
+                None,
+            ),
+            (
+                "unordered-only counterparty",
+                Version::compatible_versions(),
+                vec![version("1", &["ORDER_UNORDERED"])],
+                Some(version("1", &["ORDER_UNORDERED"])),
+            ),
+            (
+                "ordered-only counterparty",
+                Version::compatible_versions(),
+                vec![version("1", &["ORDER_ORDERED", "ORDER_DAG"])],
+                Some(version("1", &["ORDER_ORDERED"])),
+            ),
+            (
+                "local feature order is preserved",
+                Version::compatible_versions(),
+                vec![version("1", &["ORDER_UNORDERED", "ORDER_ORDERED"])],
+                Some(Version::default()),
+            ),
+            (
+                "first supported version with common features wins",
+                vec![
+                    version("2", &["ORDER_DAG"]),
+                    version("1", &["ORDER_UNORDERED"]),
+                ],
+                vec![Version::default(), version("2", &["ORDER_ORDERED"])],
+                Some(version("1", &["ORDER_UNORDERED"])),
+            ),
+        ];
+
+        for (name, supported, counterparty, expected) in tests {
+            let picked = Version::pick_version(&supported, &counterparty);
+            match expected {
+                Some(expected) => assert_eq!(picked.unwrap(), expected, "{name}"),
+                None => assert!(
+                    matches!(picked, Err(ConnectionError::NoCommonVersion)),
+                    "{name}"
+                ),
+            }
+        }
+    }
+
+    #[test]
+    fn verify_is_supported() {
+        let tests = [
+            (
+                "entire feature set supported",
+                Version::default(),
+                version("1", &["ORDER_ORDERED", "ORDER_UNORDERED", "ORDER_DAG"]),
+                true,
+            ),
+            (
+                "subset of features supported",
+                version("1", &["ORDER_UNORDERED"]),
+                Version::default(),
+                true,
+            ),
+            (
+                "empty feature sets not supported",
+                version("1", &[]),
+                Version::default(),
+                false,
+            ),
+            (
+                "one feature missing",
+                Version::default(),
+                version("1", &["ORDER_UNORDERED", "ORDER_DAG"]),
+                false,
+            ),
+            (
+                "both features missing",
+                Version::default(),
+                version("1", &["ORDER_DAG"]),
+                false,
+            ),
+            (
+                "identifiers do not match",
+                version("2", &["ORDER_UNORDERED", "ORDER_ORDERED"]),
+                Version::default(),
+                false,
+            ),
+        ];
+
+        for (name, proposed, supported, want_pass) in tests {
+            let res = proposed.verify_is_supported(&[supported]);
+            assert_eq!(res.is_ok(), want_pass, "{name}");
+            if !want_pass {
+                assert!(
+                    matches!(res, Err(ConnectionError::VersionNotSupported { version }) if version == proposed),
+                    "{name}"
+                );
+            }
+        }
+    }
+
     #[test]
     fn serialize() {
         let def = Version::default();
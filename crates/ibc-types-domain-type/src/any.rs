@@ -0,0 +1,36 @@
+//! Conversions between domain types and protobuf [`Any`] messages.
+//!
+//! Enabled by the `any` feature.
+
+use crate::prelude::*;
+
+use ibc_proto::google::protobuf::Any;
+
+use crate::DomainType;
+
+/// Decodes `any` as a `T`, or returns `None` if its type URL is not `T`'s.
+///
+/// This lets a decoder dispatch on the type URL of an incoming [`Any`] by
+/// trying each candidate message type in turn.
+pub fn decode_as<T>(any: &Any) -> Option<Result<T, anyhow::Error>>
+where
+    T: DomainType,
+    <T as TryFrom<T::Proto>>::Error: Into<anyhow::Error> + Send + Sync + 'static,
+{
+    if any.type_url != <T::Proto as prost::Name>::type_url() {
+        return None;
+    }
+    Some(T::decode(any.value.as_slice()))
+}
+
+/// Encodes `msg` as an [`Any`] carrying the type URL of its proto type.
+pub fn to_any<T>(msg: T) -> Any
+where
+    T: DomainType,
+    <T as TryFrom<T::Proto>>::Error: Into<anyhow::Error> + Send + Sync + 'static,
+{
+    Any {
+        type_url: <T::Proto as prost::Name>::type_url(),
+        value: msg.encode_to_vec(),
+    }
+}
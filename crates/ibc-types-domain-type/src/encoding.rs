@@ -0,0 +1,108 @@
+//! Helpers for encoding protobuf fields directly from borrowed domain data.
+//!
+//! These let a domain type compute its encoded length and write its encoding
+//! without first cloning itself into its proto type. Each helper follows the
+//! proto3 rules used by `prost`: scalar fields are omitted when they hold their
+//! default value, while repeated elements and embedded messages are always
+//! written.
+
+use bytes::BufMut;
+use prost::encoding::{encode_key, encode_varint, encoded_len_varint, key_len, WireType};
+
+/// Returns the length of a length-delimited field with a payload of `len` bytes,
+/// including its key and length prefix.
+pub fn length_delimited_len(tag: u32, len: usize) -> usize {
+    key_len(tag) + encoded_len_varint(len as u64) + len
+}
+
+/// Encodes the key and length prefix of a length-delimited field with a
+/// payload of `len` bytes. The caller is responsible for writing the payload.
+pub fn encode_length_delimited_header(tag: u32, len: usize, buf: &mut impl BufMut) {
+    encode_key(tag, WireType::LengthDelimited, buf);
+    encode_varint(len as u64, buf);
+}
+
+/// Returns the encoded length of a `string` or `bytes` field.
+pub fn bytes_len(tag: u32, value: &[u8]) -> usize {
+    if value.is_empty() {
+        0
+    } else {
+        length_delimited_len(tag, value.len())
+    }
+}
+
+/// Encodes a `string` or `bytes` field.
+pub fn encode_bytes(tag: u32, value: &[u8], buf: &mut impl BufMut) {
+    if !value.is_empty() {
+        encode_length_delimited_header(tag, value.len(), buf);
+        buf.put_slice(value);
+    }
+}
+
+/// Returns the encoded length of a `repeated string` or `repeated bytes` field.
+pub fn repeated_bytes_len<'a>(tag: u32, values: impl IntoIterator<Item = &'a [u8]>) -> usize {
+    values
+        .into_iter()
+        .map(|value| length_delimited_len(tag, value.len()))
+        .sum()
+}
+
+/// Encodes a `repeated string` or `repeated bytes` field.
+pub fn encode_repeated_bytes<'a>(
+    tag: u32,
+    values: impl IntoIterator<Item = &'a [u8]>,
+    buf: &mut impl BufMut,
+) {
+    for value in values {
+        encode_length_delimited_header(tag, value.len(), buf);
+        buf.put_slice(value);
+    }
+}
+
+/// Returns the encoded length of a `uint64` field.
+pub fn uint64_len(tag: u32, value: u64) -> usize {
+    if value == 0 {
+        0
+    } else {
+        key_len(tag) + encoded_len_varint(value)
+    }
+}
+
+/// Encodes a `uint64` field.
+pub fn encode_uint64(tag: u32, value: u64, buf: &mut impl BufMut) {
+    if value != 0 {
+        encode_key(tag, WireType::Varint, buf);
+        encode_varint(value, buf);
+    }
+}
+
+/// Returns the encoded length of a `bool` field.
+pub fn bool_len(tag: u32, value: bool) -> usize {
+    uint64_len(tag, value as u64)
+}
+
+/// Encodes a `bool` field.
+pub fn encode_bool(tag: u32, value: bool, buf: &mut impl BufMut) {
+    encode_uint64(tag, value as u64, buf)
+}
+
+/// Returns the encoded length of an embedded message field.
+pub fn message_len(tag: u32, msg: &impl prost::Message) -> usize {
+    length_delimited_len(tag, msg.encoded_len())
+}
+
+/// Encodes an embedded message field.
+pub fn encode_message(tag: u32, msg: &impl prost::Message, buf: &mut impl BufMut) {
+    prost::encoding::message::encode(tag, msg, buf)
+}
+
+/// Returns the encoded length of an enum field.
+pub fn enumeration_len(tag: u32, value: i32) -> usize {
+    // Enums are encoded as `int32`, which sign-extends negative values.
+    uint64_len(tag, value as i64 as u64)
+}
+
+/// Encodes an enum field.
+pub fn encode_enumeration(tag: u32, value: i32, buf: &mut impl BufMut) {
+    encode_uint64(tag, value as i64 as u64, buf)
+}
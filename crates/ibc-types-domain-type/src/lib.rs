@@ -1,6 +1,6 @@
 //! Provides a marker type capturing the relationship between a domain type and a protobuf type.
 #![no_std]
-// Requires nightly.
+// Only enabled when building docs (e.g. on docs.rs), which requires nightly.
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 
 extern crate alloc;
@@ -10,6 +10,16 @@ extern crate std;
 mod prelude;
 use prelude::*;
 
+#[cfg(feature = "any")]
+mod any;
+#[cfg(feature = "any")]
+pub use any::{decode_as, to_any};
+
+pub mod encoding;
+
+#[cfg(feature = "test-util")]
+pub mod test_util;
+
 /// A marker type that captures the relationships between a domain type (`Self`) and a protobuf type (`Self::Proto`).
 pub trait DomainType
 where
@@ -40,4 +50,49 @@ where
             .try_into()
             .map_err(Into::into)
     }
+
+    /// Decode a sequence of length-delimited values of this domain type
+    /// written back-to-back into `buf`, as produced by repeated calls to
+    /// `prost::Message::encode_length_delimited`.
+    ///
+    /// The iterator yields one item per value until `buf` is exhausted. It
+    /// stops after the first error, since the framing of any later values
+    /// can no longer be trusted.
+    fn decode_many<B: bytes::Buf>(buf: B) -> DecodeMany<Self, B> {
+        DecodeMany {
+            buf: Some(buf),
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+/// Iterator over length-delimited values decoded from a buffer, returned by
+/// [`DomainType::decode_many`].
+pub struct DecodeMany<T, B> {
+    buf: Option<B>,
+    _marker: core::marker::PhantomData<fn() -> T>,
+}
+
+impl<T, B> Iterator for DecodeMany<T, B>
+where
+    T: DomainType,
+    <T as TryFrom<T::Proto>>::Error: Into<anyhow::Error> + Send + Sync + 'static,
+    B: bytes::Buf,
+{
+    type Item = Result<T, anyhow::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let buf = self.buf.as_mut()?;
+        if !buf.has_remaining() {
+            self.buf = None;
+            return None;
+        }
+        let item = <T::Proto as prost::Message>::decode_length_delimited(&mut *buf)
+            .map_err(anyhow::Error::msg)
+            .and_then(|proto| proto.try_into().map_err(Into::into));
+        if item.is_err() {
+            self.buf = None;
+        }
+        Some(item)
+    }
 }
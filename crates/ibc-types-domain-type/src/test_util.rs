@@ -0,0 +1,86 @@
+//! Round-trip helpers for tests of [`DomainType`] implementations.
+//!
+//! Enabled by the `test-util` feature. Each helper panics on failure, so it
+//! can be called directly from a `#[test]`.
+
+use core::fmt::Debug;
+
+use ibc_proto::google::protobuf::Any;
+use prost::Message;
+
+use crate::DomainType;
+
+/// Encodes `value` to bytes, decodes it back, and asserts that the result
+/// equals `value`.
+///
+/// ```
+/// use ibc_proto::ibc::core::client::v1::Height as RawHeight;
+/// use ibc_types_domain_type::{test_util::domain_type_roundtrip, DomainType};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Height {
+///     revision_number: u64,
+///     revision_height: u64,
+/// }
+///
+/// impl DomainType for Height {
+///     type Proto = RawHeight;
+/// }
+///
+/// impl TryFrom<RawHeight> for Height {
+///     type Error = anyhow::Error;
+///
+///     fn try_from(raw: RawHeight) -> Result<Self, Self::Error> {
+///         Ok(Height {
+///             revision_number: raw.revision_number,
+///             revision_height: raw.revision_height,
+///         })
+///     }
+/// }
+///
+/// impl From<Height> for RawHeight {
+///     fn from(height: Height) -> Self {
+///         RawHeight {
+///             revision_number: height.revision_number,
+///             revision_height: height.revision_height,
+///         }
+///     }
+/// }
+///
+/// domain_type_roundtrip(Height {
+///     revision_number: 1,
+///     revision_height: 42,
+/// });
+/// ```
+pub fn domain_type_roundtrip<T>(value: T)
+where
+    T: DomainType + PartialEq + Debug,
+    <T as TryFrom<T::Proto>>::Error: Into<anyhow::Error> + Send + Sync + 'static,
+{
+    let bytes = value.encode_to_vec();
+    let decoded =
+        T::decode(bytes.as_slice()).unwrap_or_else(|e| panic!("failed to decode {value:?}: {e}"));
+    assert_eq!(decoded, value);
+}
+
+/// Wraps `value` in an [`Any`], encodes and decodes the `Any`, unwraps it
+/// again, and asserts that the result equals `value`.
+///
+/// Also checks that the `Any` carries a type URL, so types that forget to set
+/// one are caught.
+pub fn any_roundtrip<T>(value: T)
+where
+    T: Clone + PartialEq + Debug + Into<Any> + TryFrom<Any>,
+    <T as TryFrom<Any>>::Error: Debug,
+{
+    let any: Any = value.clone().into();
+    assert!(!any.type_url.is_empty(), "{value:?} has an empty type URL");
+
+    let decoded_any = Any::decode(any.encode_to_vec().as_slice())
+        .unwrap_or_else(|e| panic!("failed to decode Any for {value:?}: {e}"));
+    assert_eq!(decoded_any, any);
+
+    let decoded = T::try_from(decoded_any)
+        .unwrap_or_else(|e| panic!("failed to convert Any to {value:?}: {e:?}"));
+    assert_eq!(decoded, value);
+}
@@ -0,0 +1,28 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ibc_types_identifier::{
+    validate_channel_identifier, validate_client_identifier, validate_connection_identifier,
+    validate_identifier, validate_port_identifier,
+};
+
+fn bench_validate_identifier(c: &mut Criterion) {
+    c.bench_function("validate_client_identifier", |b| {
+        b.iter(|| validate_client_identifier(black_box("07-tendermint-1234")))
+    });
+    c.bench_function("validate_connection_identifier", |b| {
+        b.iter(|| validate_connection_identifier(black_box("connection-1234")))
+    });
+    c.bench_function("validate_port_identifier", |b| {
+        b.iter(|| validate_port_identifier(black_box("transfer")))
+    });
+    c.bench_function("validate_channel_identifier", |b| {
+        b.iter(|| validate_channel_identifier(black_box("channel-1234")))
+    });
+
+    let long_id = "a.b_c+d-e#f[g]h<i>".repeat(7);
+    c.bench_function("validate_identifier_max_length", |b| {
+        b.iter(|| validate_identifier(black_box(&long_id), 1, 128))
+    });
+}
+
+criterion_group!(benches, bench_validate_identifier);
+criterion_main!(benches);
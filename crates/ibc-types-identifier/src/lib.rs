@@ -1,6 +1,6 @@
 //! IBC identifier validation.
 #![no_std]
-// Requires nightly.
+// Only enabled when building docs (e.g. on docs.rs), which requires nightly.
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 
 extern crate alloc;
@@ -32,12 +32,17 @@ pub enum IdentifierError {
     InvalidCounterpartyChannelId,
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for IdentifierError {}
+impl core::error::Error for IdentifierError {}
 
 /// Path separator (ie. forward slash '/')
 const PATH_SEPARATOR: char = '/';
-const VALID_SPECIAL_CHARS: &str = "._+-#[]<>";
+
+/// Returns whether `c` may appear in an identifier: alphanumeric characters
+/// plus `.`, `_`, `+`, `-`, `#`, `[`, `]`, `<` and `>`.
+#[inline]
+fn is_valid_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '.' | '_' | '+' | '-' | '#' | '[' | ']' | '<' | '>')
+}
 
 /// Default validator function for identifiers.
 ///
@@ -70,10 +75,7 @@ pub fn validate_identifier(id: &str, min: usize, max: usize) -> Result<(), Ident
     // - Alphanumeric
     // - `.`, `_`, `+`, `-`, `#`
     // - `[`, `]`, `<`, `>`
-    if !id
-        .chars()
-        .all(|c| c.is_alphanumeric() || VALID_SPECIAL_CHARS.contains(c))
-    {
+    if !id.chars().all(is_valid_identifier_char) {
         return Err(IdentifierError::InvalidCharacter { id: id.into() });
     }
 
@@ -116,6 +118,7 @@ pub fn validate_channel_identifier(id: &str) -> Result<(), IdentifierError> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::{format, string::ToString};
     use test_log::test;
 
     #[test]
@@ -202,4 +205,48 @@ mod tests {
         let id = validate_identifier("id/1", 1, 10);
         assert!(id.is_err())
     }
+
+    #[test]
+    fn valid_id_chars() {
+        // every allowed special character is accepted on its own
+        for c in "._+-#[]<>".chars() {
+            let id: String = core::iter::repeat(c).take(2).collect();
+            assert!(
+                validate_identifier(&id, 1, 10).is_ok(),
+                "{id:?} should be valid"
+            );
+        }
+
+        // every ASCII alphanumeric character is accepted
+        for c in ('a'..='z').chain('A'..='Z').chain('0'..='9') {
+            assert!(validate_identifier(&c.to_string(), 1, 10).is_ok());
+        }
+
+        // non-ASCII alphanumerics are accepted, matching `char::is_alphanumeric`
+        assert!(validate_identifier("chännel-0", 1, 20).is_ok());
+    }
+
+    #[test]
+    fn invalid_id_chars() {
+        // every printable ASCII character outside the allowed set is rejected
+        let disallowed =
+            (' '..='~').filter(|c| !c.is_ascii_alphanumeric() && !"._+-#[]<>".contains(*c));
+        for c in disallowed {
+            let id = format!("id{c}0");
+            let err = validate_identifier(&id, 1, 10).unwrap_err();
+            if c == '/' {
+                assert!(matches!(err, IdentifierError::ContainSeparator { .. }));
+            } else {
+                assert!(
+                    matches!(err, IdentifierError::InvalidCharacter { .. }),
+                    "{id:?} should be rejected"
+                );
+            }
+        }
+
+        // control and whitespace characters are rejected
+        for id in ["id\t0", "id\n0", "id\u{0}0", "id\u{a0}0"] {
+            assert!(validate_identifier(id, 1, 10).is_err());
+        }
+    }
 }
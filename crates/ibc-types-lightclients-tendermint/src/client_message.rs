@@ -0,0 +1,48 @@
+use alloc::boxed::Box;
+
+use ibc_proto::google::protobuf::Any;
+
+use crate::header::{Header, TENDERMINT_HEADER_TYPE_URL};
+use crate::misbehaviour::{Misbehaviour, TENDERMINT_MISBEHAVIOUR_TYPE_URL};
+use crate::Error;
+
+/// A message submitted to update a Tendermint client: either a new header,
+/// or evidence of misbehaviour.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ClientMessage {
+    Header(Box<Header>),
+    Misbehaviour(Box<Misbehaviour>),
+}
+
+impl From<Header> for ClientMessage {
+    fn from(header: Header) -> Self {
+        ClientMessage::Header(Box::new(header))
+    }
+}
+
+impl From<Misbehaviour> for ClientMessage {
+    fn from(misbehaviour: Misbehaviour) -> Self {
+        ClientMessage::Misbehaviour(Box::new(misbehaviour))
+    }
+}
+
+impl TryFrom<Any> for ClientMessage {
+    type Error = Error;
+
+    fn try_from(raw: Any) -> Result<Self, Error> {
+        match raw.type_url.as_str() {
+            TENDERMINT_HEADER_TYPE_URL => Header::try_from(raw).map(Into::into),
+            TENDERMINT_MISBEHAVIOUR_TYPE_URL => Misbehaviour::try_from(raw).map(Into::into),
+            _ => Err(Error::WrongTypeUrl { url: raw.type_url }),
+        }
+    }
+}
+
+impl From<ClientMessage> for Any {
+    fn from(msg: ClientMessage) -> Self {
+        match msg {
+            ClientMessage::Header(header) => (*header).into(),
+            ClientMessage::Misbehaviour(misbehaviour) => (*misbehaviour).into(),
+        }
+    }
+}
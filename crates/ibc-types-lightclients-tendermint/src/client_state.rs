@@ -2,28 +2,35 @@ use crate::prelude::*;
 
 use core::convert::{TryFrom, TryInto};
 use core::time::Duration;
-use std::string::String;
 
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::core::client::v1::Height as RawHeight;
 
-use ibc_proto::ibc::lightclients::tendermint::v1::ClientState as RawTmClientState;
+use bytes::BufMut;
+use ibc_proto::ibc::lightclients::tendermint::v1::{ClientState as RawTmClientState, Fraction};
 use ibc_proto::Protobuf;
-use ibc_types_domain_type::DomainType;
+use ibc_types_domain_type::{encoding, DomainType};
 use ics23::ProofSpec;
 use prost::Message;
 use tendermint::chain::id::MAX_LENGTH as MaxChainIdLen;
+use tendermint::time::Time;
 use tendermint::trust_threshold::TrustThresholdFraction as TendermintTrustThresholdFraction;
 use tendermint_light_client_verifier::options::Options;
+use tendermint_light_client_verifier::{ProdVerifier, Verifier};
+use tendermint_proto::google::protobuf::Duration as RawDuration;
 
+use crate::client_message::ClientMessage;
+use crate::consensus_state::ConsensusState;
 use crate::header::Header as TmHeader;
 
-use ibc_types_core_client::Height;
+use ibc_types_core_client::{ClientId, FieldChange, Height};
 
+use ibc_types_core_commitment::{MerklePath, MerklePrefix, MerkleProof, MerkleRoot};
 use ibc_types_core_connection::ChainId;
+use ibc_types_path::Path;
 use ibc_types_timestamp::Timestamp;
 
-use crate::{Error, TrustThreshold};
+use crate::{error::IntoResult, Error, TrustThreshold};
 
 pub const TENDERMINT_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.tendermint.v1.ClientState";
 
@@ -57,6 +64,22 @@ pub struct AllowUpdate {
     pub after_misbehaviour: bool,
 }
 
+/// The arguments to [`ClientState::new`], named so that call sites cannot
+/// mix up the several `Duration` and `Height` parameters.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClientStateParams {
+    pub chain_id: ChainId,
+    pub trust_level: TrustThreshold,
+    pub trusting_period: Duration,
+    pub unbonding_period: Duration,
+    pub max_clock_drift: Duration,
+    pub latest_height: Height,
+    pub proof_specs: Vec<ProofSpec>,
+    pub upgrade_path: Vec<String>,
+    pub allow_update: AllowUpdate,
+    pub frozen_height: Option<Height>,
+}
+
 impl ClientState {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -167,6 +190,23 @@ impl ClientState {
         })
     }
 
+    /// Creates a [`ClientState`] from named parameters, performing the same
+    /// validation as [`ClientState::new`].
+    pub fn from_params(params: ClientStateParams) -> Result<ClientState, Error> {
+        ClientState::new(
+            params.chain_id,
+            params.trust_level,
+            params.trusting_period,
+            params.unbonding_period,
+            params.max_clock_drift,
+            params.latest_height,
+            params.proof_specs,
+            params.upgrade_path,
+            params.allow_update,
+            params.frozen_height,
+        )
+    }
+
     pub fn latest_height(&self) -> Height {
         self.latest_height
     }
@@ -176,19 +216,102 @@ impl ClientState {
         self.frozen_height.is_some()
     }
 
+    /// Returns whether a consensus state that is `elapsed` old has passed the
+    /// trusting period.
+    ///
+    /// This does not consult the client's [`AllowUpdate`] policy: an expired
+    /// client can only be brought back into use if
+    /// [`can_recover_after_expiry`](Self::can_recover_after_expiry) allows it.
     pub fn expired(&self, elapsed: Duration) -> bool {
         elapsed > self.trusting_period
     }
 
+    /// Returns whether the client may be recovered, i.e. replaced by a
+    /// substitute client through governance, once it has
+    /// [`expired`](Self::expired).
+    pub fn can_recover_after_expiry(&self) -> bool {
+        self.allow_update.after_expiry
+    }
+
+    /// Returns whether the client may be recovered, i.e. replaced by a
+    /// substitute client through governance, once it has been frozen for
+    /// misbehaviour.
+    pub fn can_recover_after_misbehaviour(&self) -> bool {
+        self.allow_update.after_misbehaviour
+    }
+
+    /// Returns how long `consensus_state` remains within the trusting period
+    /// at `now`, or [`Duration::ZERO`] once the whole trusting period has
+    /// elapsed.
+    ///
+    /// As with [`expired`](Self::expired), a state is still valid at the very
+    /// instant this reaches zero, and expires as soon as any more time passes;
+    /// see [`ConsensusState::is_expired`]. This uses saturating [`Duration`]
+    /// arithmetic rather than computing the expiry [`Time`], so consensus
+    /// states whose expiry would lie beyond the range of [`Time`] never error.
+    pub fn time_until_expiry(&self, consensus_state: &ConsensusState, now: Time) -> Duration {
+        match now.duration_since(consensus_state.timestamp) {
+            Ok(elapsed) => self.trusting_period.saturating_sub(elapsed),
+            // The consensus state is from the future, so the full trusting
+            // period is still ahead of it.
+            Err(_) => {
+                let ahead = consensus_state
+                    .timestamp
+                    .duration_since(now)
+                    .unwrap_or_default();
+                self.trusting_period.saturating_add(ahead)
+            }
+        }
+    }
+
+    /// Returns whether storing `new` over the consensus state `existing`
+    /// already recorded at the same height is a no-op.
+    ///
+    /// Re-submitting a header whose consensus state is already stored is
+    /// idempotent and should be accepted without changing any state. A
+    /// *conflicting* consensus state at an existing height is not a no-op:
+    /// it is evidence of misbehaviour, and must be handled as such.
+    pub fn is_update_noop(existing: Option<&ConsensusState>, new: &ConsensusState) -> bool {
+        existing == Some(new)
+    }
+
+    /// Lists the fields that differ between this client state and `other`,
+    /// e.g. to review a proposed client upgrade.
+    pub fn diff(&self, other: &ClientState) -> Vec<FieldChange> {
+        [
+            FieldChange::compare("chain_id", &self.chain_id, &other.chain_id),
+            FieldChange::compare("trust_level", &self.trust_level, &other.trust_level),
+            FieldChange::compare(
+                "trusting_period",
+                &self.trusting_period,
+                &other.trusting_period,
+            ),
+            FieldChange::compare(
+                "unbonding_period",
+                &self.unbonding_period,
+                &other.unbonding_period,
+            ),
+            FieldChange::compare(
+                "max_clock_drift",
+                &self.max_clock_drift,
+                &other.max_clock_drift,
+            ),
+            FieldChange::compare("latest_height", &self.latest_height, &other.latest_height),
+            FieldChange::compare("proof_specs", &self.proof_specs, &other.proof_specs),
+            FieldChange::compare("upgrade_path", &self.upgrade_path, &other.upgrade_path),
+            FieldChange::compare("allow_update", &self.allow_update, &other.allow_update),
+            FieldChange::compare("frozen_height", &self.frozen_height, &other.frozen_height),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
     pub fn with_header(self, h: TmHeader) -> Result<Self, Error> {
+        let height = h.try_height()?.revision_height();
         Ok(ClientState {
-            latest_height: Height::new(
-                self.latest_height.revision_number(),
-                h.signed_header.header.height.into(),
-            )
-            .map_err(|_| Error::InvalidHeaderHeight {
-                height: h.signed_header.header.height.value(),
-            })?,
+            latest_height: Height::new(self.latest_height.revision_number(), height)
+                .map_err(|_| Error::InvalidHeaderHeight { height })?,
             ..self
         })
     }
@@ -226,6 +349,103 @@ impl ClientState {
         })
     }
 
+    /// The client's chain id as a Tendermint [`tendermint::chain::Id`].
+    pub(crate) fn tendermint_chain_id(&self) -> Result<tendermint::chain::Id, Error> {
+        tendermint::chain::Id::try_from(self.chain_id.to_string()).map_err(|e| {
+            Error::InvalidHeader {
+                reason: "invalid client state chain id".to_string(),
+                error: e,
+            }
+        })
+    }
+
+    /// Verifies the untrusted `header` against the `trusted_consensus_state`
+    /// stored at the header's `trusted_height`, using the Tendermint light
+    /// client verifier configured with this client's trust parameters.
+    ///
+    /// `now` is the current host time, used to check that the trusted state
+    /// is within the trusting period and that the header is not from the
+    /// future.
+    pub fn verify_header(
+        &self,
+        trusted_consensus_state: &ConsensusState,
+        header: &TmHeader,
+        now: Timestamp,
+    ) -> Result<(), Error> {
+        let now = now.into_tm_time().ok_or(Error::MissingHostTimestamp)?;
+
+        // the trusted validator set is provided by the relayer, so it must
+        // match the one committed to by the trusted consensus state
+        let trusted_val_hash = header.trusted_validator_set.hash();
+        if trusted_val_hash != trusted_consensus_state.next_validators_hash {
+            return Err(Error::TrustedValidatorHashMismatch {
+                next_validators_hash: trusted_consensus_state.next_validators_hash,
+                trusted_val_hash,
+            });
+        }
+
+        let chain_id = self.tendermint_chain_id()?;
+        let trusted_state = header.as_trusted_block_state(trusted_consensus_state, &chain_id)?;
+        let options = self.as_light_client_options()?;
+
+        ProdVerifier::default()
+            .verify_update_header(
+                header.as_untrusted_block_state(),
+                trusted_state,
+                &options,
+                now,
+            )
+            .into_result()
+    }
+
+    /// Verifies a [`ClientMessage`] submitted to the client `client_id`,
+    /// dispatching to [`ClientState::verify_header`] for headers and to
+    /// [`Misbehaviour::verify_misbehaviour`](crate::misbehaviour::Misbehaviour::verify_misbehaviour)
+    /// for misbehaviour.
+    ///
+    /// `trusted_consensus_state` looks up the consensus state the client
+    /// stores at a given height; each header is verified against the one at
+    /// its `trusted_height`.
+    pub fn verify_client_message<F>(
+        &self,
+        client_id: &ClientId,
+        msg: &ClientMessage,
+        mut trusted_consensus_state: F,
+        now: Timestamp,
+    ) -> Result<(), Error>
+    where
+        F: FnMut(Height) -> Option<ConsensusState>,
+    {
+        let mut trusted_consensus_state = |height| {
+            trusted_consensus_state(height).ok_or_else(|| Error::ConsensusStateNotFound {
+                client_id: client_id.clone(),
+                height,
+            })
+        };
+
+        match msg {
+            ClientMessage::Header(header) => {
+                let consensus_state = trusted_consensus_state(header.trusted_height)?;
+                self.verify_header(&consensus_state, header, now)
+            }
+            ClientMessage::Misbehaviour(misbehaviour) => {
+                if misbehaviour.client_id != *client_id {
+                    return Err(Error::MisbehaviourClientIdMismatch {
+                        client_id: client_id.clone(),
+                        misbehaviour_client_id: misbehaviour.client_id.clone(),
+                    });
+                }
+
+                let now = now.into_tm_time().ok_or(Error::MissingHostTimestamp)?;
+                let consensus_state_1 =
+                    trusted_consensus_state(misbehaviour.header1.trusted_height)?;
+                let consensus_state_2 =
+                    trusted_consensus_state(misbehaviour.header2.trusted_height)?;
+                misbehaviour.verify_misbehaviour(self, &consensus_state_1, &consensus_state_2, now)
+            }
+        }
+    }
+
     /// Verify the time and height delays
     pub fn verify_delay_passed(
         current_time: Timestamp,
@@ -272,6 +492,106 @@ impl ClientState {
             _ => Ok(()),
         }
     }
+
+    /// Checks that a proof at `proof_height` can be verified against this
+    /// client, i.e. that the client has advanced at least to `proof_height`.
+    pub fn validate_proof_height(&self, proof_height: Height) -> Result<(), Error> {
+        if self.latest_height < proof_height {
+            return Err(Error::InvalidProofHeight {
+                latest_height: self.latest_height,
+                proof_height,
+            });
+        }
+        Ok(())
+    }
+
+    /// Verifies that `value` is committed to at `path` under `prefix`, in the
+    /// tree with the given `root`, using this client's proof specs.
+    pub fn verify_membership_at_path(
+        &self,
+        prefix: &MerklePrefix,
+        proof: &MerkleProof,
+        root: &MerkleRoot,
+        path: Path,
+        value: Vec<u8>,
+    ) -> Result<(), Error> {
+        let merkle_path = MerklePath::apply_prefix(prefix, path);
+        proof
+            .verify_membership(&self.proof_specs, root.clone(), merkle_path, value, 0)
+            .map_err(Error::MembershipVerification)
+    }
+
+    /// The fixed-size embedded messages of the [`RawTmClientState`] encoding,
+    /// in field order: the trust level, the trusting, unbonding and clock
+    /// drift periods, and the frozen and latest heights.
+    fn raw_small_fields(&self) -> (Fraction, [RawDuration; 3], [RawHeight; 2]) {
+        (
+            self.trust_level.into(),
+            [
+                CometBftDuration(self.trusting_period).into(),
+                CometBftDuration(self.unbonding_period).into(),
+                CometBftDuration(self.max_clock_drift).into(),
+            ],
+            [
+                self.frozen_height.map(Into::into).unwrap_or(RawHeight {
+                    revision_number: 0,
+                    revision_height: 0,
+                }),
+                self.latest_height.into(),
+            ],
+        )
+    }
+
+    /// The length of this client state's [`RawTmClientState`] encoding,
+    /// computed without cloning it first.
+    pub fn encoded_len_borrowed(&self) -> usize {
+        let (trust_level, [trusting, unbonding, drift], [frozen, latest]) = self.raw_small_fields();
+
+        encoding::bytes_len(1, self.chain_id.as_str().as_bytes())
+            + encoding::message_len(2, &trust_level)
+            + encoding::message_len(3, &trusting)
+            + encoding::message_len(4, &unbonding)
+            + encoding::message_len(5, &drift)
+            + encoding::message_len(6, &frozen)
+            + encoding::message_len(7, &latest)
+            + self
+                .proof_specs
+                .iter()
+                .map(|spec| encoding::message_len(8, spec))
+                .sum::<usize>()
+            + encoding::repeated_bytes_len(9, self.upgrade_path.iter().map(|p| p.as_bytes()))
+            + encoding::bool_len(10, self.allow_update.after_expiry)
+            + encoding::bool_len(11, self.allow_update.after_misbehaviour)
+    }
+
+    /// Encodes this client state as a [`RawTmClientState`] into `buf`,
+    /// without cloning it first. The output is identical to encoding the
+    /// owned proto type.
+    pub fn encode_borrowed(&self, buf: &mut impl BufMut) {
+        let (trust_level, [trusting, unbonding, drift], [frozen, latest]) = self.raw_small_fields();
+
+        encoding::encode_bytes(1, self.chain_id.as_str().as_bytes(), buf);
+        encoding::encode_message(2, &trust_level, buf);
+        encoding::encode_message(3, &trusting, buf);
+        encoding::encode_message(4, &unbonding, buf);
+        encoding::encode_message(5, &drift, buf);
+        encoding::encode_message(6, &frozen, buf);
+        encoding::encode_message(7, &latest, buf);
+        for spec in &self.proof_specs {
+            encoding::encode_message(8, spec, buf);
+        }
+        encoding::encode_repeated_bytes(9, self.upgrade_path.iter().map(|p| p.as_bytes()), buf);
+        encoding::encode_bool(10, self.allow_update.after_expiry, buf);
+        encoding::encode_bool(11, self.allow_update.after_misbehaviour, buf);
+    }
+
+    /// Appends this client state's [`RawTmClientState`] encoding to `buf`,
+    /// reserving the encoded length up front so that `buf` is grown at most
+    /// once.
+    pub fn encode_to(&self, buf: &mut Vec<u8>) {
+        buf.reserve(self.encoded_len_borrowed());
+        self.encode_borrowed(buf);
+    }
 }
 
 impl Protobuf<RawTmClientState> for ClientState {}
@@ -283,10 +603,7 @@ impl TryFrom<RawTmClientState> for ClientState {
         let chain_id = ChainId::from_string(raw.chain_id.as_str());
 
         let trust_level = {
-            let trust_level = raw
-                .trust_level
-                .clone()
-                .ok_or(Error::MissingTrustingPeriod)?;
+            let trust_level = raw.trust_level.ok_or(Error::MissingTrustingPeriod)?;
             trust_level
                 .try_into()
                 .map_err(|e| Error::InvalidTrustThreshold {
@@ -321,9 +638,26 @@ impl TryFrom<RawTmClientState> for ClientState {
         // In `RawClientState`, a `frozen_height` of `0` means "not frozen".
         // See:
         // https://github.com/cosmos/ibc-go/blob/8422d0c4c35ef970539466c5bdec1cd27369bab3/modules/light-clients/07-tendermint/types/client_state.go#L74
-        let frozen_height = raw
-            .frozen_height
-            .and_then(|raw_height| raw_height.try_into().ok());
+        //
+        // Any other value must be a valid height, at which the client is
+        // considered frozen. Malformed heights (e.g. `{1, 0}`) are rejected
+        // rather than silently treated as "not frozen".
+        let frozen_height = match raw.frozen_height {
+            None
+            | Some(RawHeight {
+                revision_number: 0,
+                revision_height: 0,
+            }) => None,
+            Some(raw_height) => {
+                Some(
+                    raw_height
+                        .try_into()
+                        .map_err(|e| Error::InvalidFrozenHeight {
+                            reason: format!("{e}"),
+                        })?,
+                )
+            }
+        };
 
         // We use set this deprecated field just so that we can properly convert
         // it back in its raw form
@@ -426,9 +760,7 @@ impl TryFrom<Any> for ClientState {
         }
 
         match raw.type_url.as_str() {
-            TENDERMINT_CLIENT_STATE_TYPE_URL => {
-                decode_client_state(raw.value.deref()).map_err(Into::into)
-            }
+            TENDERMINT_CLIENT_STATE_TYPE_URL => decode_client_state(raw.value.deref()),
             _ => Err(Error::WrongTypeUrl { url: raw.type_url }),
         }
     }
@@ -450,6 +782,7 @@ mod tests {
     use test_log::test;
 
     use ibc_types_core_client::Height;
+    use ibc_types_core_commitment::MerkleRoot;
     use ibc_types_core_connection::ChainId;
     use ibc_types_timestamp::{Timestamp, ZERO_DURATION};
 
@@ -457,26 +790,11 @@ mod tests {
 
     use crate::TrustThreshold;
 
-    use ics23::ProofSpec;
-
-    #[derive(Clone, Debug, PartialEq)]
-    struct ClientStateParams {
-        id: ChainId,
-        trust_level: TrustThreshold,
-        trusting_period: Duration,
-        unbonding_period: Duration,
-        max_clock_drift: Duration,
-        latest_height: Height,
-        proof_specs: Vec<ProofSpec>,
-        upgrade_path: Vec<String>,
-        allow_update: AllowUpdate,
-    }
-
     #[test]
     fn client_state_new() {
         // Define a "default" set of parameters to reuse throughout these tests.
         let default_params: ClientStateParams = ClientStateParams {
-            id: ChainId::default(),
+            chain_id: ChainId::default(),
             trust_level: TrustThreshold::ONE_THIRD,
             trusting_period: Duration::new(64000, 0),
             unbonding_period: Duration::new(128000, 0),
@@ -488,6 +806,7 @@ mod tests {
                 after_expiry: false,
                 after_misbehaviour: false,
             },
+            frozen_height: None,
         };
 
         struct Test {
@@ -521,7 +840,7 @@ mod tests {
             Test {
                 name: "Valid long (50 chars) chain-id".to_string(),
                 params: ClientStateParams {
-                    id: ChainId::new("a".repeat(48), 0),
+                    chain_id: ChainId::new("a".repeat(48), 0),
                     ..default_params.clone()
                 },
                 want_pass: true,
@@ -529,7 +848,7 @@ mod tests {
             Test {
                 name: "Invalid too-long (51 chars) chain-id".to_string(),
                 params: ClientStateParams {
-                    id: ChainId::new("a".repeat(49), 0),
+                    chain_id: ChainId::new("a".repeat(49), 0),
                     ..default_params.clone()
                 },
                 want_pass: false,
@@ -616,7 +935,7 @@ mod tests {
             let p = test.params.clone();
 
             let cs_result = ClientState::new(
-                p.id,
+                p.chain_id,
                 p.trust_level,
                 p.trusting_period,
                 p.unbonding_period,
@@ -717,11 +1036,247 @@ mod tests {
         }
     }
 
+    #[test]
+    fn client_state_frozen_height_from_raw() {
+        let client_state = ClientState::new(
+            ChainId::new("ibc".to_string(), 0),
+            TrustThreshold::ONE_THIRD,
+            Duration::new(64000, 0),
+            Duration::new(128000, 0),
+            Duration::new(3, 0),
+            Height::new(0, 10).unwrap(),
+            vec![ics23::iavl_spec()],
+            Default::default(),
+            AllowUpdate {
+                after_expiry: false,
+                after_misbehaviour: false,
+            },
+            None,
+        )
+        .unwrap();
+        let raw = RawTmClientState::from(client_state.clone());
+
+        // an unfrozen client encodes its frozen height as `{0, 0}`
+        assert_eq!(
+            raw.frozen_height,
+            Some(RawHeight {
+                revision_number: 0,
+                revision_height: 0,
+            })
+        );
+        let decoded = ClientState::try_from(raw.clone()).unwrap();
+        assert_eq!(decoded.frozen_height, None);
+        assert!(!decoded.is_frozen());
+
+        // a missing frozen height also means "not frozen"
+        let decoded = ClientState::try_from(RawTmClientState {
+            frozen_height: None,
+            ..raw.clone()
+        })
+        .unwrap();
+        assert_eq!(decoded.frozen_height, None);
+
+        // any valid height means the client is frozen at that height
+        let decoded = ClientState::try_from(RawTmClientState {
+            frozen_height: Some(RawHeight {
+                revision_number: 0,
+                revision_height: 10,
+            }),
+            ..raw.clone()
+        })
+        .unwrap();
+        assert_eq!(decoded.frozen_height, Some(Height::new(0, 10).unwrap()));
+        assert!(decoded.is_frozen());
+        assert_eq!(
+            RawTmClientState::from(decoded.clone()).frozen_height,
+            Some(RawHeight {
+                revision_number: 0,
+                revision_height: 10,
+            })
+        );
+
+        // an invalid, non-zero height is rejected
+        let res = ClientState::try_from(RawTmClientState {
+            frozen_height: Some(RawHeight {
+                revision_number: 1,
+                revision_height: 0,
+            }),
+            ..raw
+        });
+        assert!(matches!(res, Err(Error::InvalidFrozenHeight { .. })));
+    }
+
+    #[test]
+    fn client_state_time_until_expiry() {
+        let trusting_period = Duration::from_secs(64000);
+        let client_state = ClientState::new(
+            ChainId::new("ibc".to_string(), 0),
+            TrustThreshold::ONE_THIRD,
+            trusting_period,
+            Duration::new(128000, 0),
+            Duration::new(3, 0),
+            Height::new(0, 10).unwrap(),
+            vec![ics23::iavl_spec()],
+            Default::default(),
+            AllowUpdate {
+                after_expiry: false,
+                after_misbehaviour: false,
+            },
+            None,
+        )
+        .unwrap();
+        let consensus_state_at = |timestamp: Time| {
+            ConsensusState::new(
+                MerkleRoot { hash: vec![1; 32] },
+                timestamp,
+                tendermint::Hash::None,
+            )
+        };
+
+        let t0 = Time::from_unix_timestamp(1_700_000_000, 0).unwrap();
+        let cs = consensus_state_at(t0);
+        let at = |secs: u64| (t0 + Duration::from_secs(secs)).unwrap();
+
+        assert_eq!(client_state.time_until_expiry(&cs, t0), trusting_period);
+        assert!(!cs.is_expired(trusting_period, t0));
+        assert_eq!(
+            client_state.time_until_expiry(&cs, at(1000)),
+            Duration::from_secs(63000)
+        );
+        assert!(!cs.is_expired(trusting_period, at(63999)));
+        assert_eq!(
+            client_state.time_until_expiry(&cs, at(64000)),
+            Duration::ZERO
+        );
+
+        // the consensus state and client state agree on the boundary: exactly
+        // one trusting period after the timestamp is the last valid instant
+        let boundary = (at(64000) - Duration::from_nanos(1)).unwrap();
+        for (now, expired) in [
+            (boundary, false),
+            (at(64000), false),
+            ((at(64000) + Duration::from_nanos(1)).unwrap(), true),
+        ] {
+            let elapsed = now.duration_since(t0).unwrap();
+            assert_eq!(client_state.expired(elapsed), expired, "{elapsed:?}");
+            assert_eq!(cs.is_expired(trusting_period, now), expired, "{now}");
+        }
+        assert_eq!(
+            client_state.time_until_expiry(&cs, at(100000)),
+            Duration::ZERO
+        );
+
+        // a consensus state from the future has more than the trusting period left
+        assert_eq!(
+            client_state.time_until_expiry(&consensus_state_at(at(10)), t0),
+            trusting_period + Duration::from_secs(10)
+        );
+
+        // near the largest representable time, `timestamp + trusting_period`
+        // overflows: the expiry saturates to the far future instead of erroring
+        let max = Time::from_unix_timestamp(253_402_300_799, 999_999_999).unwrap();
+        let near_max = (max - Duration::from_secs(10)).unwrap();
+        let cs = consensus_state_at(near_max);
+        assert!((near_max + trusting_period).is_err());
+        assert!(!cs.is_expired(trusting_period, near_max));
+        assert!(!cs.is_expired(trusting_period, max));
+        assert_eq!(
+            client_state.time_until_expiry(&cs, max),
+            trusting_period - Duration::from_secs(10)
+        );
+    }
+
+    #[test]
+    fn client_state_from_params() {
+        let params = ClientStateParams {
+            chain_id: ChainId::new("ibc".to_string(), 1),
+            trust_level: TrustThreshold::ONE_THIRD,
+            trusting_period: Duration::new(64000, 0),
+            unbonding_period: Duration::new(128000, 0),
+            max_clock_drift: Duration::new(3, 0),
+            latest_height: Height::new(1, 10).unwrap(),
+            proof_specs: vec![ics23::iavl_spec()],
+            upgrade_path: vec!["upgrade".to_string(), "upgradedIBCState".to_string()],
+            allow_update: AllowUpdate {
+                after_expiry: true,
+                after_misbehaviour: false,
+            },
+            frozen_height: Some(Height::new(1, 5).unwrap()),
+        };
+
+        let client_state = ClientState::from_params(params.clone()).unwrap();
+        assert_eq!(client_state.chain_id, params.chain_id);
+        assert_eq!(client_state.trusting_period, params.trusting_period);
+        assert_eq!(client_state.unbonding_period, params.unbonding_period);
+        assert_eq!(client_state.max_clock_drift, params.max_clock_drift);
+        assert_eq!(client_state.latest_height, params.latest_height);
+        assert_eq!(client_state.upgrade_path, params.upgrade_path);
+        assert_eq!(client_state.allow_update, params.allow_update);
+        assert_eq!(client_state.frozen_height, params.frozen_height);
+
+        // validation is the same as `ClientState::new`
+        let res = ClientState::from_params(ClientStateParams {
+            trusting_period: params.unbonding_period,
+            ..params
+        });
+        assert!(
+            matches!(res, Err(Error::InvalidTrustThreshold { .. })),
+            "{res:?}"
+        );
+    }
+
+    #[test]
+    fn client_state_encode_to() {
+        let client_state = ClientState::from_params(ClientStateParams {
+            chain_id: ChainId::new("ibc".to_string(), 1),
+            trust_level: TrustThreshold::ONE_THIRD,
+            trusting_period: Duration::new(64000, 0),
+            unbonding_period: Duration::new(128000, 0),
+            max_clock_drift: Duration::new(3, 0),
+            latest_height: Height::new(1, 10).unwrap(),
+            proof_specs: vec![ics23::iavl_spec()],
+            upgrade_path: vec!["upgrade".to_string(), "upgradedIBCState".to_string()],
+            allow_update: AllowUpdate {
+                after_expiry: false,
+                after_misbehaviour: false,
+            },
+            frozen_height: None,
+        })
+        .unwrap();
+        let expected = Protobuf::<RawTmClientState>::encode_vec(client_state.clone());
+
+        let mut buf = Vec::new();
+        client_state.encode_to(&mut buf);
+        assert_eq!(buf, expected);
+        assert_eq!(client_state.encoded_len_borrowed(), expected.len());
+
+        // encodings are appended to what is already in the buffer
+        client_state.encode_to(&mut buf);
+        assert_eq!(buf, [expected.clone(), expected].concat());
+
+        // optional and flag fields are encoded as in the owned proto type
+        let client_state = ClientState {
+            proof_specs: vec![ics23::iavl_spec(), ics23::tendermint_spec()],
+            upgrade_path: vec![],
+            allow_update: AllowUpdate {
+                after_expiry: true,
+                after_misbehaviour: true,
+            },
+            ..client_state
+        }
+        .with_frozen_height(Height::new(1, 5).unwrap());
+        let expected = Protobuf::<RawTmClientState>::encode_vec(client_state.clone());
+        let mut buf = Vec::new();
+        client_state.encode_to(&mut buf);
+        assert_eq!(buf, expected);
+        assert_eq!(client_state.encoded_len_borrowed(), expected.len());
+    }
+
     #[test]
     fn client_state_verify_height() {
         // Define a "default" set of parameters to reuse throughout these tests.
         let default_params: ClientStateParams = ClientStateParams {
-            id: ChainId::new("ibc".to_string(), 1),
+            chain_id: ChainId::new("ibc".to_string(), 1),
             trust_level: TrustThreshold::ONE_THIRD,
             trusting_period: Duration::new(64000, 0),
             unbonding_period: Duration::new(128000, 0),
@@ -733,6 +1288,7 @@ mod tests {
                 after_expiry: false,
                 after_misbehaviour: false,
             },
+            frozen_height: None,
         };
 
         struct Test {
@@ -768,7 +1324,7 @@ mod tests {
         for test in tests {
             let p = default_params.clone();
             let client_state = ClientState::new(
-                p.id,
+                p.chain_id,
                 p.trust_level,
                 p.trusting_period,
                 p.unbonding_period,
@@ -796,6 +1352,395 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn client_state_validate_proof_height() {
+        let client_state = ClientState::new(
+            ChainId::new("ibc".to_string(), 1),
+            TrustThreshold::ONE_THIRD,
+            Duration::new(64000, 0),
+            Duration::new(128000, 0),
+            Duration::new(3, 0),
+            Height::new(1, 10).unwrap(),
+            vec![ics23::iavl_spec()],
+            Default::default(),
+            AllowUpdate {
+                after_expiry: false,
+                after_misbehaviour: false,
+            },
+            None,
+        )
+        .unwrap();
+
+        assert!(client_state
+            .validate_proof_height(Height::new(1, 9).unwrap())
+            .is_ok());
+        assert!(client_state
+            .validate_proof_height(Height::new(1, 10).unwrap())
+            .is_ok());
+
+        let proof_height = Height::new(1, 11).unwrap();
+        match client_state.validate_proof_height(proof_height) {
+            Err(Error::InvalidProofHeight {
+                latest_height,
+                proof_height: err_proof_height,
+            }) => {
+                assert_eq!(latest_height, client_state.latest_height());
+                assert_eq!(err_proof_height, proof_height);
+            }
+            res => panic!("expected InvalidProofHeight, got {res:?}"),
+        }
+    }
+
+    #[test]
+    fn client_state_can_recover() {
+        let client_state = |after_expiry, after_misbehaviour| {
+            ClientState::new(
+                ChainId::new("ibc".to_string(), 0),
+                TrustThreshold::ONE_THIRD,
+                Duration::new(64000, 0),
+                Duration::new(128000, 0),
+                Duration::new(3, 0),
+                Height::new(0, 10).unwrap(),
+                vec![ics23::iavl_spec()],
+                Default::default(),
+                AllowUpdate {
+                    after_expiry,
+                    after_misbehaviour,
+                },
+                None,
+            )
+            .unwrap()
+        };
+
+        for (after_expiry, after_misbehaviour) in
+            [(false, false), (true, false), (false, true), (true, true)]
+        {
+            let client_state = client_state(after_expiry, after_misbehaviour);
+            assert_eq!(client_state.can_recover_after_expiry(), after_expiry);
+            assert_eq!(
+                client_state.can_recover_after_misbehaviour(),
+                after_misbehaviour
+            );
+
+            // expiry itself is independent of the update policy
+            assert!(!client_state.expired(Duration::new(64000, 0)));
+            assert!(client_state.expired(Duration::new(64001, 0)));
+        }
+    }
+
+    #[test]
+    fn client_state_is_update_noop() {
+        let consensus_state = |root: &[u8], secs| {
+            ConsensusState::new(
+                MerkleRoot {
+                    hash: root.to_vec(),
+                },
+                Time::from_unix_timestamp(secs, 0).unwrap(),
+                tendermint::Hash::None,
+            )
+        };
+
+        let stored = consensus_state(b"app_hash", 1_700_000_000);
+
+        // no consensus state at this height yet
+        assert!(!ClientState::is_update_noop(None, &stored));
+        // resubmitting the same consensus state
+        assert!(ClientState::is_update_noop(Some(&stored), &stored.clone()));
+        // conflicting root or timestamp at the same height
+        assert!(!ClientState::is_update_noop(
+            Some(&stored),
+            &consensus_state(b"other_hash", 1_700_000_000)
+        ));
+        assert!(!ClientState::is_update_noop(
+            Some(&stored),
+            &consensus_state(b"app_hash", 1_700_000_001)
+        ));
+    }
+
+    #[test]
+    fn client_state_verify_membership_at_path() {
+        use ibc_types_core_client::ClientId;
+        use ibc_types_core_commitment::{MerklePrefix, MerkleProof};
+        use ibc_types_path::ClientStatePath;
+        use ics23::{commitment_proof::Proof, CommitmentProof, ExistenceProof};
+
+        let exist = |key: &[u8], value: &[u8]| {
+            let proof = ExistenceProof {
+                key: key.to_vec(),
+                value: value.to_vec(),
+                leaf: ics23::tendermint_spec().leaf_spec,
+                path: vec![],
+            };
+            let root =
+                ics23::calculate_existence_root::<ics23::HostFunctionsManager>(&proof).unwrap();
+            (
+                CommitmentProof {
+                    proof: Some(Proof::Exist(proof)),
+                },
+                root,
+            )
+        };
+
+        let client_state = ClientState::new(
+            ChainId::new("ibc".to_string(), 0),
+            TrustThreshold::ONE_THIRD,
+            Duration::new(64000, 0),
+            Duration::new(128000, 0),
+            Duration::new(3, 0),
+            Height::new(0, 10).unwrap(),
+            vec![ics23::tendermint_spec(), ics23::tendermint_spec()],
+            Default::default(),
+            AllowUpdate {
+                after_expiry: false,
+                after_misbehaviour: false,
+            },
+            None,
+        )
+        .unwrap();
+
+        let path = Path::ClientState(ClientStatePath::new(&ClientId::default()));
+        let value = b"client state".to_vec();
+
+        // a store holding only the client state, committed to under `ibc`
+        let (store_proof, store_root) = exist(path.to_string().as_bytes(), &value);
+        let (app_proof, app_hash) = exist(b"ibc", &store_root);
+        let proof = MerkleProof {
+            proofs: vec![store_proof, app_proof],
+        };
+        let root = MerkleRoot { hash: app_hash };
+        let prefix = MerklePrefix::from(b"ibc".to_vec());
+
+        client_state
+            .verify_membership_at_path(&prefix, &proof, &root, path.clone(), value)
+            .unwrap();
+
+        assert!(matches!(
+            client_state.verify_membership_at_path(
+                &prefix,
+                &proof,
+                &root,
+                path,
+                b"other state".to_vec()
+            ),
+            Err(Error::MembershipVerification(_))
+        ));
+    }
+
+    #[test]
+    fn client_state_diff() {
+        let current = ClientState::new(
+            ChainId::new("ibc".to_string(), 0),
+            TrustThreshold::ONE_THIRD,
+            Duration::new(64000, 0),
+            Duration::new(128000, 0),
+            Duration::new(3, 0),
+            Height::new(0, 10).unwrap(),
+            vec![ics23::iavl_spec()],
+            Default::default(),
+            AllowUpdate {
+                after_expiry: false,
+                after_misbehaviour: false,
+            },
+            None,
+        )
+        .unwrap();
+
+        assert!(current.diff(&current).is_empty());
+
+        let proposed = ClientState {
+            trusting_period: Duration::new(32000, 0),
+            latest_height: Height::new(1, 1).unwrap(),
+            ..current.clone()
+        };
+
+        assert_eq!(
+            current.diff(&proposed),
+            vec![
+                FieldChange {
+                    field: "trusting_period",
+                    old: "64000s".to_string(),
+                    new: "32000s".to_string(),
+                },
+                FieldChange {
+                    field: "latest_height",
+                    old: "Height { revision: 0, height: 10 }".to_string(),
+                    new: "Height { revision: 1, height: 1 }".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn client_state_any_rejects_trailing_bytes() {
+        let client_state = ClientState::new(
+            ChainId::new("ibc".to_string(), 0),
+            TrustThreshold::ONE_THIRD,
+            Duration::new(64000, 0),
+            Duration::new(128000, 0),
+            Duration::new(3, 0),
+            Height::new(0, 10).unwrap(),
+            vec![ics23::iavl_spec()],
+            Default::default(),
+            AllowUpdate {
+                after_expiry: false,
+                after_misbehaviour: false,
+            },
+            None,
+        )
+        .unwrap();
+        let any = Any::from(client_state.clone());
+        assert_eq!(ClientState::try_from(any.clone()).unwrap(), client_state);
+
+        // trailing bytes that do not form a complete field
+        for junk in [&[0x00][..], &[0x0a, 0x05, b'x'], &[0xf8]] {
+            let mut value = any.value.clone();
+            value.extend_from_slice(junk);
+            let res = ClientState::try_from(Any {
+                value,
+                ..any.clone()
+            });
+            assert!(matches!(res, Err(Error::Decode(_))), "{junk:?}: {res:?}");
+        }
+
+        // a well-formed unknown field (number 127) is skipped, as in ibc-go
+        let mut value = any.value.clone();
+        value.extend_from_slice(&[0xf8, 0x07, 0x01]);
+        let res = ClientState::try_from(Any { value, ..any });
+        assert_eq!(res.unwrap(), client_state);
+    }
+
+    #[test]
+    fn client_state_verify_header() {
+        use crate::header::test_util::get_dummy_header;
+
+        let time = |secs: i64| Time::from_unix_timestamp(1_700_000_000 + secs, 0).unwrap();
+
+        let client_state = ClientState::new(
+            ChainId::new("ibc".to_string(), 0),
+            TrustThreshold::ONE_THIRD,
+            Duration::new(64000, 0),
+            Duration::new(128000, 0),
+            Duration::new(3, 0),
+            Height::new(0, 1).unwrap(),
+            vec![ics23::iavl_spec()],
+            Default::default(),
+            AllowUpdate {
+                after_expiry: false,
+                after_misbehaviour: false,
+            },
+            None,
+        )
+        .unwrap();
+        let trusted_consensus_state = ConsensusState::from(get_dummy_header("ibc-0", 1, time(0)));
+        let header = get_dummy_header("ibc-0", 2, time(10));
+
+        // a self-consistent header verifies
+        client_state
+            .verify_header(&trusted_consensus_state, &header, time(20).into())
+            .unwrap();
+
+        // the trusted state is outside the trusting period
+        assert!(client_state
+            .verify_header(&trusted_consensus_state, &header, time(64001).into())
+            .is_err());
+
+        // the header is from the future, beyond the allowed clock drift
+        assert!(client_state
+            .verify_header(&trusted_consensus_state, &header, time(5).into())
+            .is_err());
+
+        // the host time must be known
+        assert!(matches!(
+            client_state.verify_header(&trusted_consensus_state, &header, Timestamp::none()),
+            Err(Error::MissingHostTimestamp)
+        ));
+
+        // the trusted validator set must match the trusted consensus state
+        let mismatched_consensus_state = ConsensusState::new(
+            trusted_consensus_state.root.clone(),
+            trusted_consensus_state.timestamp,
+            tendermint::Hash::None,
+        );
+        assert!(matches!(
+            client_state.verify_header(&mismatched_consensus_state, &header, time(20).into()),
+            Err(Error::TrustedValidatorHashMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn client_state_verify_client_message() {
+        use crate::header::test_util::get_dummy_header;
+        use crate::misbehaviour::Misbehaviour;
+
+        let time = |secs: i64| Time::from_unix_timestamp(1_700_000_000 + secs, 0).unwrap();
+
+        let client_state = ClientState::new(
+            ChainId::new("ibc".to_string(), 0),
+            TrustThreshold::ONE_THIRD,
+            Duration::new(64000, 0),
+            Duration::new(128000, 0),
+            Duration::new(3, 0),
+            Height::new(0, 1).unwrap(),
+            vec![ics23::iavl_spec()],
+            Default::default(),
+            AllowUpdate {
+                after_expiry: false,
+                after_misbehaviour: false,
+            },
+            None,
+        )
+        .unwrap();
+        let client_id = ClientId::default();
+        let trusted_height = Height::new(0, 1).unwrap();
+        let trusted_consensus_state = ConsensusState::from(get_dummy_header("ibc-0", 1, time(0)));
+        let lookup = |height| (height == trusted_height).then(|| trusted_consensus_state.clone());
+
+        // headers are verified against the consensus state at their trusted height
+        let header = ClientMessage::from(get_dummy_header("ibc-0", 2, time(10)));
+        client_state
+            .verify_client_message(&client_id, &header, lookup, time(20).into())
+            .unwrap();
+        assert!(matches!(
+            client_state.verify_client_message(&client_id, &header, |_| None, time(20).into()),
+            Err(Error::ConsensusStateNotFound { height, .. }) if height == trusted_height
+        ));
+
+        // conflicting headers at the same height are misbehaviour
+        let misbehaviour = ClientMessage::from(Misbehaviour {
+            client_id: client_id.clone(),
+            header1: get_dummy_header("ibc-0", 5, time(10)),
+            header2: get_dummy_header("ibc-0", 5, time(15)),
+        });
+        client_state
+            .verify_client_message(&client_id, &misbehaviour, lookup, time(20).into())
+            .unwrap();
+        assert!(matches!(
+            client_state.verify_client_message(
+                &client_id,
+                &misbehaviour,
+                |_| Some(ConsensusState::new(
+                    trusted_consensus_state.root.clone(),
+                    trusted_consensus_state.timestamp,
+                    tendermint::Hash::None,
+                )),
+                time(20).into(),
+            ),
+            Err(Error::MisbehaviourTrustedValidatorHashMismatch { .. })
+        ));
+
+        // misbehaviour must be submitted to the client it is evidence against
+        let other_client_id = ClientId::new(crate::client_type(), 1).unwrap();
+        assert!(matches!(
+            client_state.verify_client_message(
+                &other_client_id,
+                &misbehaviour,
+                lookup,
+                time(20).into()
+            ),
+            Err(Error::MisbehaviourClientIdMismatch { .. })
+        ));
+    }
 }
 
 /*
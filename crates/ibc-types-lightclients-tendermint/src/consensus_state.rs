@@ -1,5 +1,7 @@
 use crate::prelude::*;
 
+use core::time::Duration;
+
 use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::lightclients::tendermint::v1::ConsensusState as RawConsensusState;
 use ibc_proto::Protobuf;
@@ -8,6 +10,7 @@ use tendermint::{hash::Algorithm, time::Time, Hash};
 use tendermint_proto::google::protobuf as tpb;
 
 use ibc_types_core_commitment::MerkleRoot;
+use ibc_types_timestamp::Timestamp;
 
 use crate::{error::Error, header::Header};
 
@@ -29,6 +32,32 @@ impl ConsensusState {
             next_validators_hash,
         }
     }
+
+    /// Returns the timestamp of the header this consensus state was built from.
+    pub fn timestamp(&self) -> Timestamp {
+        self.timestamp.into()
+    }
+
+    /// Returns the commitment root (app hash) of this consensus state.
+    pub fn root(&self) -> &MerkleRoot {
+        &self.root
+    }
+
+    /// Returns whether this consensus state has expired at `now`, given the
+    /// client's `trusting_period`.
+    ///
+    /// This applies the same rule as [`ClientState::expired`] to the time
+    /// elapsed since this state's timestamp: the state expires once strictly
+    /// more than `trusting_period` has passed. A state whose timestamp lies
+    /// after `now` has not expired.
+    ///
+    /// [`ClientState::expired`]: crate::client_state::ClientState::expired
+    pub fn is_expired(&self, trusting_period: Duration, now: Time) -> bool {
+        match now.duration_since(self.timestamp) {
+            Ok(elapsed) => elapsed > trusting_period,
+            Err(_) => false,
+        }
+    }
 }
 
 impl DomainType for ConsensusState {
@@ -105,9 +134,7 @@ impl TryFrom<Any> for ConsensusState {
         }
 
         match raw.type_url.as_str() {
-            TENDERMINT_CONSENSUS_STATE_TYPE_URL => {
-                decode_consensus_state(raw.value.deref()).map_err(Into::into)
-            }
+            TENDERMINT_CONSENSUS_STATE_TYPE_URL => decode_consensus_state(raw.value.deref()),
             _ => Err(Error::WrongTypeUrl { url: raw.type_url }),
         }
     }
@@ -140,6 +167,45 @@ impl From<Header> for ConsensusState {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ibc_types_domain_type::test_util::any_roundtrip;
+    use test_log::test;
+
+    #[test]
+    fn consensus_state_accessors() {
+        let time = Time::from_unix_timestamp(1_700_000_000, 42).unwrap();
+        let root = MerkleRoot::from_bytes(vec![7; 32]);
+        let consensus_state = ConsensusState::new(root.clone(), time, Hash::None);
+
+        assert_eq!(consensus_state.timestamp(), Timestamp::from(time));
+        assert_eq!(
+            consensus_state.timestamp().nanoseconds(),
+            1_700_000_000_000_000_042
+        );
+        assert_eq!(consensus_state.root(), &root);
+    }
+
+    #[test]
+    fn consensus_state_any_rejects_trailing_bytes() {
+        let time = Time::from_unix_timestamp(1_700_000_000, 0).unwrap();
+        let consensus_state = ConsensusState::new(
+            MerkleRoot::from_bytes(vec![7; 32]),
+            time,
+            Hash::Sha256([1; 32]),
+        );
+        any_roundtrip(consensus_state.clone());
+        let any = Any::from(consensus_state);
+
+        // trailing bytes that do not form a complete field
+        let mut value = any.value.clone();
+        value.extend_from_slice(&[0x12, 0x20, 0x07]);
+        let res = ConsensusState::try_from(Any { value, ..any });
+        assert!(matches!(res, Err(Error::Decode(_))), "{res:?}");
+    }
+}
+
 /*
 #[cfg(test)]
 #[cfg(feature = "serde")]
@@ -1,6 +1,7 @@
 use crate::prelude::*;
 
 use ibc_types_core_client::{ClientId, Height};
+use ibc_types_core_commitment::Error as CommitmentError;
 use ibc_types_core_connection::ChainId;
 use ibc_types_timestamp::{Timestamp, TimestampOverflowError};
 
@@ -33,12 +34,16 @@ pub enum Error {
     InvalidTrustThreshold { reason: String },
     /// failed to build Tendermint domain type trust threshold from fraction: `{numerator}`/`{denominator}`
     FailedTrustThresholdConversion { numerator: u64, denominator: u64 },
+    /// failed to parse trust threshold `{value}`, expected `<numerator>/<denominator>`
+    ParseTrustThreshold { value: String },
     /// invalid tendermint client state trust threshold error: `{0}`
     InvalidTendermintTrustThreshold(TendermintError),
     /// invalid client state max clock drift: `{reason}`
     InvalidMaxClockDrift { reason: String },
     /// invalid client state latest height: `{reason}`
     InvalidLatestHeight { reason: String },
+    /// invalid client state frozen height: `{reason}`
+    InvalidFrozenHeight { reason: String },
     /// missing signed header
     MissingSignedHeader,
     /// invalid header, failed basic validation: `{reason}`
@@ -93,16 +98,32 @@ pub enum Error {
     /// not enough trust because insufficient validators overlap: `{reason}`
     NotEnoughTrustedValsSigned { reason: VotingPowerTally },
     /// verification failed: `{detail}`
-    VerificationError { detail: LightClientErrorDetail },
+    VerificationError {
+        // Boxed, since the verifier's error detail is much larger than any
+        // other variant.
+        detail: Box<LightClientErrorDetail>,
+    },
     /// Processed time for the client `{client_id}` at height `{height}` not found
     ProcessedTimeNotFound { client_id: ClientId, height: Height },
     /// Processed height for the client `{client_id}` at height `{height}` not found
     ProcessedHeightNotFound { client_id: ClientId, height: Height },
+    /// Consensus state for the client `{client_id}` at height `{height}` not found
+    ConsensusStateNotFound { client_id: ClientId, height: Height },
+    /// misbehaviour for the client `{misbehaviour_client_id}` was submitted to the client `{client_id}`
+    MisbehaviourClientIdMismatch {
+        client_id: ClientId,
+        misbehaviour_client_id: ClientId,
+    },
     /// the height is insufficient: latest_height=`{latest_height}` target_height=`{target_height}`
     InsufficientHeight {
         latest_height: Height,
         target_height: Height,
     },
+    /// the proof height is beyond the latest client height: latest_height=`{latest_height}` proof_height=`{proof_height}`
+    InvalidProofHeight {
+        latest_height: Height,
+        proof_height: Height,
+    },
     /// the client is frozen: frozen_height=`{frozen_height}` target_height=`{target_height}`
     ClientFrozen {
         frozen_height: Height,
@@ -114,6 +135,13 @@ pub enum Error {
         next_validators_hash: Hash,
         trusted_val_hash: Hash,
     },
+    /// trusted validators do not hash to the trusted consensus state's next validators hash. Expected: `{next_validators_hash}`, got: `{trusted_val_hash}`
+    TrustedValidatorHashMismatch {
+        next_validators_hash: Hash,
+        trusted_val_hash: Hash,
+    },
+    /// the current host timestamp is not set
+    MissingHostTimestamp,
     /// current timestamp minus the latest consensus state timestamp is greater than or equal to the trusting period (`{duration_since_consensus_state:?}` >= `{trusting_period:?}`)
     ConsensusStateTimestampGteTrustingPeriod {
         duration_since_consensus_state: Duration,
@@ -123,6 +151,10 @@ pub enum Error {
     MisbehaviourHeadersBlockHashesEqual,
     /// headers are not at same height and are monotonically increasing
     MisbehaviourHeadersNotAtSameHeight,
+    /// header1 height `{height1}` is below header2 height `{height2}`
+    MisbehaviourHeaderHeightsOutOfOrder { height1: Height, height2: Height },
+    /// headers at heights `{height1}` and `{height2}` have monotonically increasing times, which is not misbehaviour
+    MisbehaviourHeadersTimeMonotonic { height1: Height, height2: Height },
     /// header chain-id `{header_chain_id}` does not match the light client's chain-id `{chain_id}`)
     MisbehaviourHeadersChainIdMismatch {
         header_chain_id: String,
@@ -141,24 +173,27 @@ pub enum Error {
         // XXX: tendermint_proto::google::protobuf::duration::DurationError is behind a private module
         reason: String,
     },
+    /// membership verification failed: `{0}`
+    MembershipVerification(CommitmentError),
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for Error {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for Error {
+    // tendermint and prost errors only implement `Error` with `std`
+    #[cfg(feature = "std")]
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match &self {
             Self::InvalidHeader { error: e, .. } => Some(e),
             Self::InvalidTendermintTrustThreshold(e) => Some(e),
             Self::InvalidRawHeader(e) => Some(e),
             Self::Decode(e) => Some(e),
             Self::TimestampOverflow(e) => Some(e),
+            Self::MembershipVerification(e) => Some(e),
             _ => None,
         }
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for VerificationError {}
+impl core::error::Error for VerificationError {}
 
 #[derive(Debug, Display)]
 pub enum VerificationError {
@@ -179,7 +214,9 @@ impl IntoResult<(), Error> for Verdict {
         match self {
             Verdict::Success => Ok(()),
             Verdict::NotEnoughTrust(reason) => Err(Error::NotEnoughTrustedValsSigned { reason }),
-            Verdict::Invalid(detail) => Err(Error::VerificationError { detail }),
+            Verdict::Invalid(detail) => Err(Error::VerificationError {
+                detail: Box::new(detail),
+            }),
         }
     }
 }
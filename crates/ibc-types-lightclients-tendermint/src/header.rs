@@ -9,12 +9,12 @@ use ibc_proto::Protobuf;
 use prost::Message;
 use tendermint::block::signed_header::SignedHeader;
 use tendermint::validator::Set as ValidatorSet;
-use tendermint_light_client_verifier::types::UntrustedBlockState;
+use tendermint_light_client_verifier::types::{TrustedBlockState, UntrustedBlockState};
 
 use ibc_types_core_client::Height;
 use ibc_types_core_connection::ChainId;
 
-use crate::Error;
+use crate::{ConsensusState, Error};
 
 pub const TENDERMINT_HEADER_TYPE_URL: &str = "/ibc.lightclients.tendermint.v1.Header";
 
@@ -35,12 +35,31 @@ impl core::fmt::Debug for Header {
 }
 
 impl Header {
+    /// The height of the signed header, with the revision number parsed from
+    /// its chain id.
+    ///
+    /// Panics if the signed header is at height 0. Headers decoded from their
+    /// proto type never are; use [`Header::try_height`] for headers built by
+    /// hand.
     pub fn height(&self) -> Height {
+        self.try_height()
+            .expect("malformed tendermint header domain type has an illegal height of 0")
+    }
+
+    /// The height of the signed header, with the revision number parsed from
+    /// its chain id, or an error if the signed header is at height 0.
+    pub fn try_height(&self) -> Result<Height, Error> {
+        let height = u64::from(self.signed_header.header.height);
         Height::new(
             ChainId::chain_version(self.signed_header.header.chain_id.as_str()),
-            u64::from(self.signed_header.header.height),
+            height,
         )
-        .expect("malformed tendermint header domain type has an illegal height of 0")
+        .map_err(|_| Error::InvalidHeaderHeight { height })
+    }
+
+    /// The height of the trusted header this header is verified against.
+    pub fn trusted_height(&self) -> Height {
+        self.trusted_height
     }
 
     pub fn compatible_with(&self, other_header: &Header) -> bool {
@@ -54,6 +73,28 @@ impl Header {
             next_validators: None,
         }
     }
+
+    /// The state trusted at this header's `trusted_height`, as described by
+    /// the `consensus_state` stored for that height and this header's
+    /// `trusted_validator_set`.
+    pub(crate) fn as_trusted_block_state<'a>(
+        &'a self,
+        consensus_state: &ConsensusState,
+        chain_id: &'a tendermint::chain::Id,
+    ) -> Result<TrustedBlockState<'a>, Error> {
+        let trusted_height = self.trusted_height.revision_height();
+        Ok(TrustedBlockState {
+            chain_id,
+            header_time: consensus_state.timestamp,
+            height: trusted_height
+                .try_into()
+                .map_err(|_| Error::InvalidHeaderHeight {
+                    height: trusted_height,
+                })?,
+            next_validators: &self.trusted_validator_set,
+            next_validators_hash: consensus_state.next_validators_hash,
+        })
+    }
 }
 
 pub fn headers_compatible(header: &SignedHeader, other: &SignedHeader) -> bool {
@@ -107,10 +148,11 @@ impl TryFrom<RawHeader> for Header {
                 .map_err(Error::InvalidRawHeader)?,
         };
 
-        if header.height().revision_number() != header.trusted_height.revision_number() {
+        let height = header.try_height()?;
+        if height.revision_number() != header.trusted_height.revision_number() {
             return Err(Error::MismatchedRevisions {
                 current_revision: header.trusted_height.revision_number(),
-                update_revision: header.height().revision_number(),
+                update_revision: height.revision_number(),
             });
         }
 
@@ -134,7 +176,7 @@ impl TryFrom<Any> for Header {
         use core::ops::Deref;
 
         match raw.type_url.as_str() {
-            TENDERMINT_HEADER_TYPE_URL => decode_header(raw.value.deref()).map_err(Into::into),
+            TENDERMINT_HEADER_TYPE_URL => decode_header(raw.value.deref()),
             _ => Err(Error::WrongTypeUrl { url: raw.type_url }),
         }
     }
@@ -166,6 +208,39 @@ impl From<Header> for RawHeader {
 
 #[cfg(any(test, feature = "mocks"))]
 pub mod test_util {
+    use alloc::string::ToString;
+
+    use tendermint::Time;
+    use tendermint_testgen::{light_block::TmLightBlock, Generator, LightBlock};
+
+    use ibc_types_core_client::Height;
+    use ibc_types_core_connection::ChainId;
+
+    use super::Header;
+
+    /// Returns a header for a block at `height` and `time` on `chain_id`,
+    /// signed by the default `tendermint-testgen` validators, which trusts
+    /// the block at height 1.
+    pub fn get_dummy_header(chain_id: &str, height: u64, time: Time) -> Header {
+        let TmLightBlock {
+            signed_header,
+            validators,
+            next_validators,
+            ..
+        } = LightBlock::new_default_with_time_and_chain_id(chain_id.to_string(), time, height)
+            .generate()
+            .expect("failed to generate light block");
+
+        Header {
+            signed_header,
+            validator_set: validators,
+            trusted_height: Height::new(ChainId::chain_version(chain_id), 1)
+                .expect("trusted height is not zero"),
+            // the default validator set never changes
+            trusted_validator_set: next_validators,
+        }
+    }
+
     // TODO: replace with tendermint-testgen?
 
     /*
@@ -249,3 +324,53 @@ pub mod test_util {
     }
      */
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    use tendermint::Time;
+
+    use crate::header::test_util::get_dummy_header;
+
+    #[test]
+    fn header_heights() {
+        let time = Time::from_unix_timestamp(1_700_000_000, 0).unwrap();
+
+        let header = get_dummy_header("ibc-0", 5, time);
+        assert_eq!(header.height(), Height::new(0, 5).unwrap());
+        assert_eq!(
+            header.height().revision_height(),
+            header.signed_header.header.height.value()
+        );
+        assert_eq!(header.trusted_height(), Height::new(0, 1).unwrap());
+
+        // the revision number is taken from the chain id
+        let header = get_dummy_header("ibc-2", 7, time);
+        assert_eq!(header.height(), Height::new(2, 7).unwrap());
+        assert_eq!(
+            header.height().revision_height(),
+            header.signed_header.header.height.value()
+        );
+        assert_eq!(header.trusted_height(), Height::new(2, 1).unwrap());
+    }
+
+    #[test]
+    fn header_at_height_zero_is_rejected() {
+        let time = Time::from_unix_timestamp(1_700_000_000, 0).unwrap();
+        let mut header = get_dummy_header("ibc-0", 5, time);
+        header.signed_header.header.height = 0u32.into();
+        header.signed_header.commit.height = 0u32.into();
+
+        assert!(matches!(
+            header.try_height(),
+            Err(Error::InvalidHeaderHeight { height: 0 })
+        ));
+        let res = Header::try_from(RawHeader::from(header));
+        assert!(
+            matches!(res, Err(Error::InvalidHeaderHeight { height: 0 })),
+            "{res:?}"
+        );
+    }
+}
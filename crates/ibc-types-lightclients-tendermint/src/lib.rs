@@ -1,12 +1,12 @@
 //! ICS 07: Tendermint Client implements a client verification algorithm for blockchains which use
 //! the Tendermint consensus algorithm.
-
-// Requires nightly.
+#![no_std]
+// Only enabled when building docs (e.g. on docs.rs), which requires nightly.
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 
 extern crate alloc;
-
-use alloc::string::ToString;
+#[cfg(any(test, feature = "std"))]
+extern crate std;
 
 use ibc_types_core_client::ClientType;
 
@@ -18,15 +18,17 @@ pub use trust_threshold::TrustThreshold;
 mod error;
 pub use error::{Error, VerificationError};
 
+pub mod client_message;
 pub mod client_state;
 pub mod consensus_state;
 pub mod header;
 pub mod misbehaviour;
 
+pub use client_message::ClientMessage;
 pub use consensus_state::ConsensusState;
 
-pub const TENDERMINT_CLIENT_TYPE: &str = "07-tendermint";
+pub const TENDERMINT_CLIENT_TYPE: &str = ClientType::TENDERMINT;
 
 pub fn client_type() -> ClientType {
-    ClientType::new(TENDERMINT_CLIENT_TYPE.to_string())
+    ClientType::tendermint()
 }
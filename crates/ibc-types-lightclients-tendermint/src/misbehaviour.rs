@@ -5,9 +5,11 @@ use ibc_proto::google::protobuf::Any;
 use ibc_proto::ibc::lightclients::tendermint::v1::Misbehaviour as RawMisbehaviour;
 use ibc_proto::Protobuf;
 use prost::Message;
-use tendermint_light_client_verifier::ProdVerifier;
+use tendermint::Time;
+use tendermint_light_client_verifier::{ProdVerifier, Verifier};
 
-use crate::{error::IntoResult, header::Header, Error};
+use crate::client_state::ClientState;
+use crate::{error::IntoResult, header::Header, ConsensusState, Error};
 use ibc_types_core_client::ClientId;
 use ibc_types_core_connection::ChainId;
 
@@ -28,12 +30,12 @@ impl Misbehaviour {
             });
         }
 
-        if header1.height() < header2.height() {
+        let (height1, height2) = (header1.try_height()?, header2.try_height()?);
+        if height1 < height2 {
             return Err(Error::InvalidRawMisbehaviour {
                 reason: format!(
                     "headers1 height is less than header2 height ({} < {})",
-                    header1.height(),
-                    header2.height()
+                    height1, height2
                 ),
             });
         }
@@ -72,16 +74,118 @@ impl Misbehaviour {
         &self.header2
     }
 
+    /// Returns `true` if both headers are for the chain `chain_id`.
     pub fn chain_id_matches(&self, chain_id: &ChainId) -> bool {
-        assert_eq!(
-            self.header1.signed_header.header.chain_id, self.header2.signed_header.header.chain_id,
-            "this is enforced by the ctor"
-        );
+        [&self.header1, &self.header2]
+            .iter()
+            .all(|header| header.signed_header.header.chain_id.as_str() == chain_id.as_str())
+    }
+
+    /// Checks that the two headers are evidence of misbehaviour, i.e. that
+    /// they either are distinct headers at the same height (a fork), or
+    /// violate BFT time monotonicity: since `header1` is at a greater height
+    /// than `header2`, its time must not be after `header2`'s.
+    ///
+    /// `header1` must not be below `header2`; [`Misbehaviour::new`] enforces
+    /// this, but the fields are public, so it is checked here as well.
+    pub fn check_for_misbehaviour(&self) -> Result<(), Error> {
+        let header1 = &self.header1.signed_header;
+        let header2 = &self.header2.signed_header;
+        let (height1, height2) = (self.header1.try_height()?, self.header2.try_height()?);
+
+        if height1 < height2 {
+            return Err(Error::MisbehaviourHeaderHeightsOutOfOrder { height1, height2 });
+        }
+
+        if height1 == height2 {
+            // the same header submitted twice is evidence of nothing
+            if header1.commit.block_id.hash == header2.commit.block_id.hash {
+                return Err(Error::MisbehaviourHeadersBlockHashesEqual);
+            }
+        } else if header1.header.time > header2.header.time {
+            return Err(Error::MisbehaviourHeadersTimeMonotonic { height1, height2 });
+        }
 
-        self.header1.signed_header.header.chain_id.as_str() == chain_id.as_str()
+        Ok(())
+    }
+
+    /// Verifies this misbehaviour for a client with the given `client_state`,
+    /// at the host time `now`.
+    ///
+    /// Each header is verified against the consensus state the client trusts
+    /// at that header's `trusted_height`, that is `trusted_consensus_state_1`
+    /// for `header1` and `trusted_consensus_state_2` for `header2`. Finally,
+    /// the headers must actually constitute misbehaviour, see
+    /// [`Misbehaviour::check_for_misbehaviour`].
+    pub fn verify_misbehaviour(
+        &self,
+        client_state: &ClientState,
+        trusted_consensus_state_1: &ConsensusState,
+        trusted_consensus_state_2: &ConsensusState,
+        now: Time,
+    ) -> Result<(), Error> {
+        for header in [&self.header1, &self.header2] {
+            let header_chain_id = &header.signed_header.header.chain_id;
+            if header_chain_id.as_str() != client_state.chain_id.as_str() {
+                return Err(Error::MisbehaviourHeadersChainIdMismatch {
+                    header_chain_id: header_chain_id.to_string(),
+                    chain_id: client_state.chain_id.to_string(),
+                });
+            }
+        }
+
+        verify_misbehaviour_header(client_state, &self.header1, trusted_consensus_state_1, now)?;
+        verify_misbehaviour_header(client_state, &self.header2, trusted_consensus_state_2, now)?;
+
+        self.check_for_misbehaviour()
     }
 }
 
+/// Verifies a single header of a [`Misbehaviour`] against the consensus state
+/// trusted at its `trusted_height`.
+fn verify_misbehaviour_header(
+    client_state: &ClientState,
+    header: &Header,
+    trusted_consensus_state: &ConsensusState,
+    now: Time,
+) -> Result<(), Error> {
+    // ensure correctness of the trusted next validator set provided by the relayer
+    let trusted_val_hash = header.trusted_validator_set.hash();
+    if trusted_val_hash != trusted_consensus_state.next_validators_hash {
+        return Err(Error::MisbehaviourTrustedValidatorHashMismatch {
+            trusted_validator_set: header.trusted_validator_set.validators().clone(),
+            next_validators_hash: trusted_consensus_state.next_validators_hash,
+            trusted_val_hash,
+        });
+    }
+
+    // ensure the trusted consensus state is within the trusting period
+    if let Ok(duration_since_consensus_state) =
+        now.duration_since(trusted_consensus_state.timestamp)
+    {
+        if duration_since_consensus_state >= client_state.trusting_period {
+            return Err(Error::ConsensusStateTimestampGteTrustingPeriod {
+                duration_since_consensus_state,
+                trusting_period: client_state.trusting_period,
+            });
+        }
+    }
+
+    // main header verification, delegated to the tendermint light client verifier
+    let chain_id = client_state.tendermint_chain_id()?;
+    let trusted_state = header.as_trusted_block_state(trusted_consensus_state, &chain_id)?;
+    let options = client_state.as_light_client_options()?;
+
+    ProdVerifier::default()
+        .verify_misbehaviour_header(
+            header.as_untrusted_block_state(),
+            trusted_state,
+            &options,
+            now,
+        )
+        .into_result()
+}
+
 impl Protobuf<RawMisbehaviour> for Misbehaviour {}
 
 impl TryFrom<RawMisbehaviour> for Misbehaviour {
@@ -138,9 +242,7 @@ impl TryFrom<Any> for Misbehaviour {
         }
 
         match raw.type_url.as_str() {
-            TENDERMINT_MISBEHAVIOUR_TYPE_URL => {
-                decode_misbehaviour(raw.value.deref()).map_err(Into::into)
-            }
+            TENDERMINT_MISBEHAVIOUR_TYPE_URL => decode_misbehaviour(raw.value.deref()),
             _ => Err(Error::WrongTypeUrl { url: raw.type_url }),
         }
     }
@@ -174,3 +276,178 @@ impl core::fmt::Display for Misbehaviour {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::time::Duration;
+    use test_log::test;
+
+    use ibc_types_core_client::Height;
+    use ibc_types_core_commitment::MerkleRoot;
+
+    use crate::client_state::AllowUpdate;
+    use crate::header::test_util::get_dummy_header;
+    use crate::TrustThreshold;
+
+    const CHAIN_ID: &str = "ibc-0";
+
+    fn time(secs: i64) -> Time {
+        Time::from_unix_timestamp(1_700_000_000 + secs, 0).unwrap()
+    }
+
+    fn header(height: u64, secs: i64) -> Header {
+        get_dummy_header(CHAIN_ID, height, time(secs))
+    }
+
+    // `Misbehaviour::new` verifies the commits, which we don't need here.
+    fn misbehaviour(header1: Header, header2: Header) -> Misbehaviour {
+        Misbehaviour {
+            client_id: ClientId::default(),
+            header1,
+            header2,
+        }
+    }
+
+    fn client_state() -> ClientState {
+        ClientState::new(
+            ChainId::from_string(CHAIN_ID),
+            TrustThreshold::ONE_THIRD,
+            Duration::from_secs(64000),
+            Duration::from_secs(128000),
+            Duration::from_secs(3),
+            Height::new(0, 10).unwrap(),
+            vec![ics23::iavl_spec()],
+            Default::default(),
+            AllowUpdate {
+                after_expiry: false,
+                after_misbehaviour: false,
+            },
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn two_headers_at_same_height() {
+        // conflicting headers at the same height are a fork
+        let fork = misbehaviour(header(10, 100), header(10, 200));
+        assert_ne!(
+            fork.header1.signed_header.commit.block_id.hash,
+            fork.header2.signed_header.commit.block_id.hash
+        );
+        fork.check_for_misbehaviour().unwrap();
+
+        // the same header submitted twice is not misbehaviour
+        let duplicate = misbehaviour(header(10, 100), header(10, 100));
+        assert!(matches!(
+            duplicate.check_for_misbehaviour(),
+            Err(Error::MisbehaviourHeadersBlockHashesEqual)
+        ));
+    }
+
+    #[test]
+    fn monotonic_time_violation() {
+        // the higher header is not after the lower one: BFT time violation
+        misbehaviour(header(10, 100), header(5, 200))
+            .check_for_misbehaviour()
+            .unwrap();
+        misbehaviour(header(10, 100), header(5, 100))
+            .check_for_misbehaviour()
+            .unwrap();
+
+        // time increases with height, which is valid behaviour
+        assert!(matches!(
+            misbehaviour(header(10, 200), header(5, 100)).check_for_misbehaviour(),
+            Err(Error::MisbehaviourHeadersTimeMonotonic { height1, height2 })
+                if height1.revision_height() == 10 && height2.revision_height() == 5
+        ));
+    }
+
+    #[test]
+    fn header_heights_out_of_order() {
+        // `header1` below `header2` is rejected rather than read backwards
+        assert!(matches!(
+            misbehaviour(header(5, 200), header(10, 100)).check_for_misbehaviour(),
+            Err(Error::MisbehaviourHeaderHeightsOutOfOrder { height1, height2 })
+                if height1.revision_height() == 5 && height2.revision_height() == 10
+        ));
+    }
+
+    #[test]
+    fn verify_misbehaviour_checks_trusted_consensus_state() {
+        let client_state = client_state();
+        let misbehaviour = misbehaviour(header(10, 100), header(10, 200));
+        let trusted_header = header(1, 0);
+        let trusted_consensus_state = ConsensusState::new(
+            MerkleRoot::from_bytes(vec![1; 32]),
+            trusted_header.signed_header.header.time,
+            trusted_header.signed_header.header.next_validators_hash,
+        );
+
+        // the trusted validator set must hash to the trusted next validators hash
+        let wrong_consensus_state = ConsensusState {
+            next_validators_hash: tendermint::Hash::None,
+            ..trusted_consensus_state.clone()
+        };
+        assert!(matches!(
+            misbehaviour.verify_misbehaviour(
+                &client_state,
+                &wrong_consensus_state,
+                &trusted_consensus_state,
+                time(300),
+            ),
+            Err(Error::MisbehaviourTrustedValidatorHashMismatch { .. })
+        ));
+
+        // the trusted consensus state must be within the trusting period
+        assert!(matches!(
+            misbehaviour.verify_misbehaviour(
+                &client_state,
+                &trusted_consensus_state,
+                &trusted_consensus_state,
+                time(64000),
+            ),
+            Err(Error::ConsensusStateTimestampGteTrustingPeriod { .. })
+        ));
+
+        // the headers must be for the client's chain
+        let other_chain = ClientState {
+            chain_id: ChainId::from_string("other-0"),
+            ..client_state
+        };
+        assert!(matches!(
+            misbehaviour.verify_misbehaviour(
+                &other_chain,
+                &trusted_consensus_state,
+                &trusted_consensus_state,
+                time(300),
+            ),
+            Err(Error::MisbehaviourHeadersChainIdMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn headers_for_different_chains() {
+        let client_state = client_state();
+        let mixed = misbehaviour(header(10, 100), get_dummy_header("other-0", 10, time(200)));
+        assert!(!mixed.chain_id_matches(&client_state.chain_id));
+
+        let trusted_header = header(1, 0);
+        let trusted_consensus_state = ConsensusState::new(
+            MerkleRoot::from_bytes(vec![1; 32]),
+            trusted_header.signed_header.header.time,
+            trusted_header.signed_header.header.next_validators_hash,
+        );
+        assert!(matches!(
+            mixed.verify_misbehaviour(
+                &client_state,
+                &trusted_consensus_state,
+                &trusted_consensus_state,
+                time(300),
+            ),
+            Err(Error::MisbehaviourHeadersChainIdMismatch { header_chain_id, .. })
+                if header_chain_id == "other-0"
+        ));
+    }
+}
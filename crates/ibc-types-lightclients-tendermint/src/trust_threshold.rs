@@ -3,14 +3,17 @@
 //! range `[0, 1)`.
 
 use core::{
+    cmp::Ordering,
     convert::TryFrom,
     fmt::{Display, Error as FmtError, Formatter},
+    str::FromStr,
 };
 
 use ibc_proto::{ibc::lightclients::tendermint::v1::Fraction, Protobuf};
 use tendermint::trust_threshold::TrustThresholdFraction;
 
 use crate::error::Error;
+use crate::prelude::*;
 
 /// Defines the level of trust that a client has towards a set of validators of a chain.
 ///
@@ -19,6 +22,10 @@ use crate::error::Error;
 /// A typical trust threshold is 1/3 in practice.
 /// This type accepts even a value of 0, (numerator = 0, denominator = 0),
 /// which is used in the client state of an upgrading client.
+///
+/// Equality is structural, so `2/6` is not equal to `1/3`, matching their
+/// encodings. Use [`TrustThreshold::same_ratio`] and
+/// [`TrustThreshold::cmp_ratio`] to compare the values of the fractions.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TrustThreshold {
@@ -77,6 +84,41 @@ impl TrustThreshold {
     pub fn denominator(&self) -> u64 {
         self.denominator
     }
+
+    /// The value of this trust threshold as a floating point number, e.g. for
+    /// display purposes. Use [`TrustThreshold::cmp_ratio`] to compare trust
+    /// thresholds exactly.
+    pub fn as_f64(&self) -> f64 {
+        if self.denominator == 0 {
+            0.0
+        } else {
+            self.numerator as f64 / self.denominator as f64
+        }
+    }
+
+    /// The fraction widened so that cross-multiplication cannot overflow, with
+    /// the zero threshold `0/0` normalized to `0/1`.
+    fn widened(&self) -> (u128, u128) {
+        if self.denominator == 0 {
+            (0, 1)
+        } else {
+            (self.numerator.into(), self.denominator.into())
+        }
+    }
+
+    /// Compares the values of two trust thresholds by cross-multiplication,
+    /// so e.g. `1/3` is less than `2/3`, and `2/6` is equal to `1/3`.
+    pub fn cmp_ratio(&self, other: &Self) -> Ordering {
+        let (n1, d1) = self.widened();
+        let (n2, d2) = other.widened();
+        (n1 * d2).cmp(&(n2 * d1))
+    }
+
+    /// Whether two trust thresholds have the same value, e.g. `2/6` and
+    /// `1/3`, or `0/0` and any other zero threshold.
+    pub fn same_ratio(&self, other: &Self) -> bool {
+        self.cmp_ratio(other) == Ordering::Equal
+    }
 }
 
 /// Conversion from Tendermint domain type into
@@ -133,3 +175,104 @@ impl Display for TrustThreshold {
         write!(f, "{}/{}", self.numerator, self.denominator)
     }
 }
+
+/// Parses a trust threshold from its `numerator/denominator` [`Display`] form,
+/// e.g. `"1/3"`. Unlike [`TrustThreshold::new`], the zero threshold is
+/// rejected, as it is never a meaningful configuration value.
+impl FromStr for TrustThreshold {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_err = || Error::ParseTrustThreshold {
+            value: s.to_string(),
+        };
+
+        let (numerator, denominator) = s.split_once('/').ok_or_else(parse_err)?;
+        let numerator = numerator.trim().parse().map_err(|_| parse_err())?;
+        let denominator = denominator.trim().parse().map_err(|_| parse_err())?;
+
+        if numerator == 0 {
+            return Err(Error::FailedTrustThresholdConversion {
+                numerator,
+                denominator,
+            });
+        }
+
+        Self::new(numerator, denominator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    #[test]
+    fn trust_threshold_cmp_ratio() {
+        let one_third = TrustThreshold::ONE_THIRD;
+        let two_thirds = TrustThreshold::TWO_THIRDS;
+        assert_eq!(one_third.cmp_ratio(&two_thirds), Ordering::Less);
+        assert_eq!(two_thirds.cmp_ratio(&one_third), Ordering::Greater);
+        assert_eq!(TrustThreshold::ZERO.cmp_ratio(&one_third), Ordering::Less);
+
+        let two_sixths = TrustThreshold::new(2, 6).unwrap();
+        assert!(two_sixths.same_ratio(&one_third));
+        assert_ne!(two_sixths, one_third);
+        assert!(TrustThreshold::new(0, 3)
+            .unwrap()
+            .same_ratio(&TrustThreshold::ZERO));
+
+        // large values compare without overflowing
+        let almost_one = TrustThreshold::new(u64::MAX - 1, u64::MAX).unwrap();
+        assert_eq!(almost_one.cmp_ratio(&two_thirds), Ordering::Greater);
+    }
+
+    #[test]
+    fn trust_threshold_as_f64() {
+        assert_eq!(TrustThreshold::ZERO.as_f64(), 0.0);
+        assert_eq!(TrustThreshold::new(1, 2).unwrap().as_f64(), 0.5);
+        assert!((TrustThreshold::ONE_THIRD.as_f64() - 1.0 / 3.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn trust_threshold_display() {
+        assert_eq!(TrustThreshold::ONE_THIRD.to_string(), "1/3");
+        assert_eq!(TrustThreshold::TWO_THIRDS.to_string(), "2/3");
+    }
+
+    #[test]
+    fn trust_threshold_from_str() {
+        for s in ["1/3", "2/3"] {
+            let trust_threshold: TrustThreshold = s.parse().unwrap();
+            assert_eq!(trust_threshold.to_string(), s);
+        }
+        assert_eq!(
+            "1/3".parse::<TrustThreshold>().unwrap(),
+            TrustThreshold::ONE_THIRD
+        );
+        assert_eq!(
+            "2/3".parse::<TrustThreshold>().unwrap(),
+            TrustThreshold::TWO_THIRDS
+        );
+
+        for s in ["2/1", "1/0", "0/3", "0/0", "1/1"] {
+            assert!(
+                matches!(
+                    s.parse::<TrustThreshold>(),
+                    Err(Error::FailedTrustThresholdConversion { .. })
+                ),
+                "{s}"
+            );
+        }
+
+        for s in ["", "1", "1/", "/3", "a/3", "1/3/4", "-1/3"] {
+            assert!(
+                matches!(
+                    s.parse::<TrustThreshold>(),
+                    Err(Error::ParseTrustThreshold { .. })
+                ),
+                "{s}"
+            );
+        }
+    }
+}
@@ -1,4 +1,10 @@
+#![no_std]
+// Only enabled when building docs (e.g. on docs.rs), which requires nightly.
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+
 extern crate alloc;
+#[cfg(any(test, feature = "std"))]
+extern crate std;
 
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
@@ -13,6 +19,7 @@ use core::str::FromStr;
 use ibc_types_core_channel::{packet::Sequence, ChannelId, PortId};
 use ibc_types_core_client::ClientId;
 use ibc_types_core_connection::ConnectionId;
+use ibc_types_identifier::IdentifierError;
 
 use derive_more::{Display, From};
 
@@ -112,6 +119,12 @@ impl ConnectionPath {
 #[display(fmt = "ports/{_0}")]
 pub struct PortPath(pub PortId);
 
+impl PortPath {
+    pub fn new(port_id: &PortId) -> PortPath {
+        PortPath(port_id.clone())
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 #[display(fmt = "channelEnds/ports/{_0}/channels/{_1}")]
 pub struct ChannelEndPath(pub PortId, pub ChannelId);
@@ -122,6 +135,16 @@ impl ChannelEndPath {
     }
 }
 
+/// Parses a `(port_id, channel_id)` pair, validating both identifiers.
+impl TryFrom<(&str, &str)> for ChannelEndPath {
+    type Error = PathError;
+
+    fn try_from(ids: (&str, &str)) -> Result<Self, Self::Error> {
+        let (port_id, channel_id) = parse_port_and_channel(ids)?;
+        Ok(ChannelEndPath(port_id, channel_id))
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 #[display(fmt = "nextSequenceSend/ports/{_0}/channels/{_1}")]
 pub struct SeqSendPath(pub PortId, pub ChannelId);
@@ -132,6 +155,16 @@ impl SeqSendPath {
     }
 }
 
+/// Parses a `(port_id, channel_id)` pair, validating both identifiers.
+impl TryFrom<(&str, &str)> for SeqSendPath {
+    type Error = PathError;
+
+    fn try_from(ids: (&str, &str)) -> Result<Self, Self::Error> {
+        let (port_id, channel_id) = parse_port_and_channel(ids)?;
+        Ok(SeqSendPath(port_id, channel_id))
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 #[display(fmt = "nextSequenceRecv/ports/{_0}/channels/{_1}")]
 pub struct SeqRecvPath(pub PortId, pub ChannelId);
@@ -142,6 +175,16 @@ impl SeqRecvPath {
     }
 }
 
+/// Parses a `(port_id, channel_id)` pair, validating both identifiers.
+impl TryFrom<(&str, &str)> for SeqRecvPath {
+    type Error = PathError;
+
+    fn try_from(ids: (&str, &str)) -> Result<Self, Self::Error> {
+        let (port_id, channel_id) = parse_port_and_channel(ids)?;
+        Ok(SeqRecvPath(port_id, channel_id))
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 #[display(fmt = "nextSequenceAck/ports/{_0}/channels/{_1}")]
 pub struct SeqAckPath(pub PortId, pub ChannelId);
@@ -152,6 +195,16 @@ impl SeqAckPath {
     }
 }
 
+/// Parses a `(port_id, channel_id)` pair, validating both identifiers.
+impl TryFrom<(&str, &str)> for SeqAckPath {
+    type Error = PathError;
+
+    fn try_from(ids: (&str, &str)) -> Result<Self, Self::Error> {
+        let (port_id, channel_id) = parse_port_and_channel(ids)?;
+        Ok(SeqAckPath(port_id, channel_id))
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
 #[display(fmt = "commitments/ports/{port_id}/channels/{channel_id}/sequences/{sequence}")]
 pub struct CommitmentPath {
@@ -233,16 +286,73 @@ impl Path {
     pub fn into_bytes(self) -> Vec<u8> {
         self.to_string().into_bytes()
     }
+
+    /// The path of the client state stored for `client_id`.
+    pub fn client_state(client_id: &ClientId) -> Path {
+        Path::ClientState(ClientStatePath::new(client_id))
+    }
+
+    /// The path of the connection end stored for `connection_id`.
+    pub fn connection(connection_id: &ConnectionId) -> Path {
+        Path::Connection(ConnectionPath::new(connection_id))
+    }
+
+    /// The path of the next sequence to send on a channel.
+    pub fn seq_send(port_id: &PortId, channel_id: &ChannelId) -> Path {
+        Path::SeqSend(SeqSendPath::new(port_id, channel_id))
+    }
+
+    /// The path of the next sequence to receive on a channel.
+    pub fn seq_recv(port_id: &PortId, channel_id: &ChannelId) -> Path {
+        Path::SeqRecv(SeqRecvPath::new(port_id, channel_id))
+    }
+
+    /// The path of the next sequence to acknowledge on a channel.
+    pub fn seq_ack(port_id: &PortId, channel_id: &ChannelId) -> Path {
+        Path::SeqAck(SeqAckPath::new(port_id, channel_id))
+    }
+
+    /// The path of the commitment to the packet sent with `sequence`.
+    pub fn commitment(port_id: &PortId, channel_id: &ChannelId, sequence: Sequence) -> Path {
+        Path::Commitment(CommitmentPath::new(port_id, channel_id, sequence))
+    }
+
+    /// The path of the acknowledgement written for the packet received with `sequence`.
+    pub fn ack(port_id: &PortId, channel_id: &ChannelId, sequence: Sequence) -> Path {
+        Path::Ack(AckPath::new(port_id, channel_id, sequence))
+    }
+
+    /// The path of the receipt written for the packet received with `sequence`.
+    pub fn receipt(port_id: &PortId, channel_id: &ChannelId, sequence: Sequence) -> Path {
+        Path::Receipt(ReceiptPath::new(port_id, channel_id, sequence))
+    }
 }
 
 #[derive(Debug, displaydoc::Display)]
 pub enum PathError {
     /// `{path}` could not be parsed into a Path
     ParseFailure { path: String },
+    /// invalid identifier: `{0}`
+    InvalidIdentifier(IdentifierError),
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for PathError {}
+fn parse_port_and_channel(
+    (port_id, channel_id): (&str, &str),
+) -> Result<(PortId, ChannelId), PathError> {
+    Ok((
+        port_id.parse().map_err(PathError::InvalidIdentifier)?,
+        channel_id.parse().map_err(PathError::InvalidIdentifier)?,
+    ))
+}
+
+impl core::error::Error for PathError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match &self {
+            PathError::InvalidIdentifier(e) => Some(e),
+            _ => None,
+        }
+    }
+}
 
 /// The FromStr trait allows paths encoded as strings to be parsed into Paths.
 impl FromStr for Path {
@@ -266,6 +376,14 @@ impl FromStr for Path {
     }
 }
 
+impl TryFrom<&str> for Path {
+    type Error = PathError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
 fn parse_client_paths(components: &[&str]) -> Option<Path> {
     let first = match components.first() {
         Some(f) => *f,
@@ -276,9 +394,9 @@ fn parse_client_paths(components: &[&str]) -> Option<Path> {
         return None;
     }
 
-    let client_id = match ClientId::from_str(components[1]) {
-        Ok(s) => s,
-        Err(_) => return None,
+    let client_id = match components.get(1).map(|c| ClientId::from_str(c)) {
+        Some(Ok(s)) => s,
+        _ => return None,
     };
 
     if components.len() == 3 {
@@ -675,6 +793,7 @@ fn parse_upgrades(components: &[&str]) -> Option<Path> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::vec;
     use core::str::FromStr;
 
     #[test]
@@ -1083,4 +1202,165 @@ mod tests {
             Path::Upgrade(ClientUpgradePath::UpgradedClientConsensusState(0)),
         );
     }
+
+    #[test]
+    fn merkle_path_from_path() {
+        use ibc_types_core_commitment::{MerklePath, MerklePrefix};
+
+        let prefix = MerklePrefix::from(b"ibc".to_vec());
+
+        let path = Path::ClientState(ClientStatePath(ClientId::default()));
+        assert_eq!(
+            MerklePath::apply_prefix(&prefix, path).key_path,
+            vec!["ibc", "clients/07-tendermint-0/clientState"]
+        );
+
+        let path = CommitmentPath::new(
+            &PortId::transfer(),
+            &ChannelId::default(),
+            Sequence::from(1),
+        );
+        assert_eq!(
+            MerklePath::apply_prefix(&prefix, path).key_path,
+            vec![
+                "ibc",
+                "commitments/ports/transfer/channels/channel-0/sequences/1"
+            ]
+        );
+    }
+
+    #[test]
+    fn typed_paths_render_expected_keys() {
+        let port_id = PortId::transfer();
+        let channel_id = ChannelId::new(3);
+
+        assert_eq!(
+            ChannelEndPath::new(&port_id, &channel_id).to_string(),
+            "channelEnds/ports/transfer/channels/channel-3"
+        );
+        assert_eq!(
+            SeqSendPath::new(&port_id, &channel_id).to_string(),
+            "nextSequenceSend/ports/transfer/channels/channel-3"
+        );
+        assert_eq!(
+            SeqRecvPath::new(&port_id, &channel_id).to_string(),
+            "nextSequenceRecv/ports/transfer/channels/channel-3"
+        );
+        assert_eq!(
+            SeqAckPath::new(&port_id, &channel_id).to_string(),
+            "nextSequenceAck/ports/transfer/channels/channel-3"
+        );
+        assert_eq!(PortPath::new(&port_id).to_string(), "ports/transfer");
+    }
+
+    #[test]
+    fn paths_try_from_str_ids() {
+        assert_eq!(
+            ChannelEndPath::try_from(("transfer", "channel-3")).unwrap(),
+            ChannelEndPath::new(&PortId::transfer(), &ChannelId::new(3))
+        );
+        assert_eq!(
+            SeqAckPath::try_from(("transfer", "channel-3"))
+                .unwrap()
+                .to_string(),
+            "nextSequenceAck/ports/transfer/channels/channel-3"
+        );
+
+        // identifiers that would corrupt the store key are rejected
+        for (port_id, channel_id) in [
+            ("transfer/evil", "channel-3"),
+            ("transfer", "channel-3/sequences"),
+            ("", "channel-3"),
+            ("transfer", "chan"),
+        ] {
+            assert!(matches!(
+                SeqSendPath::try_from((port_id, channel_id)),
+                Err(PathError::InvalidIdentifier(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn all_path_kinds_round_trip() {
+        for key in [
+            "clients/07-tendermint-0/clientType",
+            "clients/07-tendermint-0/clientState",
+            "clients/07-tendermint-0/consensusStates/15-31",
+            "clients/07-tendermint-0/connections",
+            "connections/connection-0",
+            "ports/transfer",
+            "channelEnds/ports/transfer/channels/channel-0",
+            "nextSequenceSend/ports/transfer/channels/channel-0",
+            "nextSequenceRecv/ports/transfer/channels/channel-0",
+            "nextSequenceAck/ports/transfer/channels/channel-0",
+            "commitments/ports/transfer/channels/channel-0/sequences/1",
+            "acks/ports/transfer/channels/channel-0/sequences/1",
+            "receipts/ports/transfer/channels/channel-0/sequences/1",
+            "upgradedIBCState/42/upgradedClient",
+            "upgradedIBCState/42/upgradedConsState",
+        ] {
+            let path = Path::from_str(key).unwrap();
+            assert_eq!(path.to_string(), key);
+            assert_eq!(Path::try_from(key).unwrap(), path);
+        }
+
+        for key in ["", "clients", "channelEnds/ports/transfer", "unknown/key"] {
+            assert!(matches!(
+                Path::try_from(key),
+                Err(PathError::ParseFailure { path }) if path == key
+            ));
+        }
+    }
+
+    #[test]
+    fn path_constructors_match_sdk_keys() {
+        let port_id = PortId::transfer();
+        let channel_id = ChannelId::new(0);
+        let sequence = Sequence::from(1);
+
+        assert_eq!(
+            Path::commitment(&port_id, &channel_id, sequence).to_string(),
+            "commitments/ports/transfer/channels/channel-0/sequences/1"
+        );
+        assert_eq!(
+            Path::ack(&port_id, &channel_id, sequence).to_string(),
+            "acks/ports/transfer/channels/channel-0/sequences/1"
+        );
+        assert_eq!(
+            Path::receipt(&port_id, &channel_id, sequence).to_string(),
+            "receipts/ports/transfer/channels/channel-0/sequences/1"
+        );
+        assert_eq!(
+            Path::client_state(&ClientId::from_str("07-tendermint-0").unwrap()).to_string(),
+            "clients/07-tendermint-0/clientState"
+        );
+        assert_eq!(
+            Path::connection(&ConnectionId::new(0)).to_string(),
+            "connections/connection-0"
+        );
+    }
+
+    #[test]
+    fn next_sequence_paths_match_sdk_keys() {
+        let port_id = PortId::transfer();
+        let channel_id = ChannelId::new(0);
+
+        for (path, key) in [
+            (
+                Path::seq_send(&port_id, &channel_id),
+                "nextSequenceSend/ports/transfer/channels/channel-0",
+            ),
+            (
+                Path::seq_recv(&port_id, &channel_id),
+                "nextSequenceRecv/ports/transfer/channels/channel-0",
+            ),
+            (
+                Path::seq_ack(&port_id, &channel_id),
+                "nextSequenceAck/ports/transfer/channels/channel-0",
+            ),
+        ] {
+            assert_eq!(path.to_string(), key);
+            assert_eq!(Path::from_str(key).unwrap(), path);
+        }
+    }
 }
@@ -1,6 +1,6 @@
 //! A timestamp type for IBC.
 #![no_std]
-// Requires nightly.
+// Only enabled when building docs (e.g. on docs.rs), which requires nightly.
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 
 extern crate alloc;
@@ -10,6 +10,7 @@ extern crate std;
 mod prelude;
 use prelude::*;
 
+use core::cmp::Ordering;
 use core::fmt::{Display, Error as FmtError, Formatter};
 use core::hash::{Hash, Hasher};
 use core::num::ParseIntError;
@@ -23,6 +24,8 @@ use time::OffsetDateTime;
 
 pub const ZERO_DURATION: Duration = Duration::from_secs(0);
 
+const NANOS_PER_SEC: u64 = 1_000_000_000;
+
 /// A newtype wrapper over `Option<Time>` to keep track of
 /// IBC packet timeout.
 ///
@@ -46,6 +49,22 @@ impl Hash for Timestamp {
     }
 }
 
+/// Timestamps are ordered by their nanosecond value, so for two set
+/// timestamps `a > b` exactly when `a.after(&b)`. An unset timestamp orders
+/// before every set one, whereas [`Timestamp::after`] and
+/// [`Timestamp::check_expiry`] treat comparisons with it as invalid.
+impl PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Timestamp {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.time.cmp(&other.time)
+    }
+}
+
 /// The expiry result when comparing two timestamps.
 /// - If either timestamp is invalid (0), the result is `InvalidTimestamp`.
 /// - If the left timestamp is strictly after the right timestamp, the result is `Expired`.
@@ -61,6 +80,19 @@ pub enum Expiry {
     InvalidTimestamp,
 }
 
+impl Expiry {
+    /// Returns `true` if the result is [`Expiry::Expired`].
+    pub fn is_expired(&self) -> bool {
+        matches!(self, Expiry::Expired)
+    }
+
+    /// Returns `true` if the result is [`Expiry::NotExpired`]. Note that an
+    /// [`Expiry::InvalidTimestamp`] is neither expired nor not expired.
+    pub fn is_not_expired(&self) -> bool {
+        matches!(self, Expiry::NotExpired)
+    }
+}
+
 impl Timestamp {
     /// The IBC protocol represents timestamps as u64 Unix
     /// timestamps in nanoseconds.
@@ -91,21 +123,42 @@ impl Timestamp {
     }
 
     /// Returns a `Timestamp` representation of a timestamp not being set.
-    pub fn none() -> Self {
+    ///
+    /// This is a `const fn`, so an unset timestamp can be used in `const`
+    /// contexts. Set timestamps cannot: [`tendermint::Time`] has no `const`
+    /// constructor.
+    pub const fn none() -> Self {
         Timestamp { time: None }
     }
 
-    /// Computes the duration difference of another `Timestamp` from the current one.
+    /// Computes the duration difference of an `earlier` `Timestamp` from the current one.
     /// Returns the difference in time as an [`core::time::Duration`].
-    /// Returns `None` if the other `Timestamp` is more advanced
+    /// Returns `None` if the `earlier` `Timestamp` is more advanced
     /// than the current or if either of the `Timestamp`s is not set.
-    pub fn duration_since(&self, other: &Timestamp) -> Option<Duration> {
-        match (self.time, other.time) {
+    pub fn duration_since(&self, earlier: &Timestamp) -> Option<Duration> {
+        match (self.time, earlier.time) {
             (Some(time1), Some(time2)) => time1.duration_since(time2).ok(),
             _ => None,
         }
     }
 
+    /// Subtracts `duration` from the current `Timestamp`, returning `None` if
+    /// the result would precede the Unix epoch, which a `Timestamp` cannot
+    /// represent in nanoseconds. As with [`Sub`], an unset `Timestamp` is
+    /// returned unchanged.
+    pub fn checked_sub(&self, duration: Duration) -> Option<Timestamp> {
+        match self.time {
+            Some(time) => {
+                let time = (time - duration).ok()?;
+                if time < Time::unix_epoch() {
+                    return None;
+                }
+                Some(Timestamp { time: Some(time) })
+            }
+            None => Some(*self),
+        }
+    }
+
     /// Convert a `Timestamp` to `u64` value in nanoseconds. If no timestamp
     /// is set, the result is 0.
     ///
@@ -139,6 +192,31 @@ impl Timestamp {
         })
     }
 
+    /// Whole seconds since the Unix epoch, truncating any sub-second part.
+    /// If no timestamp is set, the result is 0.
+    pub fn unix_secs(self) -> u64 {
+        self.nanoseconds() / NANOS_PER_SEC
+    }
+
+    /// Nanoseconds past the last whole second since the Unix epoch, so that
+    /// `unix_secs() * 1_000_000_000 + subsec_nanos()` equals
+    /// [`Timestamp::nanoseconds`]. If no timestamp is set, the result is 0.
+    pub fn subsec_nanos(self) -> u32 {
+        (self.nanoseconds() % NANOS_PER_SEC) as u32
+    }
+
+    /// Encodes this timestamp as its big-endian number of nanoseconds since
+    /// the Unix epoch, where zero means the timestamp is not set.
+    pub fn to_bytes(self) -> [u8; 8] {
+        self.nanoseconds().to_be_bytes()
+    }
+
+    /// Decodes a timestamp from its encoding; the inverse of
+    /// [`Timestamp::to_bytes`].
+    pub fn from_bytes(bytes: [u8; 8]) -> Result<Timestamp, ParseTimestampError> {
+        Timestamp::from_nanoseconds(u64::from_be_bytes(bytes))
+    }
+
     /// Convert a `Timestamp` to an optional [`OffsetDateTime`]
     pub fn into_datetime(self) -> Option<OffsetDateTime> {
         self.time.map(Into::into)
@@ -149,6 +227,12 @@ impl Timestamp {
         self.time
     }
 
+    /// Creates a `Timestamp` from a [`tendermint::Time`], without
+    /// round-tripping through nanoseconds.
+    pub fn from_tendermint_time(time: Time) -> Timestamp {
+        time.into()
+    }
+
     /// Checks whether the timestamp has expired when compared to the
     /// `other` timestamp. Returns an [`Expiry`] result.
     pub fn check_expiry(&self, other: &Timestamp) -> Expiry {
@@ -193,8 +277,7 @@ pub enum TimestampOverflowError {
     TimestampOverflow,
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for TimestampOverflowError {}
+impl core::error::Error for TimestampOverflowError {}
 
 impl Add<Duration> for Timestamp {
     type Output = Result<Timestamp, TimestampOverflowError>;
@@ -232,9 +315,8 @@ pub enum ParseTimestampError {
     ParseInt(ParseIntError),
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for ParseTimestampError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+impl core::error::Error for ParseTimestampError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match &self {
             ParseTimestampError::ParseInt(e) => Some(e),
         }
@@ -263,11 +345,12 @@ impl From<Time> for Timestamp {
 mod tests {
     use time::OffsetDateTime;
 
+    use core::cmp::Ordering;
     use core::time::Duration;
     use std::thread::sleep;
     use test_log::test;
 
-    use super::{Expiry, Timestamp, ZERO_DURATION};
+    use super::{Expiry, Time, Timestamp, ZERO_DURATION};
 
     #[test]
     fn test_timestamp_comparisons() {
@@ -306,6 +389,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_timestamp_ordering() {
+        let nanos = [1, 2, 999_999_999, 1_000_000_000, 1_700_000_000_123_456_789];
+
+        for a in nanos {
+            for b in nanos {
+                let ta = Timestamp::from_nanoseconds(a).unwrap();
+                let tb = Timestamp::from_nanoseconds(b).unwrap();
+                assert_eq!(ta.cmp(&tb), a.cmp(&b), "{a} vs {b}");
+                assert_eq!(ta > tb, ta.after(&tb), "{a} vs {b}");
+                assert_eq!(
+                    ta > tb,
+                    ta.check_expiry(&tb) == Expiry::Expired,
+                    "{a} vs {b}"
+                );
+            }
+        }
+
+        let timestamp = Timestamp::from_nanoseconds(42).unwrap();
+        assert_eq!(
+            timestamp.cmp(&Timestamp::from_nanoseconds(42).unwrap()),
+            Ordering::Equal
+        );
+
+        // an unset timestamp orders first
+        assert!(Timestamp::none() < timestamp);
+        assert_eq!(Timestamp::none().cmp(&Timestamp::none()), Ordering::Equal);
+
+        let timestamps = nanos.map(|n| Timestamp::from_nanoseconds(n).unwrap());
+        assert_eq!(
+            timestamps.iter().max(),
+            Some(&Timestamp::from_nanoseconds(1_700_000_000_123_456_789).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_const_none() {
+        const NO_TIMEOUT: Timestamp = Timestamp::none();
+
+        assert_eq!(NO_TIMEOUT, Timestamp::from_nanoseconds(0).unwrap());
+        assert_eq!(NO_TIMEOUT.nanoseconds(), 0);
+    }
+
+    #[test]
+    fn test_expiry_predicates() {
+        for (expiry, is_expired, is_not_expired) in [
+            (Expiry::Expired, true, false),
+            (Expiry::NotExpired, false, true),
+            (Expiry::InvalidTimestamp, false, false),
+        ] {
+            assert_eq!(expiry.is_expired(), is_expired, "{expiry:?}");
+            assert_eq!(expiry.is_not_expired(), is_not_expired, "{expiry:?}");
+        }
+    }
+
     #[test]
     fn test_timestamp_arithmetic() {
         let time0 = Timestamp::none();
@@ -321,6 +459,98 @@ mod tests {
         assert_eq!(time0, (time0 - duration).unwrap());
     }
 
+    #[test]
+    fn tendermint_time_round_trip() {
+        let times = [
+            Time::unix_epoch(),
+            Time::from_unix_timestamp(1, 1).unwrap(),
+            Time::from_unix_timestamp(1_700_000_000, 123_456_789).unwrap(),
+            Time::from_unix_timestamp(4_102_444_800, 999_999_999).unwrap(),
+        ];
+
+        for time in times {
+            let timestamp = Timestamp::from_tendermint_time(time);
+            assert_eq!(timestamp.into_tm_time(), Some(time));
+            let dt: OffsetDateTime = time.into();
+            assert_eq!(
+                i128::from(timestamp.nanoseconds()),
+                dt.unix_timestamp_nanos()
+            );
+        }
+
+        assert_eq!(Timestamp::none().into_tm_time(), None);
+    }
+
+    #[test]
+    fn test_unix_secs_and_subsec_nanos() {
+        let timestamp = Timestamp::from_nanoseconds(1_700_000_000_123_456_789).unwrap();
+        assert_eq!(timestamp.unix_secs(), 1_700_000_000);
+        assert_eq!(timestamp.subsec_nanos(), 123_456_789);
+        let dt = timestamp.into_datetime().unwrap();
+        assert_eq!(timestamp.unix_secs() as i64, dt.unix_timestamp());
+        assert_eq!(timestamp.subsec_nanos(), dt.nanosecond());
+
+        let whole = Timestamp::from_nanoseconds(2_000_000_000).unwrap();
+        assert_eq!(whole.unix_secs(), 2);
+        assert_eq!(whole.subsec_nanos(), 0);
+
+        assert_eq!(Timestamp::none().unix_secs(), 0);
+        assert_eq!(Timestamp::none().subsec_nanos(), 0);
+    }
+
+    #[test]
+    fn test_timestamp_bytes_round_trip() {
+        assert_eq!(Timestamp::none().to_bytes(), [0u8; 8]);
+        assert_eq!(Timestamp::from_bytes([0u8; 8]).unwrap(), Timestamp::none());
+
+        let timestamp = Timestamp::from_nanoseconds(0x0102_0304_0506_0708).unwrap();
+        assert_eq!(
+            timestamp.to_bytes(),
+            [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]
+        );
+
+        for nanoseconds in [1, 1_700_000_000_000_000_000, u64::MAX] {
+            let timestamp = Timestamp::from_nanoseconds(nanoseconds).unwrap();
+            assert_eq!(
+                Timestamp::from_bytes(timestamp.to_bytes()).unwrap(),
+                timestamp
+            );
+        }
+    }
+
+    #[test]
+    fn test_duration_since_and_checked_sub() {
+        let earlier = Timestamp::from_nanoseconds(1_000_000_000).unwrap();
+        let later = Timestamp::from_nanoseconds(1_500_000_000).unwrap();
+
+        assert_eq!(
+            later.duration_since(&earlier),
+            Some(Duration::from_millis(500))
+        );
+        assert_eq!(earlier.duration_since(&earlier), Some(ZERO_DURATION));
+        // reversed order
+        assert_eq!(earlier.duration_since(&later), None);
+        // unset timestamps
+        assert_eq!(Timestamp::none().duration_since(&earlier), None);
+        assert_eq!(later.duration_since(&Timestamp::none()), None);
+
+        assert_eq!(later.checked_sub(Duration::from_millis(500)), Some(earlier));
+        assert_eq!(
+            earlier.checked_sub(Duration::from_secs(1)),
+            Some(Timestamp::from_tendermint_time(Time::unix_epoch()))
+        );
+        // underflow past the epoch
+        assert_eq!(
+            earlier.checked_sub(Duration::from_nanos(1_000_000_001)),
+            None
+        );
+        assert_eq!(earlier.checked_sub(Duration::MAX), None);
+        assert_eq!(
+            Timestamp::none().checked_sub(Duration::from_secs(1)),
+            Some(Timestamp::none())
+        );
+    }
+
     #[test]
     fn subtract_compare() {
         let sleep_duration = Duration::from_micros(100);
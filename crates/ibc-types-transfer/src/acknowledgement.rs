@@ -59,11 +59,10 @@ impl From<TokenTransferAcknowledgement> for Vec<u8> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "with_serde"))]
 mod test {
     use super::*;
 
-    #[cfg(feature = "with_serde")]
     #[test]
     fn test_ack_ser() {
         use crate::alloc::borrow::ToOwned;
@@ -84,7 +83,6 @@ mod test {
         );
     }
 
-    #[cfg(feature = "with_serde")]
     #[test]
     fn test_ack_success_to_vec() {
         let ack_success: Vec<u8> = TokenTransferAcknowledgement::success().into();
@@ -95,7 +93,6 @@ mod test {
         assert_eq!(ack_success, r#"{"result":"AQ=="}"#.as_bytes());
     }
 
-    #[cfg(feature = "with_serde")]
     #[test]
     fn test_ack_error_to_vec() {
         use crate::alloc::string::ToString;
@@ -113,7 +110,6 @@ mod test {
         );
     }
 
-    #[cfg(feature = "with_serde")]
     #[test]
     fn test_ack_de() {
         use crate::alloc::borrow::ToOwned;
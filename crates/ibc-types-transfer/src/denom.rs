@@ -0,0 +1,185 @@
+//! ICS-20 denomination traces and the `ibc/{hash}` voucher denoms derived
+//! from them.
+
+use core::fmt::{Display, Error as FmtError, Formatter};
+use core::str::FromStr;
+
+use displaydoc::Display;
+use sha2::{Digest, Sha256};
+use subtle_encoding::hex;
+
+use crate::prelude::*;
+
+const CHANNEL_ID_PREFIX: &str = "channel-";
+
+/// An error while parsing a [`DenomTrace`].
+#[derive(Debug, Display)]
+pub enum Error {
+    /// empty denomination
+    EmptyDenom,
+    /// denomination `{denom}` has an empty base denomination
+    EmptyBaseDenom { denom: String },
+}
+
+impl core::error::Error for Error {}
+
+/// The trace of a token denomination: the `{port}/{channel}` hops it was
+/// transferred over, and the denomination on the chain it originated from.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "with_serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DenomTrace {
+    /// The `{port}/{channel}` hops the token was transferred over, separated
+    /// by `/`, or empty for a native token.
+    pub path: String,
+    /// The denomination of the token on its chain of origin.
+    pub base_denom: String,
+}
+
+impl DenomTrace {
+    /// Returns the full denomination, i.e. `{path}/{base_denom}`, or just the
+    /// base denomination if the path is empty.
+    pub fn full_path(&self) -> String {
+        if self.path.is_empty() {
+            self.base_denom.clone()
+        } else {
+            alloc::format!("{}/{}", self.path, self.base_denom)
+        }
+    }
+
+    /// Returns the SHA-256 hash of the [full path](Self::full_path).
+    pub fn hash(&self) -> [u8; 32] {
+        Sha256::digest(self.full_path().as_bytes()).into()
+    }
+
+    /// Returns the denomination of this token on the chain holding it.
+    ///
+    /// As in ibc-go, this is `ibc/` followed by the uppercase hex
+    /// [`hash`](Self::hash) of the full path, or just the base denomination
+    /// for a native token.
+    pub fn ibc_denom(&self) -> String {
+        if self.path.is_empty() {
+            return self.base_denom.clone();
+        }
+        let hash = String::from_utf8(hex::encode_upper(self.hash()))
+            .expect("hex encoding is always valid UTF-8");
+        alloc::format!("ibc/{hash}")
+    }
+}
+
+/// Parses a full denomination such as `transfer/channel-0/uatom`.
+///
+/// As in ibc-go, leading `{port}/{channel}` pairs are taken as the path for as
+/// long as the channel segment is a valid channel identifier, and the rest is
+/// the base denomination, which may itself contain `/` (e.g. `gamm/pool/1`).
+/// A denomination with only two segments is always a base denomination.
+impl FromStr for DenomTrace {
+    type Err = Error;
+
+    fn from_str(denom: &str) -> Result<Self, Self::Err> {
+        if denom.is_empty() {
+            return Err(Error::EmptyDenom);
+        }
+
+        let segments: Vec<&str> = denom.split('/').collect();
+        let mut path_len = 0;
+        if segments.len() > 2 {
+            while path_len + 1 < segments.len() && is_channel_id(segments[path_len + 1]) {
+                path_len += 2;
+            }
+        }
+
+        let base_denom = segments[path_len..].join("/");
+        if base_denom.is_empty() {
+            return Err(Error::EmptyBaseDenom {
+                denom: denom.into(),
+            });
+        }
+
+        Ok(Self {
+            path: segments[..path_len].join("/"),
+            base_denom,
+        })
+    }
+}
+
+impl Display for DenomTrace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), FmtError> {
+        if self.path.is_empty() {
+            write!(f, "{}", self.base_denom)
+        } else {
+            write!(f, "{}/{}", self.path, self.base_denom)
+        }
+    }
+}
+
+fn is_channel_id(s: &str) -> bool {
+    s.strip_prefix(CHANNEL_ID_PREFIX)
+        .and_then(|seq| seq.parse::<u64>().ok())
+        .is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use test_log::test;
+
+    #[test]
+    fn parse_denom_trace() {
+        let tests = [
+            ("uatom", "", "uatom"),
+            ("transfer/channel-0/uatom", "transfer/channel-0", "uatom"),
+            (
+                "transfer/channel-0/transfer/channel-1/uatom",
+                "transfer/channel-0/transfer/channel-1",
+                "uatom",
+            ),
+            (
+                "transfer/channel-0/gamm/pool/1",
+                "transfer/channel-0",
+                "gamm/pool/1",
+            ),
+            ("gamm/pool/1", "", "gamm/pool/1"),
+            ("transfer/channel-0", "", "transfer/channel-0"),
+            ("transfer/channelToA/uatom", "", "transfer/channelToA/uatom"),
+        ];
+
+        for (denom, path, base_denom) in tests {
+            let trace: DenomTrace = denom.parse().unwrap();
+            assert_eq!(trace.path, path, "{denom}");
+            assert_eq!(trace.base_denom, base_denom, "{denom}");
+            assert_eq!(trace.to_string(), denom);
+            assert_eq!(trace.full_path(), denom);
+        }
+
+        assert!(matches!("".parse::<DenomTrace>(), Err(Error::EmptyDenom)));
+        assert!(matches!(
+            "transfer/channel-0/".parse::<DenomTrace>(),
+            Err(Error::EmptyBaseDenom { .. })
+        ));
+    }
+
+    #[test]
+    fn ibc_denom() {
+        let tests = [
+            (
+                "transfer/channel-0/uatom",
+                "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2",
+            ),
+            (
+                "transfer/channel-141/uosmo",
+                "ibc/14F9BC3E44B8A9C1BE1FB08980FAB87034C9905EF17CF2F5008FC085218811CC",
+            ),
+            (
+                "transfer/channel-0/transfer/channel-1/uatom",
+                "ibc/D219F3A490310B65BDC312B5A644B0D56FFF1789D894B902A49FBF9D2F560B32",
+            ),
+            ("uatom", "uatom"),
+        ];
+
+        for (denom, ibc_denom) in tests {
+            let trace: DenomTrace = denom.parse().unwrap();
+            assert_eq!(trace.ibc_denom(), ibc_denom, "{denom}");
+        }
+    }
+}
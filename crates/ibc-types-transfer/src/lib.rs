@@ -1,6 +1,6 @@
 //! IBC transfer types.
 #![no_std]
-// Requires nightly.
+// Only enabled when building docs (e.g. on docs.rs), which requires nightly.
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 
 extern crate alloc;
@@ -8,6 +8,7 @@ extern crate alloc;
 extern crate std;
 
 mod prelude;
-use prelude::*;
 
 pub mod acknowledgement;
+pub mod denom;
+pub mod packet;
@@ -0,0 +1,243 @@
+//! The ICS-20 fungible token transfer packet payload.
+
+use displaydoc::Display;
+use ibc_proto::ibc::applications::transfer::v2::FungibleTokenPacketData as RawFungibleTokenPacketData;
+use ibc_types_domain_type::DomainType;
+
+use crate::prelude::*;
+
+/// An error while converting or parsing [`FungibleTokenPacketData`].
+#[derive(Debug, Display)]
+pub enum Error {
+    /// missing token denomination
+    MissingDenom,
+    /// invalid token amount `{amount}`: must be a positive integer
+    InvalidAmount { amount: String },
+    /// missing sender address
+    MissingSender,
+    /// missing receiver address
+    MissingReceiver,
+    /// invalid ICS-20 packet data JSON: {0}
+    #[cfg(feature = "with_serde")]
+    InvalidJson(serde_json::Error),
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match &self {
+            #[cfg(feature = "with_serde")]
+            Self::InvalidJson(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// The payload of an ICS-20 fungible token transfer packet.
+///
+/// On the wire, this is carried in the packet data as JSON with sorted keys,
+/// rather than as protobuf; see [`FungibleTokenPacketData::from_packet_data`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "with_serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FungibleTokenPacketData {
+    // Fields are declared in lexicographic order, so that serializing them
+    // matches ibc-go's sorted JSON encoding.
+    /// The amount of tokens to transfer, as a decimal integer string.
+    pub amount: String,
+    /// The denomination of the tokens, including any trace prefix.
+    pub denom: String,
+    /// An optional memo, omitted from the JSON encoding when empty.
+    #[cfg_attr(
+        feature = "with_serde",
+        serde(default, skip_serializing_if = "String::is_empty")
+    )]
+    pub memo: String,
+    /// The recipient address on the destination chain.
+    pub receiver: String,
+    /// The sender address on the source chain.
+    pub sender: String,
+}
+
+impl FungibleTokenPacketData {
+    /// Checks that the denomination and addresses are set, and that the
+    /// amount is a positive integer.
+    pub fn validate_basic(&self) -> Result<(), Error> {
+        if self.denom.trim().is_empty() {
+            return Err(Error::MissingDenom);
+        }
+        if self.amount.is_empty()
+            || !self.amount.bytes().all(|b| b.is_ascii_digit())
+            || self.amount.bytes().all(|b| b == b'0')
+        {
+            return Err(Error::InvalidAmount {
+                amount: self.amount.clone(),
+            });
+        }
+        if self.sender.trim().is_empty() {
+            return Err(Error::MissingSender);
+        }
+        if self.receiver.trim().is_empty() {
+            return Err(Error::MissingReceiver);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "with_serde")]
+impl FungibleTokenPacketData {
+    /// Parses the JSON-encoded data of an ICS-20 packet.
+    pub fn from_packet_data(data: &[u8]) -> Result<Self, Error> {
+        let packet_data: Self = serde_json::from_slice(data).map_err(Error::InvalidJson)?;
+        packet_data.validate_basic()?;
+        Ok(packet_data)
+    }
+
+    /// Encodes this payload as ICS-20 packet data, i.e. as JSON with sorted
+    /// keys.
+    pub fn to_packet_data(&self) -> Vec<u8> {
+        serde_json::to_vec(self).expect("packet data always serializes to JSON")
+    }
+}
+
+impl DomainType for FungibleTokenPacketData {
+    type Proto = RawFungibleTokenPacketData;
+}
+
+impl TryFrom<RawFungibleTokenPacketData> for FungibleTokenPacketData {
+    type Error = anyhow::Error;
+
+    fn try_from(raw: RawFungibleTokenPacketData) -> Result<Self, Self::Error> {
+        let packet_data = Self {
+            amount: raw.amount,
+            denom: raw.denom,
+            memo: raw.memo,
+            receiver: raw.receiver,
+            sender: raw.sender,
+        };
+        packet_data.validate_basic().map_err(anyhow::Error::msg)?;
+        Ok(packet_data)
+    }
+}
+
+impl From<FungibleTokenPacketData> for RawFungibleTokenPacketData {
+    fn from(value: FungibleTokenPacketData) -> Self {
+        Self {
+            denom: value.denom,
+            amount: value.amount,
+            sender: value.sender,
+            receiver: value.receiver,
+            memo: value.memo,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ibc_types_domain_type::test_util::domain_type_roundtrip;
+    use test_log::test;
+
+    fn osmosis_to_penumbra() -> FungibleTokenPacketData {
+        FungibleTokenPacketData {
+            amount: "1000000".into(),
+            denom: "uosmo".into(),
+            memo: String::new(),
+            receiver: "penumbra1xfp5p0qjsfh0wqsvvaay0yerth8lfsar2ysyxd2n09s29rvgvapz8wswpuhcvhaudqh4c5wqs2z8tvpv4fmasenfwmdyxqfwwhxf3kqfkhw3l77k3ts0x7ux59ejhnc8yx4ap7".into(),
+            sender: "osmo1qnk2n4nlkpw9xfqntladh74w6ujtulwnmxnh3k".into(),
+        }
+    }
+
+    #[test]
+    fn domain_type_round_trip() {
+        domain_type_roundtrip(osmosis_to_penumbra());
+    }
+
+    #[test]
+    fn decode_many_length_delimited() {
+        use prost::Message;
+
+        let packets = [
+            osmosis_to_penumbra(),
+            FungibleTokenPacketData {
+                amount: "1".into(),
+                ..osmosis_to_penumbra()
+            },
+            FungibleTokenPacketData {
+                memo: "hello".into(),
+                ..osmosis_to_penumbra()
+            },
+        ];
+        let mut buf = Vec::new();
+        for packet in &packets {
+            packet.to_proto().encode_length_delimited(&mut buf).unwrap();
+        }
+
+        let decoded = FungibleTokenPacketData::decode_many(buf.as_slice())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(decoded, packets);
+
+        // a truncated final message yields one error, then nothing more
+        let mut decoded = FungibleTokenPacketData::decode_many(&buf[..buf.len() - 1]);
+        assert_eq!(decoded.next().unwrap().unwrap(), packets[0]);
+        assert_eq!(decoded.next().unwrap().unwrap(), packets[1]);
+        assert!(decoded.next().unwrap().is_err());
+        assert!(decoded.next().is_none());
+    }
+
+    type Modifier = fn(&mut FungibleTokenPacketData);
+
+    #[test]
+    fn validate_basic() {
+        assert!(osmosis_to_penumbra().validate_basic().is_ok());
+
+        let tests: [(Modifier, &str); 6] = [
+            (|p| p.denom.clear(), "empty denom"),
+            (|p| p.amount.clear(), "empty amount"),
+            (|p| p.amount = "0".into(), "zero amount"),
+            (|p| p.amount = "-5".into(), "negative amount"),
+            (|p| p.sender.clear(), "empty sender"),
+            (|p| p.receiver.clear(), "empty receiver"),
+        ];
+        for (modify, name) in tests {
+            let mut packet_data = osmosis_to_penumbra();
+            modify(&mut packet_data);
+            assert!(packet_data.validate_basic().is_err(), "{name}");
+        }
+    }
+
+    #[cfg(feature = "with_serde")]
+    #[test]
+    fn from_packet_data() {
+        // the packet data of a transfer of 1 OSMO from Osmosis to Penumbra
+        let data = br#"{"amount":"1000000","denom":"uosmo","receiver":"penumbra1xfp5p0qjsfh0wqsvvaay0yerth8lfsar2ysyxd2n09s29rvgvapz8wswpuhcvhaudqh4c5wqs2z8tvpv4fmasenfwmdyxqfwwhxf3kqfkhw3l77k3ts0x7ux59ejhnc8yx4ap7","sender":"osmo1qnk2n4nlkpw9xfqntladh74w6ujtulwnmxnh3k"}"#;
+
+        let packet_data = FungibleTokenPacketData::from_packet_data(data).unwrap();
+        assert_eq!(packet_data, osmosis_to_penumbra());
+        // an empty memo is omitted, so the encoding round-trips exactly
+        assert_eq!(packet_data.to_packet_data(), data.to_vec());
+
+        let with_memo = FungibleTokenPacketData {
+            memo: "hello".into(),
+            ..osmosis_to_penumbra()
+        };
+        let encoded = with_memo.to_packet_data();
+        assert!(encoded
+            .windows(br#""memo":"hello""#.len())
+            .any(|w| w == br#""memo":"hello""#));
+        assert_eq!(
+            FungibleTokenPacketData::from_packet_data(&encoded).unwrap(),
+            with_memo
+        );
+
+        assert!(matches!(
+            FungibleTokenPacketData::from_packet_data(b"not json"),
+            Err(Error::InvalidJson(_))
+        ));
+        assert!(matches!(
+            FungibleTokenPacketData::from_packet_data(
+                br#"{"amount":"0","denom":"uosmo","receiver":"a","sender":"b"}"#
+            ),
+            Err(Error::InvalidAmount { .. })
+        ));
+    }
+}
@@ -0,0 +1,93 @@
+use core::time::Duration;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ibc_proto::ibc::lightclients::tendermint::v1::ClientState as RawTmClientState;
+use ibc_proto::Protobuf;
+use ibc_types::core::channel::{
+    channel::{Order, State},
+    ChannelEnd, ChannelId, Counterparty as ChannelCounterparty, PortId, Version as ChannelVersion,
+};
+use ibc_types::core::client::Height;
+use ibc_types::core::connection::{
+    ChainId, ConnectionEnd, ConnectionId, Counterparty as ConnectionCounterparty,
+    Version as ConnectionVersion,
+};
+use ibc_types::lightclients::tendermint::{
+    client_state::{AllowUpdate, ClientState, ClientStateParams},
+    TrustThreshold,
+};
+
+fn bench_encode(c: &mut Criterion) {
+    let connection = ConnectionEnd::new_open(
+        "07-tendermint-0".parse().unwrap(),
+        ConnectionCounterparty::new(
+            "07-tendermint-12".parse().unwrap(),
+            Some(ConnectionId::new(7)),
+            b"ibc".to_vec().into(),
+        ),
+        vec![ConnectionVersion::default()],
+        Duration::from_secs(300),
+    );
+    let channel = ChannelEnd::new(
+        State::Open,
+        Order::Unordered,
+        ChannelCounterparty::new(PortId::transfer(), Some(ChannelId::new(42))),
+        vec![ConnectionId::new(0)],
+        ChannelVersion::new("ics20-1".to_string()),
+        0,
+    );
+    let client_state = ClientState::from_params(ClientStateParams {
+        chain_id: ChainId::new("ibc".to_string(), 1),
+        trust_level: TrustThreshold::ONE_THIRD,
+        trusting_period: Duration::from_secs(64000),
+        unbonding_period: Duration::from_secs(128000),
+        max_clock_drift: Duration::from_secs(3),
+        latest_height: Height::new(1, 10).unwrap(),
+        proof_specs: vec![ics23::iavl_spec(), ics23::tendermint_spec()],
+        upgrade_path: vec!["upgrade".to_string(), "upgradedIBCState".to_string()],
+        allow_update: AllowUpdate {
+            after_expiry: false,
+            after_misbehaviour: false,
+        },
+        frozen_height: None,
+    })
+    .unwrap();
+
+    // Each `encode_to` benchmark writes into a buffer reused across
+    // iterations, as a hot commitment path would.
+    c.bench_function("connection_end_encode_vec", |b| {
+        b.iter(|| black_box(&connection).clone().encode_vec())
+    });
+    let mut buf = Vec::new();
+    c.bench_function("connection_end_encode_to", |b| {
+        b.iter(|| {
+            buf.clear();
+            black_box(&connection).encode_to(&mut buf);
+        })
+    });
+
+    c.bench_function("channel_end_encode_vec", |b| {
+        b.iter(|| black_box(&channel).clone().encode_vec())
+    });
+    let mut buf = Vec::new();
+    c.bench_function("channel_end_encode_to", |b| {
+        b.iter(|| {
+            buf.clear();
+            black_box(&channel).encode_to(&mut buf);
+        })
+    });
+
+    c.bench_function("client_state_encode_vec", |b| {
+        b.iter(|| Protobuf::<RawTmClientState>::encode_vec(black_box(&client_state).clone()))
+    });
+    let mut buf = Vec::new();
+    c.bench_function("client_state_encode_to", |b| {
+        b.iter(|| {
+            buf.clear();
+            black_box(&client_state).encode_to(&mut buf);
+        })
+    });
+}
+
+criterion_group!(benches, bench_encode);
+criterion_main!(benches);
@@ -0,0 +1,144 @@
+//! Audits a JSON dump of IBC state, printing every validation failure.
+//!
+//! ```text
+//! cargo run --example ibc-lint --features with_serde -- <state.json>
+//! ```
+//!
+//! The dump is an object with `clients`, `connections`, and `channels`
+//! arrays, using the proto3 JSON encoding of ibc-go's gRPC gateway.  Each
+//! client is an object with a `clientId` and a Tendermint `clientState`; see
+//! `ibc-lint.json` for an example.  The process exits with status 1 if any
+//! violation is found.
+
+use std::{env, fs, process};
+
+use ibc_proto::ibc::core::channel::v1::IdentifiedChannel as RawIdentifiedChannel;
+use ibc_proto::ibc::core::connection::v1::IdentifiedConnection as RawIdentifiedConnection;
+use ibc_proto::ibc::lightclients::tendermint::v1::ClientState as RawTmClientState;
+use ibc_types::core::channel::IdentifiedChannelEnd;
+use ibc_types::core::connection::IdentifiedConnectionEnd;
+use ibc_types::lightclients::tendermint::client_state::ClientState;
+use serde_json::Value;
+
+/// Returns the entries of the `key` array of the dump, with their indices.
+fn entries<'a>(state: &'a Value, key: &str) -> impl Iterator<Item = (usize, &'a Value)> {
+    state
+        .get(key)
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .enumerate()
+}
+
+/// Names an entry by the string at `key`, or by its index if it has none.
+fn name(entry: &Value, key: &str, index: usize) -> String {
+    match entry.get(key).and_then(Value::as_str) {
+        Some(name) => name.to_string(),
+        None => format!("#{index}"),
+    }
+}
+
+/// Runs every available validation over the dump, returning one line per
+/// violation.
+fn lint(state: &Value) -> Vec<String> {
+    let mut violations = Vec::new();
+
+    for (i, entry) in entries(state, "clients") {
+        let client_id = name(entry, "clientId", i);
+        let client_state = entry.get("clientState").cloned().unwrap_or_default();
+        let result = serde_json::from_value::<RawTmClientState>(client_state)
+            .map_err(|e| e.to_string())
+            .and_then(|raw| ClientState::try_from(raw).map_err(|e| e.to_string()));
+        if let Err(e) = result {
+            violations.push(format!("client {client_id}: {e}"));
+        }
+    }
+
+    for (i, entry) in entries(state, "connections") {
+        let connection_id = name(entry, "id", i);
+        let result = serde_json::from_value::<RawIdentifiedConnection>(entry.clone())
+            .map_err(|e| e.to_string())
+            .and_then(|raw| IdentifiedConnectionEnd::try_from(raw).map_err(|e| e.to_string()))
+            .and_then(|identified| {
+                identified
+                    .connection_end
+                    .validate_basic()
+                    .map_err(|e| e.to_string())
+            });
+        if let Err(e) = result {
+            violations.push(format!("connection {connection_id}: {e}"));
+        }
+    }
+
+    for (i, entry) in entries(state, "channels") {
+        let channel = format!(
+            "{}/{}",
+            name(entry, "portId", i),
+            name(entry, "channelId", i)
+        );
+        let result = serde_json::from_value::<RawIdentifiedChannel>(entry.clone())
+            .map_err(|e| e.to_string())
+            .and_then(|raw| IdentifiedChannelEnd::try_from(raw).map_err(|e| e.to_string()));
+        match result {
+            Ok(identified) => {
+                if let Err(errors) = identified.channel_end.validate_all() {
+                    for e in errors.0 {
+                        violations.push(format!("channel {channel}: {e}"));
+                    }
+                }
+            }
+            Err(e) => violations.push(format!("channel {channel}: {e}")),
+        }
+    }
+
+    violations
+}
+
+fn main() {
+    let path = env::args().nth(1).unwrap_or_else(|| {
+        eprintln!("usage: ibc-lint <state.json>");
+        process::exit(2);
+    });
+    let state = fs::read_to_string(&path)
+        .map_err(|e| e.to_string())
+        .and_then(|json| serde_json::from_str::<Value>(&json).map_err(|e| e.to_string()))
+        .unwrap_or_else(|e| {
+            eprintln!("failed to read {path}: {e}");
+            process::exit(2);
+        });
+
+    let violations = lint(&state);
+    for violation in &violations {
+        println!("{violation}");
+    }
+    if !violations.is_empty() {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lint_sample_state() {
+        let state = serde_json::from_str(include_str!("ibc-lint.json")).unwrap();
+        let violations = lint(&state);
+
+        let subjects: Vec<_> = violations
+            .iter()
+            .map(|violation| violation.split(':').next().unwrap())
+            .collect();
+        assert_eq!(
+            subjects,
+            [
+                "client 07-tendermint-1",
+                "connection connection-1",
+                "connection connection-2",
+                "channel transfer/channel-1",
+                "channel transfer/channel-1",
+            ],
+            "unexpected violations: {violations:#?}"
+        );
+    }
+}
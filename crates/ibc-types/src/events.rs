@@ -0,0 +1,613 @@
+//! A single type covering the IBC events emitted by the client, connection,
+//! and channel modules.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+#[cfg(feature = "with_serde")]
+use alloc::{format, string::String};
+
+use displaydoc::Display;
+use ibc_types_core_channel::events::{self as channel_events, channel, packet};
+use ibc_types_core_channel::{ChannelId, PortId};
+use ibc_types_core_client::{events as client, Height};
+use ibc_types_core_connection::events as connection;
+use tendermint::abci::Event;
+
+/// An error while parsing an [`IbcEvent`] from an ABCI [`Event`].
+#[derive(Debug, Display)]
+pub enum Error {
+    /// Error parsing client event: {0}
+    Client(client::Error),
+    /// Error parsing connection event: {0}
+    Connection(connection::Error),
+    /// Error parsing channel event: {0}
+    // Boxed, since channel event errors are much larger than the others.
+    Channel(Box<channel_events::Error>),
+}
+
+impl From<client::Error> for Error {
+    fn from(e: client::Error) -> Self {
+        Self::Client(e)
+    }
+}
+
+impl From<connection::Error> for Error {
+    fn from(e: connection::Error) -> Self {
+        Self::Connection(e)
+    }
+}
+
+impl From<channel_events::Error> for Error {
+    fn from(e: channel_events::Error) -> Self {
+        Self::Channel(Box::new(e))
+    }
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match &self {
+            Self::Client(e) => Some(e),
+            Self::Connection(e) => Some(e),
+            Self::Channel(e) => Some(e.as_ref()),
+        }
+    }
+}
+
+/// Any IBC event emitted by the client, connection, or channel modules.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IbcEvent {
+    CreateClient(client::CreateClient),
+    UpdateClient(client::UpdateClient),
+    UpgradeClient(client::UpgradeClient),
+    ClientMisbehaviour(client::ClientMisbehaviour),
+    ConnectionOpenInit(connection::ConnectionOpenInit),
+    ConnectionOpenTry(connection::ConnectionOpenTry),
+    ConnectionOpenAck(connection::ConnectionOpenAck),
+    ConnectionOpenConfirm(connection::ConnectionOpenConfirm),
+    ChannelOpenInit(channel::OpenInit),
+    ChannelOpenTry(channel::OpenTry),
+    ChannelOpenAck(channel::OpenAck),
+    ChannelOpenConfirm(channel::OpenConfirm),
+    ChannelCloseInit(channel::CloseInit),
+    ChannelCloseConfirm(channel::CloseConfirm),
+    ChannelClose(packet::ChannelClose),
+    SendPacket(packet::SendPacket),
+    ReceivePacket(packet::ReceivePacket),
+    WriteAcknowledgement(packet::WriteAcknowledgement),
+    AcknowledgePacket(packet::AcknowledgePacket),
+    TimeoutPacket(packet::TimeoutPacket),
+}
+
+/// A coarse classification of [`IbcEvent`]s, e.g. for bucketing events in
+/// metrics or dashboards.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EventCategory {
+    /// Client creation, updates, upgrades, and misbehaviour.
+    ClientLifecycle,
+    /// Steps of the connection opening handshake.
+    ConnectionHandshake,
+    /// Steps of the channel opening and closing handshakes.
+    ChannelHandshake,
+    /// Sending, receiving, acknowledging, and timing out packets.
+    PacketFlow,
+}
+
+impl IbcEvent {
+    /// Parses an ABCI [`Event`] into the [`IbcEvent`] matching its `kind`.
+    ///
+    /// Returns `Ok(None)` if the event is not an IBC event, and an error if
+    /// it is an IBC event whose attributes are malformed.
+    pub fn try_from_abci(event: Event) -> Result<Option<Self>, Error> {
+        let ibc_event = match event.kind.as_str() {
+            client::CreateClient::TYPE_STR => IbcEvent::CreateClient(event.try_into()?),
+            client::UpdateClient::TYPE_STR => IbcEvent::UpdateClient(event.try_into()?),
+            client::UpgradeClient::TYPE_STR => IbcEvent::UpgradeClient(event.try_into()?),
+            client::ClientMisbehaviour::TYPE_STR => IbcEvent::ClientMisbehaviour(event.try_into()?),
+            connection::ConnectionOpenInit::TYPE_STR => {
+                IbcEvent::ConnectionOpenInit(event.try_into()?)
+            }
+            connection::ConnectionOpenTry::TYPE_STR => {
+                IbcEvent::ConnectionOpenTry(event.try_into()?)
+            }
+            connection::ConnectionOpenAck::TYPE_STR => {
+                IbcEvent::ConnectionOpenAck(event.try_into()?)
+            }
+            connection::ConnectionOpenConfirm::TYPE_STR => {
+                IbcEvent::ConnectionOpenConfirm(event.try_into()?)
+            }
+            channel::OpenInit::TYPE_STR => IbcEvent::ChannelOpenInit(event.try_into()?),
+            channel::OpenTry::TYPE_STR => IbcEvent::ChannelOpenTry(event.try_into()?),
+            channel::OpenAck::TYPE_STR => IbcEvent::ChannelOpenAck(event.try_into()?),
+            channel::OpenConfirm::TYPE_STR => IbcEvent::ChannelOpenConfirm(event.try_into()?),
+            channel::CloseInit::TYPE_STR => IbcEvent::ChannelCloseInit(event.try_into()?),
+            channel::CloseConfirm::TYPE_STR => IbcEvent::ChannelCloseConfirm(event.try_into()?),
+            packet::ChannelClose::TYPE_STR => IbcEvent::ChannelClose(event.try_into()?),
+            packet::SendPacket::TYPE_STR => IbcEvent::SendPacket(event.try_into()?),
+            packet::ReceivePacket::TYPE_STR => IbcEvent::ReceivePacket(event.try_into()?),
+            packet::WriteAcknowledgement::TYPE_STR => {
+                IbcEvent::WriteAcknowledgement(event.try_into()?)
+            }
+            packet::AcknowledgePacket::TYPE_STR => IbcEvent::AcknowledgePacket(event.try_into()?),
+            packet::TimeoutPacket::TYPE_STR => IbcEvent::TimeoutPacket(event.try_into()?),
+            _ => return Ok(None),
+        };
+
+        Ok(Some(ibc_event))
+    }
+
+    /// Returns the [`EventCategory`] this event belongs to.
+    pub fn category(&self) -> EventCategory {
+        match self {
+            IbcEvent::CreateClient(_)
+            | IbcEvent::UpdateClient(_)
+            | IbcEvent::UpgradeClient(_)
+            | IbcEvent::ClientMisbehaviour(_) => EventCategory::ClientLifecycle,
+            IbcEvent::ConnectionOpenInit(_)
+            | IbcEvent::ConnectionOpenTry(_)
+            | IbcEvent::ConnectionOpenAck(_)
+            | IbcEvent::ConnectionOpenConfirm(_) => EventCategory::ConnectionHandshake,
+            IbcEvent::ChannelOpenInit(_)
+            | IbcEvent::ChannelOpenTry(_)
+            | IbcEvent::ChannelOpenAck(_)
+            | IbcEvent::ChannelOpenConfirm(_)
+            | IbcEvent::ChannelCloseInit(_)
+            | IbcEvent::ChannelCloseConfirm(_) => EventCategory::ChannelHandshake,
+            // `ChannelClose` is emitted when a packet times out on an ordered
+            // channel, so it is part of the packet flow.
+            IbcEvent::ChannelClose(_)
+            | IbcEvent::SendPacket(_)
+            | IbcEvent::ReceivePacket(_)
+            | IbcEvent::WriteAcknowledgement(_)
+            | IbcEvent::AcknowledgePacket(_)
+            | IbcEvent::TimeoutPacket(_) => EventCategory::PacketFlow,
+        }
+    }
+
+    /// Returns `true` if this event refers to the channel `channel_id` on
+    /// port `port_id`, as either its own end or its counterparty's.
+    ///
+    /// For packet events, this matches both the source and destination
+    /// channel. Client and connection events never match.
+    pub fn involves_channel(&self, port_id: &PortId, channel_id: &ChannelId) -> bool {
+        let is = |p: &PortId, c: &ChannelId| p == port_id && c == channel_id;
+        match self {
+            IbcEvent::CreateClient(_)
+            | IbcEvent::UpdateClient(_)
+            | IbcEvent::UpgradeClient(_)
+            | IbcEvent::ClientMisbehaviour(_)
+            | IbcEvent::ConnectionOpenInit(_)
+            | IbcEvent::ConnectionOpenTry(_)
+            | IbcEvent::ConnectionOpenAck(_)
+            | IbcEvent::ConnectionOpenConfirm(_) => false,
+            // The counterparty channel is not yet known at `ChanOpenInit`.
+            IbcEvent::ChannelOpenInit(e) => is(&e.port_id, &e.channel_id),
+            IbcEvent::ChannelOpenTry(e) => {
+                is(&e.port_id, &e.channel_id)
+                    || is(&e.counterparty_port_id, &e.counterparty_channel_id)
+            }
+            IbcEvent::ChannelOpenAck(e) => {
+                is(&e.port_id, &e.channel_id)
+                    || is(&e.counterparty_port_id, &e.counterparty_channel_id)
+            }
+            IbcEvent::ChannelOpenConfirm(e) => {
+                is(&e.port_id, &e.channel_id)
+                    || is(&e.counterparty_port_id, &e.counterparty_channel_id)
+            }
+            IbcEvent::ChannelCloseInit(e) => {
+                is(&e.port_id, &e.channel_id)
+                    || is(&e.counterparty_port_id, &e.counterparty_channel_id)
+            }
+            IbcEvent::ChannelCloseConfirm(e) => {
+                is(&e.port_id, &e.channel_id)
+                    || is(&e.counterparty_port_id, &e.counterparty_channel_id)
+            }
+            IbcEvent::ChannelClose(e) => {
+                is(&e.port_id, &e.channel_id)
+                    || (e.counterparty_port_id == *port_id
+                        && e.counterparty_channel_id.as_ref() == Some(channel_id))
+            }
+            IbcEvent::SendPacket(e) => {
+                is(&e.src_port_id, &e.src_channel_id) || is(&e.dst_port_id, &e.dst_channel_id)
+            }
+            IbcEvent::ReceivePacket(e) => {
+                is(&e.src_port_id, &e.src_channel_id) || is(&e.dst_port_id, &e.dst_channel_id)
+            }
+            IbcEvent::WriteAcknowledgement(e) => {
+                is(&e.src_port_id, &e.src_channel_id) || is(&e.dst_port_id, &e.dst_channel_id)
+            }
+            IbcEvent::AcknowledgePacket(e) => {
+                is(&e.src_port_id, &e.src_channel_id) || is(&e.dst_port_id, &e.dst_channel_id)
+            }
+            IbcEvent::TimeoutPacket(e) => {
+                is(&e.src_port_id, &e.src_channel_id) || is(&e.dst_port_id, &e.dst_channel_id)
+            }
+        }
+    }
+}
+
+impl From<IbcEvent> for Event {
+    fn from(event: IbcEvent) -> Self {
+        match event {
+            IbcEvent::CreateClient(e) => e.into(),
+            IbcEvent::UpdateClient(e) => e.into(),
+            IbcEvent::UpgradeClient(e) => e.into(),
+            IbcEvent::ClientMisbehaviour(e) => e.into(),
+            IbcEvent::ConnectionOpenInit(e) => e.into(),
+            IbcEvent::ConnectionOpenTry(e) => e.into(),
+            IbcEvent::ConnectionOpenAck(e) => e.into(),
+            IbcEvent::ConnectionOpenConfirm(e) => e.into(),
+            IbcEvent::ChannelOpenInit(e) => e.into(),
+            IbcEvent::ChannelOpenTry(e) => e.into(),
+            IbcEvent::ChannelOpenAck(e) => e.into(),
+            IbcEvent::ChannelOpenConfirm(e) => e.into(),
+            IbcEvent::ChannelCloseInit(e) => e.into(),
+            IbcEvent::ChannelCloseConfirm(e) => e.into(),
+            IbcEvent::ChannelClose(e) => e.into(),
+            IbcEvent::SendPacket(e) => e.into(),
+            IbcEvent::ReceivePacket(e) => e.into(),
+            IbcEvent::WriteAcknowledgement(e) => e.into(),
+            IbcEvent::AcknowledgePacket(e) => e.into(),
+            IbcEvent::TimeoutPacket(e) => e.into(),
+        }
+    }
+}
+
+/// Attributes carrying raw bytes, as `(json key, abci key)` pairs. The ABCI
+/// events hex-encode these, while the JSON encoding uses base64.
+#[cfg(feature = "with_serde")]
+const BYTE_ATTRIBUTES: [(&str, &str); 3] = [
+    ("header", "header"),
+    ("packet_data", "packet_data_hex"),
+    ("packet_ack", "packet_ack_hex"),
+];
+
+/// Serializes the event as a flat JSON object tagged with its ABCI event
+/// type, e.g. `{"type":"send_packet","packet_data":"...",...}`.
+///
+/// Every attribute of the ABCI event is kept as a string, except for byte
+/// attributes, which are base64-encoded under their unsuffixed key (e.g.
+/// `packet_data` instead of `packet_data_hex`). The deprecated UTF-8 copies
+/// of the packet data and acknowledgement are dropped.
+#[cfg(feature = "with_serde")]
+impl serde::Serialize for IbcEvent {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::{Error as _, SerializeMap};
+
+        let event = Event::from(self.clone());
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", &event.kind)?;
+        for attr in &event.attributes {
+            let key = String::from_utf8_lossy(attr.key_bytes());
+            let value = String::from_utf8_lossy(attr.value_bytes());
+            if BYTE_ATTRIBUTES
+                .iter()
+                .any(|(json_key, abci_key)| key == *json_key && json_key != abci_key)
+            {
+                continue;
+            }
+            match BYTE_ATTRIBUTES
+                .iter()
+                .find(|(_, abci_key)| key == *abci_key)
+            {
+                Some((json_key, _)) => {
+                    let bytes = subtle_encoding::hex::decode(value.as_bytes())
+                        .map_err(|e| S::Error::custom(format!("invalid {key}: {e}")))?;
+                    let encoded = String::from_utf8(subtle_encoding::base64::encode(bytes))
+                        .expect("base64 is valid UTF-8");
+                    map.serialize_entry(json_key, &encoded)?;
+                }
+                None => map.serialize_entry(&key, &value)?,
+            }
+        }
+        map.end()
+    }
+}
+
+#[cfg(feature = "with_serde")]
+impl<'de> serde::Deserialize<'de> for IbcEvent {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use alloc::collections::BTreeMap;
+        use serde::de::Error as _;
+
+        let mut fields = BTreeMap::<String, String>::deserialize(deserializer)?;
+        let kind = fields
+            .remove("type")
+            .ok_or_else(|| D::Error::missing_field("type"))?;
+
+        let mut attributes = Vec::with_capacity(fields.len());
+        for (key, value) in fields {
+            match BYTE_ATTRIBUTES
+                .iter()
+                .find(|(json_key, _)| key == *json_key)
+            {
+                Some((json_key, abci_key)) => {
+                    let bytes = subtle_encoding::base64::decode(value.as_bytes())
+                        .map_err(|e| D::Error::custom(format!("invalid {json_key}: {e}")))?;
+                    let encoded = String::from_utf8(subtle_encoding::hex::encode(bytes))
+                        .expect("hex is valid UTF-8");
+                    attributes.push((String::from(*abci_key), encoded));
+                }
+                None => attributes.push((key, value)),
+            }
+        }
+
+        IbcEvent::try_from_abci(Event::new(kind.clone(), attributes))
+            .map_err(D::Error::custom)?
+            .ok_or_else(|| D::Error::custom(format!("unknown IBC event type `{kind}`")))
+    }
+}
+
+/// An [`IbcEvent`] together with where it was emitted: the height of the
+/// block, and the hash of the transaction if it was emitted by one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnnotatedIbcEvent {
+    pub event: IbcEvent,
+    pub height: Height,
+    /// The hash of the emitting transaction, or `None` for events emitted
+    /// outside of any transaction (e.g. at the beginning or end of a block).
+    pub tx_hash: Option<[u8; 32]>,
+}
+
+impl AnnotatedIbcEvent {
+    /// Parses the IBC events out of the results of the block at `height`,
+    /// skipping any non-IBC events.
+    ///
+    /// `block_events` are the events emitted outside of any transaction, and
+    /// `tx_events` pairs each transaction's hash with the events it emitted.
+    /// Block-level events are returned first, followed by the events of each
+    /// transaction in order.
+    pub fn parse_block_results(
+        height: Height,
+        block_events: impl IntoIterator<Item = Event>,
+        tx_events: impl IntoIterator<Item = ([u8; 32], Vec<Event>)>,
+    ) -> Result<Vec<Self>, Error> {
+        let block_events = block_events.into_iter().map(|event| (None, event));
+        let tx_events = tx_events.into_iter().flat_map(|(tx_hash, events)| {
+            events.into_iter().map(move |event| (Some(tx_hash), event))
+        });
+
+        let mut annotated = Vec::new();
+        for (tx_hash, event) in block_events.chain(tx_events) {
+            if let Some(event) = IbcEvent::try_from_abci(event)? {
+                annotated.push(Self {
+                    event,
+                    height,
+                    tx_hash,
+                });
+            }
+        }
+        Ok(annotated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    use alloc::vec;
+
+    use bytes::Bytes;
+    use ibc_types_core_channel::{channel::Order, ChannelId, PortId, TimeoutHeight, Version};
+    use ibc_types_core_client::{ClientId, ClientType, Height};
+    use ibc_types_core_connection::ConnectionId;
+    use ibc_types_timestamp::Timestamp;
+
+    #[test]
+    fn event_categories() {
+        let tests = [
+            (
+                IbcEvent::CreateClient(client::CreateClient {
+                    client_id: ClientId::default(),
+                    client_type: ClientType::tendermint(),
+                    consensus_height: Height::new(0, 10).unwrap(),
+                }),
+                EventCategory::ClientLifecycle,
+            ),
+            (
+                IbcEvent::ConnectionOpenInit(connection::ConnectionOpenInit {
+                    connection_id: ConnectionId::new(0),
+                    client_id_on_a: ClientId::default(),
+                    client_id_on_b: ClientId::default(),
+                }),
+                EventCategory::ConnectionHandshake,
+            ),
+            (
+                IbcEvent::ChannelOpenInit(channel::OpenInit {
+                    port_id: PortId::transfer(),
+                    channel_id: ChannelId::new(0),
+                    counterparty_port_id: PortId::transfer(),
+                    connection_id: ConnectionId::new(0),
+                    version: Version::default(),
+                }),
+                EventCategory::ChannelHandshake,
+            ),
+            (
+                IbcEvent::SendPacket(packet::SendPacket {
+                    packet_data: Bytes::from_static(b"data"),
+                    timeout_height: TimeoutHeight::default(),
+                    timeout_timestamp: Timestamp::none(),
+                    sequence: 1.into(),
+                    src_port_id: PortId::transfer(),
+                    src_channel_id: ChannelId::new(0),
+                    dst_port_id: PortId::transfer(),
+                    dst_channel_id: ChannelId::new(1),
+                    channel_ordering: Order::Unordered,
+                    src_connection_id: ConnectionId::new(0),
+                }),
+                EventCategory::PacketFlow,
+            ),
+        ];
+
+        for (event, category) in tests {
+            assert_eq!(event.category(), category, "{event:?}");
+        }
+    }
+
+    #[test]
+    fn involves_channel() {
+        let send_packet = IbcEvent::SendPacket(packet::SendPacket {
+            packet_data: Bytes::from_static(b"data"),
+            timeout_height: TimeoutHeight::default(),
+            timeout_timestamp: Timestamp::none(),
+            sequence: 1.into(),
+            src_port_id: PortId::transfer(),
+            src_channel_id: ChannelId::new(0),
+            dst_port_id: PortId::transfer(),
+            dst_channel_id: ChannelId::new(1),
+            channel_ordering: Order::Unordered,
+            src_connection_id: ConnectionId::new(0),
+        });
+        let other_port: PortId = "oracle".parse().unwrap();
+
+        assert!(send_packet.involves_channel(&PortId::transfer(), &ChannelId::new(0)));
+        assert!(send_packet.involves_channel(&PortId::transfer(), &ChannelId::new(1)));
+        assert!(!send_packet.involves_channel(&PortId::transfer(), &ChannelId::new(2)));
+        assert!(!send_packet.involves_channel(&other_port, &ChannelId::new(0)));
+
+        let create_client = IbcEvent::CreateClient(client::CreateClient {
+            client_id: ClientId::default(),
+            client_type: ClientType::tendermint(),
+            consensus_height: Height::new(0, 10).unwrap(),
+        });
+        assert!(!create_client.involves_channel(&PortId::transfer(), &ChannelId::new(0)));
+
+        let close = IbcEvent::ChannelClose(packet::ChannelClose {
+            port_id: PortId::transfer(),
+            channel_id: ChannelId::new(0),
+            counterparty_port_id: other_port.clone(),
+            counterparty_channel_id: None,
+            connection_id: ConnectionId::new(0),
+            channel_ordering: Order::Ordered,
+        });
+        assert!(close.involves_channel(&PortId::transfer(), &ChannelId::new(0)));
+        assert!(!close.involves_channel(&other_port, &ChannelId::new(0)));
+    }
+
+    #[test]
+    fn try_from_abci() {
+        let send_packet = packet::SendPacket {
+            packet_data: Bytes::from_static(b"data"),
+            timeout_height: TimeoutHeight::At(Height::new(0, 10).unwrap()),
+            timeout_timestamp: Timestamp::none(),
+            sequence: 1.into(),
+            src_port_id: PortId::transfer(),
+            src_channel_id: ChannelId::new(0),
+            dst_port_id: PortId::transfer(),
+            dst_channel_id: ChannelId::new(1),
+            channel_ordering: Order::Unordered,
+            src_connection_id: ConnectionId::new(0),
+        };
+
+        let event = IbcEvent::try_from_abci(Event::from(send_packet.clone()))
+            .unwrap()
+            .unwrap();
+        assert_eq!(event, IbcEvent::SendPacket(send_packet));
+        assert_eq!(event.category(), EventCategory::PacketFlow);
+
+        // non-IBC events are skipped
+        let transfer = Event::new("transfer", [("amount", "1upenumbra")]);
+        assert!(IbcEvent::try_from_abci(transfer).unwrap().is_none());
+
+        // malformed IBC events are rejected
+        let malformed = Event::new(packet::SendPacket::TYPE_STR, [("packet_sequence", "1")]);
+        assert!(matches!(
+            IbcEvent::try_from_abci(malformed),
+            Err(Error::Channel(_))
+        ));
+    }
+
+    #[test]
+    fn annotate_block_results() {
+        let send_packet = packet::SendPacket {
+            packet_data: Bytes::from_static(b"data"),
+            timeout_height: TimeoutHeight::At(Height::new(0, 10).unwrap()),
+            timeout_timestamp: Timestamp::none(),
+            sequence: 1.into(),
+            src_port_id: PortId::transfer(),
+            src_channel_id: ChannelId::new(0),
+            dst_port_id: PortId::transfer(),
+            dst_channel_id: ChannelId::new(1),
+            channel_ordering: Order::Unordered,
+            src_connection_id: ConnectionId::new(0),
+        };
+        let update_client = client::UpdateClient {
+            client_id: ClientId::default(),
+            client_type: ClientType::tendermint(),
+            consensus_height: Height::new(0, 9).unwrap(),
+            header: vec![],
+        };
+        let height = Height::new(1, 1234).unwrap();
+        let tx_hash = [0xab; 32];
+
+        let annotated = AnnotatedIbcEvent::parse_block_results(
+            height,
+            [
+                Event::from(update_client.clone()),
+                Event::new("rewards", [("amount", "1upenumbra")]),
+            ],
+            [(
+                tx_hash,
+                vec![
+                    Event::new("transfer", [("amount", "1upenumbra")]),
+                    Event::from(send_packet.clone()),
+                ],
+            )],
+        )
+        .unwrap();
+
+        assert_eq!(
+            annotated,
+            vec![
+                AnnotatedIbcEvent {
+                    event: IbcEvent::UpdateClient(update_client),
+                    height,
+                    tx_hash: None,
+                },
+                AnnotatedIbcEvent {
+                    event: IbcEvent::SendPacket(send_packet),
+                    height,
+                    tx_hash: Some(tx_hash),
+                },
+            ]
+        );
+    }
+
+    #[cfg(feature = "with_serde")]
+    #[test]
+    fn ibc_event_serde_round_trip() {
+        let send_packet = IbcEvent::SendPacket(packet::SendPacket {
+            packet_data: Bytes::from_static(&[0xff, 0x00]),
+            timeout_height: TimeoutHeight::At(Height::new(0, 10).unwrap()),
+            timeout_timestamp: Timestamp::none(),
+            sequence: 1.into(),
+            src_port_id: PortId::transfer(),
+            src_channel_id: ChannelId::new(0),
+            dst_port_id: PortId::transfer(),
+            dst_channel_id: ChannelId::new(1),
+            channel_ordering: Order::Unordered,
+            src_connection_id: ConnectionId::new(0),
+        });
+        let json = serde_json::to_value(&send_packet).unwrap();
+        assert_eq!(json["type"], "send_packet");
+        assert_eq!(json["packet_data"], "/wA=");
+        assert_eq!(json["packet_src_channel"], "channel-0");
+        assert!(json.get("packet_data_hex").is_none());
+        assert_eq!(
+            serde_json::from_value::<IbcEvent>(json).unwrap(),
+            send_packet
+        );
+
+        let open_init = IbcEvent::ConnectionOpenInit(connection::ConnectionOpenInit {
+            connection_id: ConnectionId::new(0),
+            client_id_on_a: ClientId::default(),
+            client_id_on_b: "07-tendermint-3".parse().unwrap(),
+        });
+        let json = serde_json::to_value(&open_init).unwrap();
+        assert_eq!(json["type"], "connection_open_init");
+        assert_eq!(json["connection_id"], "connection-0");
+        assert_eq!(serde_json::from_value::<IbcEvent>(json).unwrap(), open_init);
+
+        assert!(serde_json::from_str::<IbcEvent>(r#"{"type":"transfer"}"#).is_err());
+    }
+}
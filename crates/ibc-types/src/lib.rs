@@ -25,7 +25,7 @@
 //! crates, the Tendermint light client types can depend on the core IBC client
 //! types, preventing cyclic dependency issues.
 #![no_std]
-// Requires nightly.
+// Only enabled when building docs (e.g. on docs.rs), which requires nightly.
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 
 extern crate alloc;
@@ -67,5 +67,8 @@ pub mod lightclients {
 #[doc(inline)]
 pub use ibc_types_path as path;
 
+pub mod events;
+pub mod msgs;
+
 #[doc(inline)]
 pub use ibc_types_transfer as transfer;
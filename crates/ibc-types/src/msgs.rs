@@ -0,0 +1,150 @@
+//! A single type covering the messages processed by the client, connection,
+//! and channel modules.
+
+use alloc::string::String;
+
+use displaydoc::Display;
+use ibc_proto::google::protobuf::Any;
+use ibc_types_core_channel::msgs::MsgEnvelope as ChannelMsgEnvelope;
+use ibc_types_core_client::msgs::ClientMsg;
+use ibc_types_core_connection::msgs::ConnectionMsg;
+
+const CLIENT_TYPE_URL_PREFIX: &str = "/ibc.core.client.v1.";
+const CONNECTION_TYPE_URL_PREFIX: &str = "/ibc.core.connection.v1.";
+const CHANNEL_TYPE_URL_PREFIX: &str = "/ibc.core.channel.v1.";
+
+/// An error while decoding a [`MsgEnvelope`] from an [`Any`].
+#[derive(Debug, Display)]
+pub enum Error {
+    /// not a core IBC message type URL: `{type_url}`
+    UnknownTypeUrl { type_url: String },
+    /// Error decoding client message: {0}
+    Client(anyhow::Error),
+    /// Error decoding connection message: {0}
+    Connection(anyhow::Error),
+    /// Error decoding channel message: {0}
+    Channel(anyhow::Error),
+}
+
+impl core::error::Error for Error {}
+
+/// Any message processed by the client, connection, or channel modules.
+///
+/// This can be decoded from an [`Any`] by dispatching on its type URL, so
+/// that a transaction handler can decode incoming IBC messages uniformly.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MsgEnvelope {
+    Client(ClientMsg),
+    Connection(ConnectionMsg),
+    Channel(ChannelMsgEnvelope),
+}
+
+impl TryFrom<Any> for MsgEnvelope {
+    type Error = Error;
+
+    fn try_from(any: Any) -> Result<Self, Self::Error> {
+        if any.type_url.starts_with(CLIENT_TYPE_URL_PREFIX) {
+            ClientMsg::try_from(any)
+                .map(Self::Client)
+                .map_err(Error::Client)
+        } else if any.type_url.starts_with(CONNECTION_TYPE_URL_PREFIX) {
+            ConnectionMsg::try_from(any)
+                .map(Self::Connection)
+                .map_err(Error::Connection)
+        } else if any.type_url.starts_with(CHANNEL_TYPE_URL_PREFIX) {
+            ChannelMsgEnvelope::try_from(any)
+                .map(Self::Channel)
+                .map_err(Error::Channel)
+        } else {
+            Err(Error::UnknownTypeUrl {
+                type_url: any.type_url,
+            })
+        }
+    }
+}
+
+impl From<MsgEnvelope> for Any {
+    fn from(envelope: MsgEnvelope) -> Self {
+        match envelope {
+            MsgEnvelope::Client(msg) => msg.into(),
+            MsgEnvelope::Connection(msg) => msg.into(),
+            MsgEnvelope::Channel(msg) => msg.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_log::test;
+
+    use alloc::{string::ToString, vec};
+    use core::time::Duration;
+
+    use ibc_types_core_channel::{
+        msgs::{ChannelMsg, MsgChannelCloseInit},
+        ChannelId, PortId,
+    };
+    use ibc_types_core_client::{msgs::MsgUpdateClient, ClientId};
+    use ibc_types_core_connection::{msgs::MsgConnectionOpenInit, Counterparty};
+
+    #[test]
+    fn msg_envelope_round_trip() {
+        let signer = "cosmos1wxeyh7zgn4tctjzs0vtqpc6p5cxq5t2muzl7ng".to_string();
+        let envelopes = [
+            MsgEnvelope::Client(ClientMsg::UpdateClient(MsgUpdateClient {
+                client_id: ClientId::default(),
+                client_message: Any {
+                    type_url: "/ibc.lightclients.tendermint.v1.Header".to_string(),
+                    value: b"header".to_vec(),
+                },
+                signer: signer.clone(),
+            })),
+            MsgEnvelope::Connection(ConnectionMsg::OpenInit(MsgConnectionOpenInit {
+                client_id_on_a: ClientId::default(),
+                counterparty: Counterparty {
+                    client_id: ClientId::default(),
+                    connection_id: None,
+                    prefix: b"ibc".to_vec().into(),
+                },
+                version: None,
+                delay_period: Duration::ZERO,
+                signer: signer.clone(),
+            })),
+            MsgEnvelope::Channel(ChannelMsgEnvelope::Channel(ChannelMsg::CloseInit(
+                MsgChannelCloseInit {
+                    port_id_on_a: PortId::transfer(),
+                    chan_id_on_a: ChannelId::new(0),
+                    signer,
+                },
+            ))),
+        ];
+
+        for (envelope, type_url) in envelopes.into_iter().zip([
+            "/ibc.core.client.v1.MsgUpdateClient",
+            "/ibc.core.connection.v1.MsgConnectionOpenInit",
+            "/ibc.core.channel.v1.MsgChannelCloseInit",
+        ]) {
+            let any = Any::from(envelope.clone());
+            assert_eq!(any.type_url, type_url);
+            assert_eq!(MsgEnvelope::try_from(any).unwrap(), envelope);
+        }
+    }
+
+    #[test]
+    fn msg_envelope_rejects_unknown_type_urls() {
+        let any = |type_url: &str| Any {
+            type_url: type_url.to_string(),
+            value: vec![],
+        };
+
+        assert!(matches!(
+            MsgEnvelope::try_from(any("/cosmos.bank.v1beta1.MsgSend")),
+            Err(Error::UnknownTypeUrl { .. })
+        ));
+        assert!(matches!(
+            MsgEnvelope::try_from(any("/ibc.core.client.v1.MsgFrobnicateClient")),
+            Err(Error::Client(_))
+        ));
+    }
+}
@@ -0,0 +1,44 @@
+//! Checks that every crate in the workspace builds on stable: nightly
+//! `#![feature(..)]` attributes may only appear behind `cfg_attr(docsrs, ..)`.
+
+use std::fs;
+use std::path::Path;
+
+fn check_dir(dir: &Path, offenders: &mut Vec<String>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            check_dir(&path, offenders);
+        } else if path.extension().and_then(|ext| ext.to_str()) == Some("rs") {
+            let source = fs::read_to_string(&path).unwrap();
+            for (i, line) in source.lines().enumerate() {
+                let line = line.trim();
+                if line.starts_with("#![")
+                    && line.contains("feature(")
+                    && !line.starts_with("#![cfg_attr(docsrs,")
+                {
+                    offenders.push(format!("{}:{}: {line}", path.display(), i + 1));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn nightly_features_are_gated_on_docsrs() {
+    let crates = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+
+    let mut offenders = Vec::new();
+    for entry in fs::read_dir(crates).unwrap() {
+        let src = entry.unwrap().path().join("src");
+        if src.is_dir() {
+            check_dir(&src, &mut offenders);
+        }
+    }
+
+    assert!(
+        offenders.is_empty(),
+        "ungated nightly features:\n{}",
+        offenders.join("\n")
+    );
+}